@@ -22,87 +22,65 @@ unsafe fn run() -> Result<()> {
     let width: vx_uint32 = 512;
     let height: vx_uint32 = 512;
 
-    let mut context = VxContext::create();
-    context.check_status().expect("Context was invalid");
+    let context = VxContext::create().expect("Context was invalid");
     context.enable_logging().expect("Unable to enable logging");
     context
         .enable_performance_counters()
         .expect("Unable to enable performance counters");
 
-    let mut graph = VxGraph::create(&context);
-    graph
-        .check_status()
-        .expect("Graph was invalid")
-        .set_name("CANNY_GRAPH");
-
-    let mut input_rgb_image =
-        vxCreateImage(context.as_raw(), width, height, ImageType::RGB.to_raw());
-    let mut output_filtered_image =
-        vxCreateImage(context.as_raw(), width, height, ImageType::U8.to_raw());
-    error_check_object(input_rgb_image as vx_reference);
-    error_check_object(output_filtered_image as vx_reference);
-
-    let mut yuv_image =
-        vxCreateVirtualImage(graph.as_raw(), width, height, ImageType::IYUV.to_raw());
-    let mut luma_image =
-        vxCreateVirtualImage(graph.as_raw(), width, height, ImageType::U8.to_raw());
-    error_check_object(yuv_image as vx_reference);
-    error_check_object(luma_image as vx_reference);
-
-    let hyst = vxCreateThreshold(
-        context.as_raw(),
-        vx_threshold_type_e_VX_THRESHOLD_TYPE_RANGE as vx_enum,
-        vx_type_e_VX_TYPE_UINT8 as vx_enum,
-    );
-    let lower: vx_int32 = 130;
-    let upper: vx_int32 = 150;
-    vxSetThresholdAttribute(
-        hyst,
-        constants::thresholds::VX_THRESHOLD_ATTRIBUTE_THRESHOLD_LOWER,
-        &lower as *const _ as *const std::ffi::c_void,
-        std::mem::size_of_val(&lower) as vx_size,
-    );
-    vxSetThresholdAttribute(
-        hyst,
-        constants::thresholds::VX_THRESHOLD_ATTRIBUTE_THRESHOLD_UPPER,
-        &upper as *const _ as *const std::ffi::c_void,
-        std::mem::size_of_val(&upper) as vx_size,
-    );
-    error_check_object(hyst as vx_reference);
+    let graph = VxGraph::create(&context).expect("Graph was invalid");
+    graph.set_name("CANNY_GRAPH");
+
+    let input_rgb_image =
+        VxImage::new(&context, width, height, ImageType::RGB).expect("Input image was invalid");
+    let output_filtered_image =
+        VxImage::new(&context, width, height, ImageType::U8).expect("Output image was invalid");
+
+    let yuv_image = VxImage::virtual_image(&graph, width, height, ImageType::IYUV)
+        .expect("YUV image was invalid");
+    let luma_image = VxImage::virtual_image(&graph, width, height, ImageType::U8)
+        .expect("Luma image was invalid");
+
+    let hyst = VxThreshold::new(&context, ThresholdType::Range, ImageType::U8, ImageType::U8)
+        .expect("Threshold was invalid");
+    hyst.set_range(PixelValue::from_u8(130), PixelValue::from_u8(150))
+        .expect("Unable to set hysteresis range");
 
     let gradient_size: vx_int32 = 3;
-    let mut nodes = vec![
-        set_node_name(
-            vxColorConvertNode(graph.as_raw(), input_rgb_image, yuv_image),
+    let nodes = vec![
+        VxNode::from(set_node_name(
+            vxColorConvertNode(graph.as_raw(), input_rgb_image.as_raw(), yuv_image.as_raw()),
             "RGB_TO_YUV",
-        ),
-        set_node_name(
+        ))
+        .into_checked()
+        .expect("Color conversion node was invalid"),
+        VxNode::from(set_node_name(
             vxChannelExtractNode(
                 graph.as_raw(),
-                yuv_image,
+                yuv_image.as_raw(),
                 vx_channel_e_VX_CHANNEL_Y as vx_enum,
-                luma_image,
+                luma_image.as_raw(),
             ),
             "EXTRACT_LUMA",
-        ),
-        set_node_name(
+        ))
+        .into_checked()
+        .expect("Channel extraction node was invalid"),
+        VxNode::from(set_node_name(
             vxCannyEdgeDetectorNode(
                 graph.as_raw(),
-                luma_image,
-                hyst,
+                luma_image.as_raw(),
+                hyst.as_raw(),
                 gradient_size,
                 vx_norm_type_e_VX_NORM_L1 as vx_enum,
-                output_filtered_image,
+                output_filtered_image.as_raw(),
             ),
             "CANNY_EDGE",
-        ),
+        ))
+        .into_checked()
+        .expect("Canny edge detection node was invalid"),
     ];
 
-    for node in nodes.iter_mut() {
-        error_check_object(*node as vx_reference);
-    }
-
-    error_check_status(vxVerifyGraph(graph.as_raw()));
+    graph.verify().expect("failed to verify graph");
 
     let image = imread(".images/selfie.jpg", IMREAD_COLOR)?;
     let mut resized = Mat::default();
@@ -120,116 +98,43 @@ unsafe fn run() -> Result<()> {
 
     imshow("Input Image", &resized)?;
 
-    let cv_rgb_image_region = vx_rectangle_t {
-        start_x: 0 as vx_uint32,
-        start_y: 0 as vx_uint32,
-        end_x: width,
-        end_y: height,
-    };
-
-    let cv_rgb_image_layout = vx_imagepatch_addressing_t {
-        stride_x: 3 as vx_int32,
-        stride_y: resized.mat_step().get(0) as vx_int32,
-        // Default isn't implemented; not sure about these, but zeros seem to work.
-        dim_x: 0 as vx_uint32,
-        dim_y: 0 as vx_uint32,
-        scale_x: 0 as vx_uint32,
-        scale_y: 0 as vx_uint32,
-        step_x: 0 as vx_uint32,
-        step_y: 0 as vx_uint16,
-        stride_x_bits: 0 as vx_uint16,
-    };
-
-    let cv_rgb_image_buffer: *mut vx_uint8 = resized.data_mut();
-    error_check_status(vxCopyImagePatch(
-        input_rgb_image,
-        &cv_rgb_image_region,
-        0,
-        &cv_rgb_image_layout,
-        cv_rgb_image_buffer as *mut std::ffi::c_void,
-        vx_accessor_e_VX_WRITE_ONLY as vx_enum,
-        vx_memory_type_e_VX_MEMORY_TYPE_HOST as vx_enum,
-    ));
-
-    error_check_status(vxProcessGraph(graph.as_raw()));
-
-    let rect = vx_rectangle_t {
-        start_x: 0 as vx_uint32,
-        start_y: 0 as vx_uint32,
-        end_x: width,
-        end_y: height,
-    };
-
-    let mut map_id: vx_map_id = 0usize;
-    let mut addr: vx_imagepatch_addressing_t = vx_imagepatch_addressing_t {
-        dim_x: 0 as vx_uint32,
-        dim_y: 0 as vx_uint32,
-        stride_x: 0 as vx_int32,
-        stride_y: 0 as vx_int32,
-        scale_x: 0 as vx_uint32,
-        scale_y: 0 as vx_uint32,
-        step_x: 0 as vx_uint32,
-        step_y: 0 as vx_uint16,
-        stride_x_bits: 0 as vx_uint16,
-    };
-    let mut ptr: *mut std::ffi::c_void = std::ptr::null_mut();
-    error_check_status(vxMapImagePatch(
-        output_filtered_image,
-        &rect,
-        0 as vx_uint32,
-        &mut map_id,
-        &mut addr,
-        &mut ptr,
-        vx_accessor_e_VX_READ_ONLY as vx_enum,
-        vx_memory_type_e_VX_MEMORY_TYPE_HOST as vx_enum,
-        vx_map_flag_e_VX_NOGAP_X as vx_uint32,
-    ));
+    let stride = resized.mat_step().get(0) / 3;
+    let pixels: &[[u8; 3]] = std::slice::from_raw_parts(
+        resized.data() as *const [u8; 3],
+        stride * (height as usize - 1) + width as usize,
+    );
+    input_rgb_image
+        .copy_from_slice(0, Rect::new(0, 0, width, height), pixels, stride)
+        .expect("Unable to copy input image");
+
+    graph.process().expect("failed to process graph");
 
+    let patch = output_filtered_image
+        .map_patch::<u8>(Rect::new(0, 0, width, height), 0, Access::Read)
+        .expect("Unable to map output image");
+
+    // Declared after the patch, the matrix wrapping its memory is dropped first.
     let mat = Mat::new_rows_cols_with_data_unsafe(
         height as i32,
         width as i32,
         CV_8U,
-        ptr,
-        addr.stride_y as usize,
+        patch.as_ptr() as *mut std::ffi::c_void,
+        patch.addressing().stride_y as usize,
     )?;
 
-    print_graph_attributes(&mut graph);
-    for node in nodes.iter_mut() {
-        print_node_attributes(*node);
-        error_check_status(vxReleaseNode(node));
+    print_graph_attributes(&graph).expect("Unable to query graph attributes");
+    for node in nodes.iter() {
+        print_node_attributes(node).expect("Unable to query node attributes");
     }
 
     imshow("Canny Edge Detection", &mat)?;
     wait_key(0)?;
     destroy_all_windows()?;
 
-    error_check_status(vxUnmapImagePatch(output_filtered_image, map_id));
-
-    graph.release().expect("Releasing graph failed");
-    error_check_status(vxReleaseImage(&mut yuv_image));
-    error_check_status(vxReleaseImage(&mut luma_image));
-    error_check_status(vxReleaseImage(&mut input_rgb_image));
-    error_check_status(vxReleaseImage(&mut output_filtered_image));
-    context.release().expect("Releasing context failed");
-
+    // All objects are released in reverse order of creation when they go out of scope.
     Ok(())
 }
 
-fn error_check_object(r#ref: vx_reference) {
-    let status = unsafe { vxGetStatus(r#ref) };
-    let status = VxStatus::from(status);
-    if status != VxStatus::Success {
-        panic!("ERROR: failed with status {}", status);
-    }
-}
-
-fn error_check_status(status: vx_status) {
-    let status = VxStatus::from(status);
-    if status != VxStatus::Success {
-        panic!("ERROR: failed with status {}", status);
-    }
-}
-
 fn main() {
     unsafe { run().unwrap() };
 }
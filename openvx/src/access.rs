@@ -0,0 +1,84 @@
+use core::fmt;
+use libopenvx_sys::*;
+
+/// The memory accessor hint flags, used when mapping or copying data objects.
+#[derive(Debug, Eq, PartialEq, Hash, Copy, Clone)]
+pub enum Access {
+    /// The memory shall be treated by the system as if it were read-only.
+    Read,
+    /// The memory shall be treated by the system as if it were write-only.
+    Write,
+    /// The memory shall be treated by the system as if it were readable and writeable.
+    ReadWrite,
+    /// A vendor-defined or otherwise unknown value.
+    Other { accessor: vx_accessor_e },
+}
+
+impl Access {
+    pub const fn new(accessor: vx_accessor_e) -> Self {
+        #[allow(non_upper_case_globals)]
+        match accessor {
+            vx_accessor_e_VX_READ_ONLY => Access::Read,
+            vx_accessor_e_VX_WRITE_ONLY => Access::Write,
+            vx_accessor_e_VX_READ_AND_WRITE => Access::ReadWrite,
+            accessor => Access::Other { accessor },
+        }
+    }
+
+    /// Converts this instance into a [`vx_accessor_e`].
+    ///
+    /// [`vx_accessor_e`]: ../libopenvx_sys/type.vx_accessor_e.html
+    pub const fn to_raw(&self) -> vx_accessor_e {
+        match self {
+            Access::Read => vx_accessor_e_VX_READ_ONLY,
+            Access::Write => vx_accessor_e_VX_WRITE_ONLY,
+            Access::ReadWrite => vx_accessor_e_VX_READ_AND_WRITE,
+            Access::Other { accessor } => *accessor,
+        }
+    }
+
+    /// Determines whether the accessor permits reading the memory.
+    pub const fn is_readable(&self) -> bool {
+        matches!(self, Access::Read | Access::ReadWrite)
+    }
+
+    /// Determines whether the accessor permits writing the memory.
+    pub const fn is_writable(&self) -> bool {
+        matches!(self, Access::Write | Access::ReadWrite)
+    }
+}
+
+impl From<vx_accessor_e> for Access {
+    fn from(value: vx_accessor_e) -> Self {
+        Access::new(value)
+    }
+}
+
+impl fmt::Display for Access {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Access::Read => write!(f, "VX_READ_ONLY"),
+            Access::Write => write!(f, "VX_WRITE_ONLY"),
+            Access::ReadWrite => write!(f, "VX_READ_AND_WRITE"),
+            Access::Other { accessor } => write!(f, "VX_ACCESSOR_OTHER ({})", accessor),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roundtrip() {
+        for value in [Access::Read, Access::Write, Access::ReadWrite] {
+            assert_eq!(Access::from(value.to_raw()), value);
+        }
+    }
+
+    #[test]
+    fn other() {
+        assert_eq!(Access::new(0xFFFF), Access::Other { accessor: 0xFFFF });
+        assert!(!Access::new(0xFFFF).is_readable());
+    }
+}
@@ -0,0 +1,70 @@
+use crate::{Coordinates2d, Coordinates2df, Coordinates3d, Keypoint, Line2d, Rect, VxType};
+use libopenvx_sys::{
+    vx_coordinates2d_t, vx_coordinates2df_t, vx_coordinates3d_t, vx_keypoint_t, vx_line2d_t,
+    vx_rectangle_t, vx_size,
+};
+
+/// A type that can represent the items of an array in host memory.
+///
+/// | Type                                                                  | Item type                |
+/// |-----------------------------------------------------------------------|--------------------------|
+/// | `i8`, …, `u64`                                                        | `VX_TYPE_INT8`, …        |
+/// | `f32`, `f64`                                                          | `VX_TYPE_FLOAT32`, …     |
+/// | `usize`                                                               | `VX_TYPE_SIZE`           |
+/// | [`Rect`](struct.Rect.html), `vx_rectangle_t`                          | `VX_TYPE_RECTANGLE`      |
+/// | [`Keypoint`](struct.Keypoint.html), `vx_keypoint_t`                   | `VX_TYPE_KEYPOINT`       |
+/// | [`Coordinates2d`](struct.Coordinates2d.html), `vx_coordinates2d_t`    | `VX_TYPE_COORDINATES2D`  |
+/// | [`Coordinates3d`](struct.Coordinates3d.html), `vx_coordinates3d_t`    | `VX_TYPE_COORDINATES3D`  |
+/// | [`Coordinates2df`](struct.Coordinates2df.html), `vx_coordinates2df_t` | `VX_TYPE_COORDINATES2DF` |
+/// | [`Line2d`](struct.Line2d.html), `vx_line2d_t`                         | `VX_TYPE_LINE_2D`        |
+///
+/// User structs implement this trait with their item type, e.g. an offset from
+/// `VX_TYPE_USER_STRUCT_START`. Arrays of types registered at runtime by calling
+/// [`vxRegisterUserStruct`] are created with
+/// [`VxArray::with_type`](struct.VxArray.html#method.with_type) instead.
+///
+/// # Safety
+///
+/// Implementors must be plain old data with the size and layout of an item of `ITEM_TYPE`.
+///
+/// [`vxRegisterUserStruct`]: ../libopenvx_sys/fn.vxRegisterUserStruct.html
+pub unsafe trait ArrayItem: Copy + 'static {
+    /// The item type of arrays with items of this type.
+    const ITEM_TYPE: VxType;
+}
+
+macro_rules! array_items {
+    ($($ty:ty => $item_type:ident),* $(,)?) => {
+        $(
+            unsafe impl ArrayItem for $ty {
+                const ITEM_TYPE: VxType = VxType::$item_type;
+            }
+        )*
+    };
+}
+
+array_items! {
+    i8 => Int8,
+    u8 => UInt8,
+    i16 => Int16,
+    u16 => UInt16,
+    i32 => Int32,
+    u32 => UInt32,
+    i64 => Int64,
+    u64 => UInt64,
+    f32 => Float32,
+    f64 => Float64,
+    vx_size => Size,
+    vx_rectangle_t => Rectangle,
+    vx_keypoint_t => Keypoint,
+    vx_coordinates2d_t => Coordinates2D,
+    vx_coordinates3d_t => Coordinates3D,
+    vx_coordinates2df_t => Coordinates2DF,
+    vx_line2d_t => Line2D,
+    Rect => Rectangle,
+    Keypoint => Keypoint,
+    Coordinates2d => Coordinates2D,
+    Coordinates3d => Coordinates3D,
+    Coordinates2df => Coordinates2DF,
+    Line2d => Line2D,
+}
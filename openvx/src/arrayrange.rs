@@ -0,0 +1,150 @@
+use crate::error::check_call;
+use crate::mapping::Mapping;
+use crate::{Access, ArrayItem, AsRaw, MemoryType, Release, Result, VxArray, VxError};
+use libopenvx_sys::{
+    vxMapArrayRange, vxUnmapArrayRange, vx_array, vx_enum, vx_map_flag_e_VX_NOGAP_X, vx_map_id,
+    vx_size, vx_uint32,
+};
+use std::ffi::c_void;
+use std::marker::PhantomData;
+
+/// A range of array items mapped into host memory by
+/// [`VxArray::map_range`](struct.VxArray.html#method.map_range).
+///
+/// The items are adjacent in memory and accessed as a slice. The range is unmapped by
+/// calling [`vxUnmapArrayRange`] when the guard is dropped.
+///
+/// [`vxUnmapArrayRange`]: ../libopenvx_sys/fn.vxUnmapArrayRange.html
+#[derive(Debug)]
+pub struct ArrayRange<'r, T: ArrayItem> {
+    mapping: Mapping<vx_array>,
+    len: usize,
+    _marker: PhantomData<(&'r (), T)>,
+}
+
+impl<'r, T: ArrayItem> ArrayRange<'r, T> {
+    /// Maps the items from `start` (inclusive) to `end` (exclusive) by calling
+    /// [`vxMapArrayRange`].
+    ///
+    /// [`vxMapArrayRange`]: ../libopenvx_sys/fn.vxMapArrayRange.html
+    pub(crate) fn map(
+        array: &'r VxArray<'_, T>,
+        start: usize,
+        end: usize,
+        access: Access,
+    ) -> Result<Self> {
+        array.check_item_size()?;
+        if start >= end {
+            return Err(VxError::InvalidParameters.into());
+        }
+
+        let mut map_id: vx_map_id = 0;
+        let mut stride: vx_size = 0;
+        let mut ptr: *mut c_void = std::ptr::null_mut();
+        check_call("vxMapArrayRange", array, || unsafe {
+            vxMapArrayRange(
+                array.as_raw(),
+                start,
+                end,
+                &mut map_id,
+                &mut stride,
+                &mut ptr,
+                access.to_raw() as vx_enum,
+                MemoryType::Host.to_raw() as vx_enum,
+                vx_map_flag_e_VX_NOGAP_X as vx_uint32,
+            )
+        })?;
+
+        let range = Self {
+            mapping: Mapping::new(array.as_raw(), map_id, ptr, access, vxUnmapArrayRange),
+            len: end - start,
+            _marker: PhantomData,
+        };
+
+        if stride != std::mem::size_of::<T>() {
+            return Err(VxError::NotSupported.into());
+        }
+        range.mapping.check_layout::<T>(&[stride])?;
+        Ok(range)
+    }
+
+    /// Returns the number of mapped items.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Determines whether no items are mapped.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the accessor the range was mapped with.
+    pub fn access(&self) -> Access {
+        self.mapping.access()
+    }
+
+    /// Returns the mapped items.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range was mapped write-only.
+    pub fn as_slice(&self) -> &[T] {
+        assert!(
+            self.mapping.access().is_readable(),
+            "range is not mapped for reading"
+        );
+        unsafe { std::slice::from_raw_parts(self.mapping.ptr() as *const T, self.len) }
+    }
+
+    /// Returns the mapped items for writing.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range was mapped read-only.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        assert!(
+            self.mapping.access().is_writable(),
+            "range is not mapped for writing"
+        );
+        unsafe { std::slice::from_raw_parts_mut(self.mapping.ptr() as *mut T, self.len) }
+    }
+
+    /// Returns an iterator over the mapped items.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range was mapped write-only.
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.as_slice().iter()
+    }
+}
+
+impl<T: ArrayItem> Release for ArrayRange<'_, T> {
+    fn release(&mut self) -> Result<()> {
+        self.mapping.release()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mapped_items() {
+        let mut data = vec![1u16, 2, 3];
+        let mut range = ArrayRange::<u16> {
+            mapping: Mapping::detached(
+                std::ptr::null_mut(),
+                data.as_mut_ptr() as *mut u8,
+                Access::ReadWrite,
+            ),
+            len: data.len(),
+            _marker: PhantomData,
+        };
+
+        range.as_mut_slice()[1] = 5;
+        assert_eq!(range.iter().sum::<u16>(), 9);
+        assert_eq!(range.len(), 3);
+        assert_eq!(data, [1, 5, 3]);
+    }
+}
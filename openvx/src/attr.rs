@@ -0,0 +1,639 @@
+//! Typed access to object attributes.
+//!
+//! Every attribute is represented by a marker type that knows the attribute enumeration,
+//! the C type exchanged with the implementation and the Rust type it is converted to.
+//! Markers are shared between objects where OpenVX uses the same concept, e.g. [`Width`]
+//! applies to images and pyramids. Whether an attribute can be read or written on a given
+//! object is expressed through the [`Readable`] and [`Writable`] traits.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use openvx::{attr, Border, QueryAttribute, SetAttribute, VxContext, VxGraph};
+//!
+//! let context = VxContext::create().unwrap();
+//! let graph = VxGraph::create(&context).unwrap();
+//!
+//! let num_nodes: u32 = graph.query::<attr::NumNodes>().unwrap();
+//! context.set::<attr::BorderMode>(Border::Replicate).unwrap();
+//! ```
+
+use crate::{
+    AsVxReference, ImageType, PixelValue, Result, VxArray, VxContext, VxConvolution, VxDelay,
+    VxDistribution, VxGraph, VxGraphState, VxImage, VxKernel, VxLut, VxMatrix, VxNode, VxParameter,
+    VxPyramid, VxRemap, VxScalar, VxStatus, VxTensor, VxThreshold, VxType,
+};
+use libopenvx_sys::*;
+use std::ffi::{c_void, CStr};
+
+/// An attribute of an OpenVX object of type `O`.
+///
+/// # Safety
+///
+/// [`Raw`](#associatedtype.Raw) must be the exact C type the implementation reads or writes
+/// for [`ATTRIBUTE`](#associatedconstant.ATTRIBUTE), and a value with all bits set to zero
+/// must be a valid instance of it.
+pub unsafe trait Attribute<O: ?Sized> {
+    /// The C representation exchanged with the `vxQuery*` and `vxSet*Attribute` functions.
+    type Raw: Copy;
+
+    /// The Rust representation of the attribute value.
+    type Value;
+
+    /// The attribute enumeration value, e.g. `VX_GRAPH_NUMNODES`.
+    const ATTRIBUTE: vx_enum;
+
+    /// Converts a value filled in by the implementation.
+    ///
+    /// # Safety
+    ///
+    /// `raw` must have been filled in by the implementation when querying
+    /// [`ATTRIBUTE`](#associatedconstant.ATTRIBUTE).
+    unsafe fn from_raw(raw: Self::Raw) -> Self::Value;
+}
+
+/// Marks an attribute as readable on objects of type `O`.
+pub trait Readable<O: ?Sized>: Attribute<O> {}
+
+/// Marks an attribute as writable on objects of type `O`.
+pub trait Writable<O: ?Sized>: Attribute<O> {
+    /// Converts a value into the representation expected by the implementation.
+    fn to_raw(value: &Self::Value) -> Self::Raw;
+}
+
+/// Queries attributes of an object.
+pub trait QueryAttribute: AsVxReference {
+    /// Calls the `vxQuery*` function of the object.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must point to `size` writable bytes.
+    unsafe fn query_raw(&self, attribute: vx_enum, ptr: *mut c_void, size: vx_size) -> vx_status;
+
+    /// Queries the attribute `A` of this object.
+    ///
+    /// Attributes of the generic reference, such as [`Name`] or [`ReferenceCount`], are
+    /// queried by calling [`vxQueryReference`].
+    ///
+    /// [`vxQueryReference`]: ../../libopenvx_sys/fn.vxQueryReference.html
+    fn query<A>(&self) -> Result<A::Value>
+    where
+        A: Readable<Self>,
+    {
+        let mut raw: A::Raw = unsafe { std::mem::zeroed() };
+        let ptr = &mut raw as *mut A::Raw as *mut c_void;
+        let size = std::mem::size_of::<A::Raw>() as vx_size;
+
+        let status = unsafe {
+            if vx_type!(A::ATTRIBUTE) == vx_type_e_VX_TYPE_REFERENCE {
+                vxQueryReference(self.as_reference().into(), A::ATTRIBUTE, ptr, size)
+            } else {
+                self.query_raw(A::ATTRIBUTE, ptr, size)
+            }
+        };
+
+        VxStatus::new_result(status, ())?;
+        Ok(unsafe { A::from_raw(raw) })
+    }
+}
+
+/// Sets attributes of an object.
+pub trait SetAttribute: QueryAttribute {
+    /// Calls the `vxSet*Attribute` function of the object.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must point to `size` readable bytes.
+    unsafe fn set_raw(&self, attribute: vx_enum, ptr: *const c_void, size: vx_size) -> vx_status;
+
+    /// Sets the attribute `A` of this object.
+    fn set<A>(&self, value: A::Value) -> Result<()>
+    where
+        A: Writable<Self>,
+    {
+        let raw = A::to_raw(&value);
+        let ptr = &raw as *const A::Raw as *const c_void;
+        let size = std::mem::size_of::<A::Raw>() as vx_size;
+
+        let status = unsafe { self.set_raw(A::ATTRIBUTE, ptr, size) };
+        VxStatus::new_result(status, ())
+    }
+}
+
+/// Declares attribute marker types.
+macro_rules! markers {
+    ($($(#[$meta:meta])* $name:ident;)*) => {
+        $(
+            $(#[$meta])*
+            #[derive(Debug, Copy, Clone)]
+            pub enum $name {}
+        )*
+    };
+}
+
+/// Implements [`Attribute`], [`Readable`] and optionally [`Writable`] for a marker type.
+macro_rules! attribute {
+    ($attr:ident for $object:ty = $enum:expr; $raw:ty) => {
+        attribute!($attr for $object = $enum; $raw => $raw, |raw| raw);
+    };
+    ($attr:ident for $object:ty = $enum:expr; $raw:ty, writable) => {
+        attribute!($attr for $object = $enum; $raw => $raw, |raw| raw, writable |value| *value);
+    };
+    ($attr:ident for $object:ty = $enum:expr; $raw:ty => $value:ty, |$r:ident| $from:expr) => {
+        unsafe impl Attribute<$object> for $attr {
+            type Raw = $raw;
+            type Value = $value;
+            const ATTRIBUTE: vx_enum = $enum as vx_enum;
+
+            unsafe fn from_raw($r: Self::Raw) -> Self::Value {
+                $from
+            }
+        }
+
+        impl Readable<$object> for $attr {}
+    };
+    (<$($g:ident),+> $attr:ident for $object:ty = $enum:expr; $raw:ty) => {
+        attribute!(<$($g),+> $attr for $object = $enum; $raw => $raw, |raw| raw);
+    };
+    (<$($g:ident),+> $attr:ident for $object:ty = $enum:expr;
+     $raw:ty => $value:ty, |$r:ident| $from:expr) => {
+        unsafe impl<$($g),+> Attribute<$object> for $attr {
+            type Raw = $raw;
+            type Value = $value;
+            const ATTRIBUTE: vx_enum = $enum as vx_enum;
+
+            unsafe fn from_raw($r: Self::Raw) -> Self::Value {
+                $from
+            }
+        }
+
+        impl<$($g),+> Readable<$object> for $attr {}
+    };
+    ($attr:ident for $object:ty = $enum:expr; $raw:ty => $value:ty, |$r:ident| $from:expr,
+     writable |$v:ident| $to:expr) => {
+        attribute!($attr for $object = $enum; $raw => $value, |$r| $from);
+
+        impl Writable<$object> for $attr {
+            fn to_raw($v: &Self::Value) -> Self::Raw {
+                $to
+            }
+        }
+    };
+}
+
+markers! {
+    /// The name of a reference (`VX_REFERENCE_NAME`), or `None` if no name was set.
+    Name;
+    /// The reference count of an object (`VX_REFERENCE_COUNT`).
+    ReferenceCount;
+    /// The type of a reference (`VX_REFERENCE_TYPE`).
+    ReferenceType;
+
+    /// The unique vendor ID (`VX_CONTEXT_VENDOR_ID`).
+    VendorId;
+    /// The OpenVX version of the implementation (`VX_CONTEXT_VERSION`).
+    Version;
+    /// The number of unique kernels in the system (`VX_CONTEXT_UNIQUE_KERNELS`).
+    UniqueKernels;
+    /// The number of loaded modules (`VX_CONTEXT_MODULES`).
+    Modules;
+    /// The number of live references in the context (`VX_CONTEXT_REFERENCES`).
+    References;
+    /// The implementation name (`VX_CONTEXT_IMPLEMENTATION`).
+    Implementation;
+    /// The size of the extension string in bytes (`VX_CONTEXT_EXTENSIONS_SIZE`).
+    ExtensionsSize;
+    /// The maximum width or height of a convolution matrix (`VX_CONTEXT_CONVOLUTION_MAX_DIMENSION`).
+    ConvolutionMaxDimension;
+    /// The maximum window dimension of the optical flow kernel
+    /// (`VX_CONTEXT_OPTICAL_FLOW_MAX_WINDOW_DIMENSION`).
+    OpticalFlowMaxWindowDimension;
+    /// The policy for unsupported border modes in immediate mode functions
+    /// (`VX_CONTEXT_IMMEDIATE_BORDER_POLICY`).
+    ImmediateBorderPolicy;
+    /// The maximum width or height of the non-linear filter mask
+    /// (`VX_CONTEXT_NONLINEAR_MAX_DIMENSION`).
+    NonlinearMaxDimension;
+    /// The maximum number of tensor dimensions supported (`VX_CONTEXT_MAX_TENSOR_DIMS`).
+    MaxTensorDims;
+
+    /// The border mode of a node (`VX_NODE_BORDER`) or of immediate mode functions
+    /// (`VX_CONTEXT_IMMEDIATE_BORDER`).
+    BorderMode;
+
+    /// The number of nodes in a graph (`VX_GRAPH_NUMNODES`).
+    NumNodes;
+    /// The number of parameters of a graph (`VX_GRAPH_NUMPARAMETERS`), node
+    /// (`VX_NODE_PARAMETERS`) or kernel (`VX_KERNEL_PARAMETERS`).
+    NumParameters;
+    /// The performance measurements of a graph (`VX_GRAPH_PERFORMANCE`) or node
+    /// (`VX_NODE_PERFORMANCE`).
+    Performance;
+    /// The state of a graph (`VX_GRAPH_STATE`) or parameter (`VX_PARAMETER_STATE`).
+    State;
+
+    /// The status of the last execution of a node (`VX_NODE_STATUS`).
+    Status;
+    /// The size of the local data area of a node (`VX_NODE_LOCAL_DATA_SIZE`) or kernel
+    /// (`VX_KERNEL_LOCAL_DATA_SIZE`).
+    LocalDataSize;
+    /// Whether a node is replicated (`VX_NODE_IS_REPLICATED`).
+    IsReplicated;
+    /// Whether the valid rectangles of a node's outputs are reset (`VX_NODE_VALID_RECT_RESET`).
+    ValidRectReset;
+
+    /// The name of a kernel (`VX_KERNEL_NAME`).
+    KernelName;
+    /// The enumeration of a kernel (`VX_KERNEL_ENUM`).
+    KernelEnum;
+
+    /// The index of a parameter (`VX_PARAMETER_INDEX`).
+    Index;
+    /// The direction of a parameter (`VX_PARAMETER_DIRECTION`).
+    Direction;
+    /// The type of a parameter, scalar, LUT, matrix, delay (`VX_*_TYPE`) or threshold
+    /// (`VX_THRESHOLD_TYPE`).
+    Type;
+
+    /// The width of an image (`VX_IMAGE_WIDTH`) or of level 0 of a pyramid (`VX_PYRAMID_WIDTH`).
+    Width;
+    /// The height of an image (`VX_IMAGE_HEIGHT`) or of level 0 of a pyramid (`VX_PYRAMID_HEIGHT`).
+    Height;
+    /// The format of an image (`VX_IMAGE_FORMAT`) or of the images of a pyramid (`VX_PYRAMID_FORMAT`).
+    Format;
+    /// The number of planes of an image (`VX_IMAGE_PLANES`).
+    Planes;
+    /// The color space of an image (`VX_IMAGE_SPACE`).
+    Space;
+    /// The channel range of an image (`VX_IMAGE_RANGE`) or the range of a distribution
+    /// (`VX_DISTRIBUTION_RANGE`).
+    Range;
+    /// The memory type of an image (`VX_IMAGE_MEMORY_TYPE`).
+    MemoryType;
+    /// Whether an image is uniform (`VX_IMAGE_IS_UNIFORM`).
+    IsUniform;
+    /// The value of a uniform image (`VX_IMAGE_UNIFORM_VALUE`).
+    UniformValue;
+    /// Whether an image is a raw image (`VX_IMAGE_IS_RAW`).
+    IsRaw;
+    /// The memory layout of the exposures of a raw image (`VX_IMAGE_RAW_EXPOSURE_INTERLEAVING`).
+    RawExposureInterleaving;
+    /// The number of meta data lines before the pixels of a raw image
+    /// (`VX_IMAGE_RAW_META_HEIGHT_BEFORE`).
+    RawMetaHeightBefore;
+    /// The number of meta data lines after the pixels of a raw image
+    /// (`VX_IMAGE_RAW_META_HEIGHT_AFTER`).
+    RawMetaHeightAfter;
+
+    /// The number of entries of a LUT (`VX_LUT_COUNT`).
+    Count;
+    /// The total size of a LUT, distribution, matrix or convolution in bytes (`VX_*_SIZE`).
+    Size;
+    /// The index of the input value equal to zero in a LUT (`VX_LUT_OFFSET`) or the start
+    /// offset of a distribution (`VX_DISTRIBUTION_OFFSET`).
+    Offset;
+
+    /// The number of dimensions of a distribution (`VX_DISTRIBUTION_DIMENSIONS`).
+    Dimensions;
+    /// The number of bins of a distribution (`VX_DISTRIBUTION_BINS`).
+    Bins;
+    /// The width of a bin of a distribution (`VX_DISTRIBUTION_WINDOW`).
+    Window;
+
+    /// The input image format a threshold was created for (`VX_THRESHOLD_INPUT_FORMAT`).
+    InputFormat;
+    /// The output image format a threshold was created for (`VX_THRESHOLD_OUTPUT_FORMAT`).
+    OutputFormat;
+
+    /// The number of rows of a matrix (`VX_MATRIX_ROWS`) or convolution (`VX_CONVOLUTION_ROWS`).
+    Rows;
+    /// The number of columns of a matrix (`VX_MATRIX_COLUMNS`) or convolution
+    /// (`VX_CONVOLUTION_COLUMNS`).
+    Columns;
+    /// The origin of a matrix (`VX_MATRIX_ORIGIN`).
+    Origin;
+    /// The pattern of a matrix (`VX_MATRIX_PATTERN`).
+    Pattern;
+    /// The scale of a convolution (`VX_CONVOLUTION_SCALE`) or the scale factor between
+    /// pyramid levels (`VX_PYRAMID_SCALE`).
+    Scale;
+
+    /// The number of levels of a pyramid (`VX_PYRAMID_LEVELS`).
+    Levels;
+
+    /// The source width of a remap (`VX_REMAP_SOURCE_WIDTH`).
+    SourceWidth;
+    /// The source height of a remap (`VX_REMAP_SOURCE_HEIGHT`).
+    SourceHeight;
+    /// The destination width of a remap (`VX_REMAP_DESTINATION_WIDTH`).
+    DestinationWidth;
+    /// The destination height of a remap (`VX_REMAP_DESTINATION_HEIGHT`).
+    DestinationHeight;
+
+    /// The type of the items of an array (`VX_ARRAY_ITEMTYPE`).
+    ItemType;
+    /// The number of items in an array (`VX_ARRAY_NUMITEMS`).
+    NumItems;
+    /// The maximal number of items an array can hold (`VX_ARRAY_CAPACITY`).
+    Capacity;
+    /// The size of an array item in bytes (`VX_ARRAY_ITEMSIZE`).
+    ItemSize;
+
+    /// The number of dimensions of a tensor (`VX_TENSOR_NUMBER_OF_DIMS`).
+    NumberOfDims;
+    /// The data type of a tensor (`VX_TENSOR_DATA_TYPE`).
+    DataType;
+    /// The fixed point position of a tensor (`VX_TENSOR_FIXED_POINT_POSITION`).
+    FixedPointPosition;
+
+    /// The number of objects in a delay (`VX_DELAY_SLOTS`).
+    Slots;
+}
+
+unsafe impl<O: QueryAttribute + ?Sized> Attribute<O> for Name {
+    type Raw = *const vx_char;
+    type Value = Option<String>;
+    const ATTRIBUTE: vx_enum = vx_reference_attribute_e_VX_REFERENCE_NAME as vx_enum;
+
+    unsafe fn from_raw(raw: Self::Raw) -> Self::Value {
+        if raw.is_null() {
+            None
+        } else {
+            Some(CStr::from_ptr(raw).to_string_lossy().into_owned())
+        }
+    }
+}
+
+impl<O: QueryAttribute + ?Sized> Readable<O> for Name {}
+
+unsafe impl<O: QueryAttribute + ?Sized> Attribute<O> for ReferenceCount {
+    type Raw = vx_uint32;
+    type Value = u32;
+    const ATTRIBUTE: vx_enum = vx_reference_attribute_e_VX_REFERENCE_COUNT as vx_enum;
+
+    unsafe fn from_raw(raw: Self::Raw) -> Self::Value {
+        raw
+    }
+}
+
+impl<O: QueryAttribute + ?Sized> Readable<O> for ReferenceCount {}
+
+unsafe impl<O: QueryAttribute + ?Sized> Attribute<O> for ReferenceType {
+    type Raw = vx_enum;
+    type Value = VxType;
+    const ATTRIBUTE: vx_enum = vx_reference_attribute_e_VX_REFERENCE_TYPE as vx_enum;
+
+    unsafe fn from_raw(raw: Self::Raw) -> Self::Value {
+        VxType::from(raw as vx_type_e)
+    }
+}
+
+impl<O: QueryAttribute + ?Sized> Readable<O> for ReferenceType {}
+
+// Context
+attribute!(VendorId for VxContext = vx_context_attribute_e_VX_CONTEXT_VENDOR_ID; vx_uint16);
+attribute!(Version for VxContext = vx_context_attribute_e_VX_CONTEXT_VERSION;
+    vx_uint16 => crate::VxVersion, |raw| crate::VxVersion::from(raw));
+attribute!(UniqueKernels for VxContext = vx_context_attribute_e_VX_CONTEXT_UNIQUE_KERNELS; vx_uint32);
+attribute!(Modules for VxContext = vx_context_attribute_e_VX_CONTEXT_MODULES; vx_uint32);
+attribute!(References for VxContext = vx_context_attribute_e_VX_CONTEXT_REFERENCES; vx_uint32);
+attribute!(Implementation for VxContext = vx_context_attribute_e_VX_CONTEXT_IMPLEMENTATION;
+    [vx_char; VX_MAX_IMPLEMENTATION_NAME as usize] => String, |raw| string_from_chars(&raw));
+attribute!(ExtensionsSize for VxContext = vx_context_attribute_e_VX_CONTEXT_EXTENSIONS_SIZE; vx_size);
+attribute!(ConvolutionMaxDimension for VxContext =
+    vx_context_attribute_e_VX_CONTEXT_CONVOLUTION_MAX_DIMENSION; vx_size);
+attribute!(OpticalFlowMaxWindowDimension for VxContext =
+    vx_context_attribute_e_VX_CONTEXT_OPTICAL_FLOW_MAX_WINDOW_DIMENSION; vx_size);
+attribute!(BorderMode for VxContext = vx_context_attribute_e_VX_CONTEXT_IMMEDIATE_BORDER;
+    vx_border_t => crate::Border, |raw| crate::Border::from(raw), writable |value| value.to_raw());
+attribute!(ImmediateBorderPolicy for VxContext =
+    vx_context_attribute_e_VX_CONTEXT_IMMEDIATE_BORDER_POLICY;
+    vx_enum => crate::BorderPolicy, |raw| crate::BorderPolicy::from(raw as vx_border_policy_e));
+attribute!(NonlinearMaxDimension for VxContext =
+    vx_context_attribute_e_VX_CONTEXT_NONLINEAR_MAX_DIMENSION; vx_size);
+attribute!(MaxTensorDims for VxContext = vx_context_attribute_e_VX_CONTEXT_MAX_TENSOR_DIMS; vx_size);
+
+// Graph
+attribute!(NumNodes for VxGraph<'_> = vx_graph_attribute_e_VX_GRAPH_NUMNODES; vx_uint32);
+attribute!(Performance for VxGraph<'_> = vx_graph_attribute_e_VX_GRAPH_PERFORMANCE;
+    vx_perf_t => crate::Performance, |raw| crate::Performance::from(raw));
+attribute!(NumParameters for VxGraph<'_> = vx_graph_attribute_e_VX_GRAPH_NUMPARAMETERS; vx_uint32);
+attribute!(State for VxGraph<'_> = vx_graph_attribute_e_VX_GRAPH_STATE;
+    vx_enum => VxGraphState, |raw| VxGraphState::from(raw as vx_graph_state_e));
+
+// Node
+attribute!(Status for VxNode<'_> = vx_node_attribute_e_VX_NODE_STATUS;
+    vx_status => VxStatus, |raw| VxStatus::from(raw as vx_status_e));
+attribute!(Performance for VxNode<'_> = vx_node_attribute_e_VX_NODE_PERFORMANCE;
+    vx_perf_t => crate::Performance, |raw| crate::Performance::from(raw));
+attribute!(BorderMode for VxNode<'_> = vx_node_attribute_e_VX_NODE_BORDER;
+    vx_border_t => crate::Border, |raw| crate::Border::from(raw), writable |value| value.to_raw());
+attribute!(LocalDataSize for VxNode<'_> = vx_node_attribute_e_VX_NODE_LOCAL_DATA_SIZE; vx_size, writable);
+attribute!(NumParameters for VxNode<'_> = vx_node_attribute_e_VX_NODE_PARAMETERS; vx_uint32);
+attribute!(IsReplicated for VxNode<'_> = vx_node_attribute_e_VX_NODE_IS_REPLICATED;
+    vx_bool => bool, |raw| bool_from_raw(raw));
+attribute!(ValidRectReset for VxNode<'_> = vx_node_attribute_e_VX_NODE_VALID_RECT_RESET;
+    vx_bool => bool, |raw| bool_from_raw(raw), writable |value| bool_to_raw(*value));
+
+// Kernel
+attribute!(NumParameters for VxKernel<'_> = vx_kernel_attribute_e_VX_KERNEL_PARAMETERS; vx_uint32);
+attribute!(KernelName for VxKernel<'_> = vx_kernel_attribute_e_VX_KERNEL_NAME;
+    [vx_char; VX_MAX_KERNEL_NAME as usize] => String, |raw| string_from_chars(&raw));
+attribute!(KernelEnum for VxKernel<'_> = vx_kernel_attribute_e_VX_KERNEL_ENUM; vx_enum);
+attribute!(LocalDataSize for VxKernel<'_> = vx_kernel_attribute_e_VX_KERNEL_LOCAL_DATA_SIZE;
+    vx_size, writable);
+
+// Parameter
+attribute!(Index for VxParameter<'_> = vx_parameter_attribute_e_VX_PARAMETER_INDEX; vx_uint32);
+attribute!(Direction for VxParameter<'_> = vx_parameter_attribute_e_VX_PARAMETER_DIRECTION;
+    vx_enum => crate::Direction, |raw| crate::Direction::from(raw as vx_direction_e));
+attribute!(Type for VxParameter<'_> = vx_parameter_attribute_e_VX_PARAMETER_TYPE;
+    vx_enum => VxType, |raw| VxType::from(raw as vx_type_e));
+attribute!(State for VxParameter<'_> = vx_parameter_attribute_e_VX_PARAMETER_STATE;
+    vx_enum => crate::ParameterState, |raw| crate::ParameterState::from(raw as vx_parameter_state_e));
+
+// Image
+attribute!(Width for VxImage<'_> = vx_image_attribute_e_VX_IMAGE_WIDTH; vx_uint32);
+attribute!(Height for VxImage<'_> = vx_image_attribute_e_VX_IMAGE_HEIGHT; vx_uint32);
+attribute!(Format for VxImage<'_> = vx_image_attribute_e_VX_IMAGE_FORMAT;
+    vx_df_image => ImageType, |raw| ImageType::from(raw));
+attribute!(Planes for VxImage<'_> = vx_image_attribute_e_VX_IMAGE_PLANES; vx_size);
+attribute!(Space for VxImage<'_> = vx_image_attribute_e_VX_IMAGE_SPACE;
+    vx_enum => crate::ColorSpace, |raw| crate::ColorSpace::from(raw as vx_color_space_e),
+    writable |value| value.to_raw() as vx_enum);
+attribute!(Range for VxImage<'_> = vx_image_attribute_e_VX_IMAGE_RANGE;
+    vx_enum => crate::ChannelRange, |raw| crate::ChannelRange::from(raw as vx_channel_range_e));
+attribute!(MemoryType for VxImage<'_> = vx_image_attribute_e_VX_IMAGE_MEMORY_TYPE;
+    vx_enum => crate::MemoryType, |raw| crate::MemoryType::from(raw as vx_memory_type_e));
+attribute!(IsUniform for VxImage<'_> = vx_image_attribute_e_VX_IMAGE_IS_UNIFORM;
+    vx_bool => bool, |raw| bool_from_raw(raw));
+attribute!(UniformValue for VxImage<'_> = vx_image_attribute_e_VX_IMAGE_UNIFORM_VALUE;
+    vx_pixel_value_t => PixelValue, |raw| PixelValue::from(raw));
+
+// Raw image (vx_khr_raw_image); bindgen does not translate the attribute macros of the
+// extension, which continue the image attributes at offset 0xA.
+attribute!(IsRaw for VxImage<'_> = vx_image_attribute_e_VX_IMAGE_WIDTH + 0xA;
+    vx_bool => bool, |raw| bool_from_raw(raw));
+attribute!(RawExposureInterleaving for VxImage<'_> = vx_image_attribute_e_VX_IMAGE_WIDTH + 0xB;
+    vx_enum => crate::ExposureInterleaving,
+    |raw| crate::ExposureInterleaving::from(raw as vx_image_raw_exposure_interleaving_e));
+attribute!(RawMetaHeightBefore for VxImage<'_> = vx_image_attribute_e_VX_IMAGE_WIDTH + 0xD;
+    vx_uint32);
+attribute!(RawMetaHeightAfter for VxImage<'_> = vx_image_attribute_e_VX_IMAGE_WIDTH + 0xE;
+    vx_uint32);
+
+// Scalar
+attribute!(<T> Type for VxScalar<'_, T> = vx_scalar_attribute_e_VX_SCALAR_TYPE;
+    vx_enum => VxType, |raw| VxType::from(raw as vx_type_e));
+
+// LUT
+attribute!(Type for VxLut<'_> = vx_lut_attribute_e_VX_LUT_TYPE;
+    vx_enum => VxType, |raw| VxType::from(raw as vx_type_e));
+attribute!(Count for VxLut<'_> = vx_lut_attribute_e_VX_LUT_COUNT; vx_size);
+attribute!(Size for VxLut<'_> = vx_lut_attribute_e_VX_LUT_SIZE; vx_size);
+attribute!(Offset for VxLut<'_> = vx_lut_attribute_e_VX_LUT_OFFSET; vx_uint32);
+
+// Distribution
+attribute!(Dimensions for VxDistribution<'_> =
+    vx_distribution_attribute_e_VX_DISTRIBUTION_DIMENSIONS; vx_size);
+attribute!(Offset for VxDistribution<'_> = vx_distribution_attribute_e_VX_DISTRIBUTION_OFFSET; vx_int32);
+attribute!(Range for VxDistribution<'_> = vx_distribution_attribute_e_VX_DISTRIBUTION_RANGE; vx_uint32);
+attribute!(Bins for VxDistribution<'_> = vx_distribution_attribute_e_VX_DISTRIBUTION_BINS; vx_size);
+attribute!(Window for VxDistribution<'_> = vx_distribution_attribute_e_VX_DISTRIBUTION_WINDOW; vx_uint32);
+attribute!(Size for VxDistribution<'_> = vx_distribution_attribute_e_VX_DISTRIBUTION_SIZE; vx_size);
+
+// Threshold
+attribute!(Type for VxThreshold<'_> = vx_threshold_attribute_e_VX_THRESHOLD_TYPE;
+    vx_enum => crate::ThresholdType, |raw| crate::ThresholdType::from(raw as vx_threshold_type_e));
+attribute!(InputFormat for VxThreshold<'_> = vx_threshold_attribute_e_VX_THRESHOLD_INPUT_FORMAT;
+    vx_df_image => ImageType, |raw| ImageType::from(raw));
+attribute!(OutputFormat for VxThreshold<'_> = vx_threshold_attribute_e_VX_THRESHOLD_OUTPUT_FORMAT;
+    vx_df_image => ImageType, |raw| ImageType::from(raw));
+
+// Matrix
+attribute!(<T> Type for VxMatrix<'_, T> = vx_matrix_attribute_e_VX_MATRIX_TYPE;
+    vx_enum => VxType, |raw| VxType::from(raw as vx_type_e));
+attribute!(<T> Rows for VxMatrix<'_, T> = vx_matrix_attribute_e_VX_MATRIX_ROWS; vx_size);
+attribute!(<T> Columns for VxMatrix<'_, T> = vx_matrix_attribute_e_VX_MATRIX_COLUMNS; vx_size);
+attribute!(<T> Size for VxMatrix<'_, T> = vx_matrix_attribute_e_VX_MATRIX_SIZE; vx_size);
+attribute!(<T> Origin for VxMatrix<'_, T> = vx_matrix_attribute_e_VX_MATRIX_ORIGIN;
+    vx_coordinates2d_t => crate::Coordinates2d, |raw| crate::Coordinates2d::from(raw));
+attribute!(<T> Pattern for VxMatrix<'_, T> = vx_matrix_attribute_e_VX_MATRIX_PATTERN;
+    vx_enum => crate::Pattern, |raw| crate::Pattern::from(raw as vx_pattern_e));
+
+// Convolution
+attribute!(Rows for VxConvolution<'_> = vx_convolution_attribute_e_VX_CONVOLUTION_ROWS; vx_size);
+attribute!(Columns for VxConvolution<'_> = vx_convolution_attribute_e_VX_CONVOLUTION_COLUMNS; vx_size);
+attribute!(Scale for VxConvolution<'_> = vx_convolution_attribute_e_VX_CONVOLUTION_SCALE;
+    vx_uint32, writable);
+attribute!(Size for VxConvolution<'_> = vx_convolution_attribute_e_VX_CONVOLUTION_SIZE; vx_size);
+
+// Pyramid
+attribute!(Levels for VxPyramid<'_> = vx_pyramid_attribute_e_VX_PYRAMID_LEVELS; vx_size);
+attribute!(Scale for VxPyramid<'_> = vx_pyramid_attribute_e_VX_PYRAMID_SCALE; vx_float32);
+attribute!(Width for VxPyramid<'_> = vx_pyramid_attribute_e_VX_PYRAMID_WIDTH; vx_uint32);
+attribute!(Height for VxPyramid<'_> = vx_pyramid_attribute_e_VX_PYRAMID_HEIGHT; vx_uint32);
+attribute!(Format for VxPyramid<'_> = vx_pyramid_attribute_e_VX_PYRAMID_FORMAT;
+    vx_df_image => ImageType, |raw| ImageType::from(raw));
+
+// Remap
+attribute!(SourceWidth for VxRemap<'_> = vx_remap_attribute_e_VX_REMAP_SOURCE_WIDTH; vx_uint32);
+attribute!(SourceHeight for VxRemap<'_> = vx_remap_attribute_e_VX_REMAP_SOURCE_HEIGHT; vx_uint32);
+attribute!(DestinationWidth for VxRemap<'_> =
+    vx_remap_attribute_e_VX_REMAP_DESTINATION_WIDTH; vx_uint32);
+attribute!(DestinationHeight for VxRemap<'_> =
+    vx_remap_attribute_e_VX_REMAP_DESTINATION_HEIGHT; vx_uint32);
+
+// Array
+attribute!(<T> ItemType for VxArray<'_, T> = vx_array_attribute_e_VX_ARRAY_ITEMTYPE;
+    vx_enum => VxType, |raw| VxType::from(raw as vx_type_e));
+attribute!(<T> NumItems for VxArray<'_, T> = vx_array_attribute_e_VX_ARRAY_NUMITEMS; vx_size);
+attribute!(<T> Capacity for VxArray<'_, T> = vx_array_attribute_e_VX_ARRAY_CAPACITY; vx_size);
+attribute!(<T> ItemSize for VxArray<'_, T> = vx_array_attribute_e_VX_ARRAY_ITEMSIZE; vx_size);
+
+// Tensor
+attribute!(NumberOfDims for VxTensor<'_> = vx_tensor_attribute_e_VX_TENSOR_NUMBER_OF_DIMS; vx_size);
+attribute!(DataType for VxTensor<'_> = vx_tensor_attribute_e_VX_TENSOR_DATA_TYPE;
+    vx_enum => VxType, |raw| VxType::from(raw as vx_type_e));
+attribute!(FixedPointPosition for VxTensor<'_> =
+    vx_tensor_attribute_e_VX_TENSOR_FIXED_POINT_POSITION; vx_int8);
+
+// Delay
+attribute!(Type for VxDelay<'_> = vx_delay_attribute_e_VX_DELAY_TYPE;
+    vx_enum => VxType, |raw| VxType::from(raw as vx_type_e));
+attribute!(Slots for VxDelay<'_> = vx_delay_attribute_e_VX_DELAY_SLOTS; vx_size);
+
+fn bool_from_raw(raw: vx_bool) -> bool {
+    raw != vx_bool_e_vx_false_e as vx_bool
+}
+
+fn bool_to_raw(value: bool) -> vx_bool {
+    if value {
+        vx_bool_e_vx_true_e as vx_bool
+    } else {
+        vx_bool_e_vx_false_e as vx_bool
+    }
+}
+
+pub(crate) fn string_from_chars(chars: &[vx_char]) -> String {
+    let bytes: Vec<u8> = chars
+        .iter()
+        .take_while(|&&c| c != 0)
+        .map(|&c| c as u8)
+        .collect();
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reference_attributes_are_dispatched_to_vx_query_reference() {
+        assert_eq!(
+            vx_type!(<Name as Attribute<VxImage>>::ATTRIBUTE),
+            vx_type_e_VX_TYPE_REFERENCE
+        );
+        assert_ne!(
+            vx_type!(<Width as Attribute<VxImage>>::ATTRIBUTE),
+            vx_type_e_VX_TYPE_REFERENCE
+        );
+    }
+
+    #[test]
+    fn shared_markers_map_to_object_attributes() {
+        assert_eq!(
+            <Scale as Attribute<VxConvolution>>::ATTRIBUTE,
+            vx_convolution_attribute_e_VX_CONVOLUTION_SCALE as vx_enum
+        );
+        assert_eq!(
+            <Scale as Attribute<VxPyramid>>::ATTRIBUTE,
+            vx_pyramid_attribute_e_VX_PYRAMID_SCALE as vx_enum
+        );
+    }
+
+    #[test]
+    fn raw_image_attributes_extend_image_attributes() {
+        // VX_ATTRIBUTE_BASE(VX_ID_KHRONOS, VX_TYPE_IMAGE) + 0xA
+        assert_eq!(<IsRaw as Attribute<VxImage>>::ATTRIBUTE, 0x80F0A);
+        assert_eq!(
+            <RawMetaHeightAfter as Attribute<VxImage>>::ATTRIBUTE,
+            0x80F0E
+        );
+    }
+
+    #[test]
+    fn string_from_chars_stops_at_nul() {
+        let mut chars = [0 as vx_char; 8];
+        for (c, b) in chars.iter_mut().zip(b"khr") {
+            *c = *b as vx_char;
+        }
+        assert_eq!(string_from_chars(&chars), "khr");
+    }
+
+    #[test]
+    fn bool_roundtrip() {
+        assert!(bool_from_raw(bool_to_raw(true)));
+        assert!(!bool_from_raw(bool_to_raw(false)));
+    }
+}
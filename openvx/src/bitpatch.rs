@@ -0,0 +1,251 @@
+use crate::error::check_call;
+use crate::mapping::Mapping;
+use crate::{
+    attr, Access, AsRaw, ImageAddressing, ImageType, MemoryType, QueryAttribute, Rect, Release,
+    Result, VxError, VxImage,
+};
+use libopenvx_sys::{
+    vxMapImagePatch, vxUnmapImagePatch, vx_enum, vx_image, vx_imagepatch_addressing_t,
+    vx_map_flag_e_VX_NOGAP_X, vx_map_id, vx_uint32,
+};
+use std::ffi::c_void;
+use std::marker::PhantomData;
+
+/// A patch of a bit-packed `U1` image mapped into host memory by
+/// [`VxImage::map_bits`](struct.VxImage.html#method.map_bits).
+///
+/// Each row starts at a byte boundary and holds one bit per pixel, starting with the least
+/// significant bit. Pixels are addressed in coordinates of the patch. The patch is unmapped
+/// by calling [`vxUnmapImagePatch`] when the guard is dropped.
+///
+/// [`vxUnmapImagePatch`]: ../libopenvx_sys/fn.vxUnmapImagePatch.html
+#[derive(Debug)]
+pub struct BitPatch<'i> {
+    mapping: Mapping<vx_image>,
+    addressing: ImageAddressing,
+    offset: u32,
+    _marker: PhantomData<&'i ()>,
+}
+
+impl<'i> BitPatch<'i> {
+    /// Maps the patch by calling [`vxMapImagePatch`].
+    ///
+    /// [`vxMapImagePatch`]: ../libopenvx_sys/fn.vxMapImagePatch.html
+    pub(crate) fn map(image: &'i VxImage<'_>, rect: Rect, access: Access) -> Result<Self> {
+        if image.query::<attr::Format>()? != ImageType::U1 {
+            return Err(VxError::NotCompatible.into());
+        }
+
+        let raw_rect = rect.to_raw();
+        let mut map_id: vx_map_id = 0;
+        let mut addressing: vx_imagepatch_addressing_t = unsafe { std::mem::zeroed() };
+        let mut ptr: *mut c_void = std::ptr::null_mut();
+        check_call("vxMapImagePatch", image, || unsafe {
+            vxMapImagePatch(
+                image.as_raw(),
+                &raw_rect,
+                0,
+                &mut map_id,
+                &mut addressing,
+                &mut ptr,
+                access.to_raw() as vx_enum,
+                MemoryType::Host.to_raw() as vx_enum,
+                vx_map_flag_e_VX_NOGAP_X as vx_uint32,
+            )
+        })?;
+
+        let patch = Self {
+            mapping: Mapping::new(image.as_raw(), map_id, ptr, access, vxUnmapImagePatch),
+            addressing: ImageAddressing::from(addressing),
+            offset: rect.start_x % 8,
+            _marker: PhantomData,
+        };
+
+        if patch.addressing.stride_x_bits != 1 || patch.addressing.stride_y < 0 {
+            return Err(VxError::NotSupported.into());
+        }
+        patch.mapping.check_layout::<u8>(&[])?;
+        Ok(patch)
+    }
+
+    /// Returns the addressing structure describing the memory layout of the patch.
+    pub fn addressing(&self) -> &ImageAddressing {
+        &self.addressing
+    }
+
+    /// Returns the accessor the patch was mapped with.
+    pub fn access(&self) -> Access {
+        self.mapping.access()
+    }
+
+    /// Returns the number of pixels per row.
+    pub fn width(&self) -> usize {
+        self.addressing.dim_x as usize
+    }
+
+    /// Returns the number of rows.
+    pub fn height(&self) -> usize {
+        self.addressing.dim_y as usize
+    }
+
+    /// Returns the pixel at column `x` of row `y`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the coordinates are out of bounds or the patch was mapped write-only.
+    pub fn get(&self, x: usize, y: usize) -> bool {
+        assert!(
+            self.mapping.access().is_readable(),
+            "patch is not mapped for reading"
+        );
+        let (byte, bit) = self.locate(x, y);
+        unsafe { *self.mapping.ptr().add(byte) & (1 << bit) != 0 }
+    }
+
+    /// Sets the pixel at column `x` of row `y`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the coordinates are out of bounds or the patch was mapped read-only.
+    pub fn set(&mut self, x: usize, y: usize, value: bool) {
+        assert!(
+            self.mapping.access().is_writable(),
+            "patch is not mapped for writing"
+        );
+        let (byte, bit) = self.locate(x, y);
+        unsafe {
+            let byte = &mut *self.mapping.ptr().add(byte);
+            if value {
+                *byte |= 1 << bit;
+            } else {
+                *byte &= !(1 << bit);
+            }
+        }
+    }
+
+    /// Returns an iterator over the pixels of row `y`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `y` is out of bounds or the patch was mapped write-only.
+    pub fn row(&self, y: usize) -> impl Iterator<Item = bool> + '_ {
+        assert!(y < self.height(), "row {} out of bounds", y);
+        (0..self.width()).map(move |x| self.get(x, y))
+    }
+
+    /// Returns an iterator over all pixels of the patch, row by row.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the patch was mapped write-only.
+    pub fn iter(&self) -> impl Iterator<Item = bool> + '_ {
+        (0..self.height()).flat_map(move |y| self.row(y))
+    }
+
+    /// Returns the byte and bit index of a pixel.
+    fn locate(&self, x: usize, y: usize) -> (usize, usize) {
+        assert!(
+            x < self.width() && y < self.height(),
+            "pixel ({}, {}) out of bounds",
+            x,
+            y
+        );
+        let x = x + self.offset as usize;
+        (y * self.addressing.stride_y as usize + x / 8, x % 8)
+    }
+}
+
+impl Release for BitPatch<'_> {
+    fn release(&mut self) -> Result<()> {
+        self.mapping.release()
+    }
+}
+
+/// Returns the addressing of host memory holding the region `rect` of a `U1` image,
+/// with rows starting at bit `rect.start_x % 8` of their first byte.
+pub(crate) fn bit_addressing(rect: Rect) -> ImageAddressing {
+    let offset = rect.start_x % 8;
+    let stride = (offset + rect.width()).div_ceil(8);
+    let mut addressing = ImageAddressing::new(rect.width(), rect.height(), 0, stride as i32);
+    addressing.stride_x_bits = 1;
+    addressing
+}
+
+/// Packs row-major pixels into rows of the given addressing.
+pub(crate) fn pack_bits(bits: &[bool], addressing: &ImageAddressing, offset: u32) -> Vec<u8> {
+    let (width, height) = (addressing.dim_x as usize, addressing.dim_y as usize);
+    let stride = addressing.stride_y as usize;
+    let mut data = vec![0u8; stride * height];
+    for (y, row) in bits.chunks(width.max(1)).take(height).enumerate() {
+        for (x, _) in row.iter().enumerate().filter(|(_, &bit)| bit) {
+            let x = x + offset as usize;
+            data[y * stride + x / 8] |= 1 << (x % 8);
+        }
+    }
+    data
+}
+
+/// Unpacks rows of the given addressing into row-major pixels.
+pub(crate) fn unpack_bits(data: &[u8], addressing: &ImageAddressing, offset: u32) -> Vec<bool> {
+    let (width, height) = (addressing.dim_x as usize, addressing.dim_y as usize);
+    let stride = addressing.stride_y as usize;
+    (0..height)
+        .flat_map(|y| {
+            (0..width).map(move |x| {
+                let x = x + offset as usize;
+                data[y * stride + x / 8] & (1 << (x % 8)) != 0
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn packs_rows_at_byte_boundaries() {
+        let rect = Rect::from_size(0, 0, 10, 2);
+        let addressing = bit_addressing(rect);
+        assert_eq!(addressing.stride_y, 2);
+
+        let mut bits = vec![false; 20];
+        bits[0] = true;
+        bits[9] = true;
+        bits[12] = true;
+        let data = pack_bits(&bits, &addressing, 0);
+        assert_eq!(data, [0b0000_0001, 0b0000_0010, 0b0000_0100, 0]);
+        assert_eq!(unpack_bits(&data, &addressing, 0), bits);
+    }
+
+    #[test]
+    fn packs_unaligned_regions() {
+        let rect = Rect::from_size(6, 0, 4, 1);
+        let addressing = bit_addressing(rect);
+        assert_eq!(addressing.stride_y, 2);
+
+        let bits = [true, false, false, true];
+        let data = pack_bits(&bits, &addressing, 6);
+        assert_eq!(data, [0b0100_0000, 0b0000_0010]);
+        assert_eq!(unpack_bits(&data, &addressing, 6), bits);
+    }
+
+    #[test]
+    fn mapped_bits() {
+        let mut data = vec![0u8; 4];
+        let mut patch = BitPatch {
+            mapping: Mapping::detached(std::ptr::null_mut(), data.as_mut_ptr(), Access::ReadWrite),
+            addressing: bit_addressing(Rect::from_size(3, 0, 9, 2)),
+            offset: 3,
+            _marker: PhantomData,
+        };
+
+        patch.set(0, 0, true);
+        patch.set(8, 1, true);
+        assert!(patch.get(0, 0) && !patch.get(1, 0));
+        assert_eq!(patch.iter().filter(|&bit| bit).count(), 2);
+        patch.set(0, 0, false);
+        assert_eq!(patch.row(1).position(|bit| bit), Some(8));
+        assert_eq!(data, [0, 0, 0, 0b0000_1000]);
+    }
+}
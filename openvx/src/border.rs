@@ -0,0 +1,93 @@
+use crate::PixelValue;
+use core::fmt;
+use libopenvx_sys::*;
+
+/// Describes how border pixels are handled by a kernel, i.e. the `VX_NODE_BORDER`
+/// and `VX_CONTEXT_IMMEDIATE_BORDER` attributes.
+#[derive(Debug, Default, Eq, PartialEq, Hash, Copy, Clone)]
+pub enum Border {
+    /// No defined border mode behavior is given.
+    #[default]
+    Undefined,
+    /// For nodes that support this behavior, a constant value is filled-in when accessing
+    /// out-of-bounds pixels.
+    Constant(PixelValue),
+    /// For nodes that support this behavior, a replication of the nearest edge pixels value
+    /// is given for out-of-bounds pixels.
+    Replicate,
+    /// A vendor-defined or otherwise unknown border mode.
+    Other { mode: vx_border_e },
+}
+
+impl Border {
+    pub fn new(border: vx_border_t) -> Self {
+        #[allow(non_upper_case_globals)]
+        match border.mode as vx_border_e {
+            vx_border_e_VX_BORDER_UNDEFINED => Border::Undefined,
+            vx_border_e_VX_BORDER_CONSTANT => {
+                Border::Constant(PixelValue::from(border.constant_value))
+            }
+            vx_border_e_VX_BORDER_REPLICATE => Border::Replicate,
+            mode => Border::Other { mode },
+        }
+    }
+
+    /// Returns the border mode enumeration of this instance.
+    pub const fn mode(&self) -> vx_border_e {
+        match self {
+            Border::Undefined => vx_border_e_VX_BORDER_UNDEFINED,
+            Border::Constant(_) => vx_border_e_VX_BORDER_CONSTANT,
+            Border::Replicate => vx_border_e_VX_BORDER_REPLICATE,
+            Border::Other { mode } => *mode,
+        }
+    }
+
+    /// Converts this instance into a [`vx_border_t`].
+    ///
+    /// [`vx_border_t`]: ../libopenvx_sys/type.vx_border_t.html
+    pub fn to_raw(&self) -> vx_border_t {
+        let constant_value = match self {
+            Border::Constant(value) => *value,
+            _ => PixelValue::zero(),
+        };
+
+        vx_border_t {
+            mode: self.mode() as vx_enum,
+            constant_value: constant_value.to_raw(),
+        }
+    }
+}
+
+impl From<vx_border_t> for Border {
+    fn from(value: vx_border_t) -> Self {
+        Border::new(value)
+    }
+}
+
+impl fmt::Display for Border {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Border::Undefined => write!(f, "VX_BORDER_UNDEFINED"),
+            Border::Constant(value) => write!(f, "VX_BORDER_CONSTANT ({:?})", value.as_bytes()),
+            Border::Replicate => write!(f, "VX_BORDER_REPLICATE"),
+            Border::Other { mode } => write!(f, "VX_BORDER_OTHER ({})", mode),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roundtrip() {
+        for border in [
+            Border::Undefined,
+            Border::Constant(PixelValue::from_u8(42)),
+            Border::Replicate,
+            Border::Other { mode: 0xFFFF },
+        ] {
+            assert_eq!(Border::from(border.to_raw()), border);
+        }
+    }
+}
@@ -0,0 +1,76 @@
+use core::fmt;
+use libopenvx_sys::*;
+
+/// The unsupported border mode policy list used by the `VX_CONTEXT_IMMEDIATE_BORDER_POLICY`
+/// attribute of a `vx_context`.
+#[derive(Debug, Eq, PartialEq, Hash, Copy, Clone)]
+pub enum BorderPolicy {
+    /// Use `VX_BORDER_UNDEFINED` instead of unsupported border modes.
+    DefaultToUndefined,
+    /// Return `VX_ERROR_NOT_SUPPORTED` for unsupported border modes.
+    ReturnError,
+    /// A vendor-defined or otherwise unknown value.
+    Other { policy: vx_border_policy_e },
+}
+
+impl BorderPolicy {
+    pub const fn new(policy: vx_border_policy_e) -> Self {
+        #[allow(non_upper_case_globals)]
+        match policy {
+            vx_border_policy_e_VX_BORDER_POLICY_DEFAULT_TO_UNDEFINED => {
+                BorderPolicy::DefaultToUndefined
+            }
+            vx_border_policy_e_VX_BORDER_POLICY_RETURN_ERROR => BorderPolicy::ReturnError,
+            policy => BorderPolicy::Other { policy },
+        }
+    }
+
+    /// Converts this instance into a [`vx_border_policy_e`].
+    ///
+    /// [`vx_border_policy_e`]: ../libopenvx_sys/type.vx_border_policy_e.html
+    pub const fn to_raw(&self) -> vx_border_policy_e {
+        match self {
+            BorderPolicy::DefaultToUndefined => {
+                vx_border_policy_e_VX_BORDER_POLICY_DEFAULT_TO_UNDEFINED
+            }
+            BorderPolicy::ReturnError => vx_border_policy_e_VX_BORDER_POLICY_RETURN_ERROR,
+            BorderPolicy::Other { policy } => *policy,
+        }
+    }
+}
+
+impl From<vx_border_policy_e> for BorderPolicy {
+    fn from(value: vx_border_policy_e) -> Self {
+        BorderPolicy::new(value)
+    }
+}
+
+impl fmt::Display for BorderPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BorderPolicy::DefaultToUndefined => write!(f, "VX_BORDER_POLICY_DEFAULT_TO_UNDEFINED"),
+            BorderPolicy::ReturnError => write!(f, "VX_BORDER_POLICY_RETURN_ERROR"),
+            BorderPolicy::Other { policy } => write!(f, "VX_BORDER_POLICY_OTHER ({})", policy),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roundtrip() {
+        for value in [BorderPolicy::DefaultToUndefined, BorderPolicy::ReturnError] {
+            assert_eq!(BorderPolicy::from(value.to_raw()), value);
+        }
+    }
+
+    #[test]
+    fn other() {
+        assert_eq!(
+            BorderPolicy::new(0xFFFF),
+            BorderPolicy::Other { policy: 0xFFFF }
+        );
+    }
+}
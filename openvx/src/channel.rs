@@ -0,0 +1,125 @@
+use core::fmt;
+use libopenvx_sys::*;
+
+/// The channel enumerations for channel extractions.
+#[derive(Debug, Eq, PartialEq, Hash, Copy, Clone)]
+pub enum Channel {
+    /// Used by formats with unknown channel types.
+    C0,
+    /// Used by formats with unknown channel types.
+    C1,
+    /// Used by formats with unknown channel types.
+    C2,
+    /// Used by formats with unknown channel types.
+    C3,
+    /// Use to extract the red channel, no matter the byte or packing order.
+    R,
+    /// Use to extract the green channel, no matter the byte or packing order.
+    G,
+    /// Use to extract the blue channel, no matter the byte or packing order.
+    B,
+    /// Use to extract the alpha channel, no matter the byte or packing order.
+    A,
+    /// Use to extract the luma channel, no matter the byte or packing order.
+    Y,
+    /// Use to extract the Cb/U channel, no matter the byte or packing order.
+    U,
+    /// Use to extract the Cr/V/Value channel, no matter the byte or packing order.
+    V,
+    /// A vendor-defined or otherwise unknown value.
+    Other { channel: vx_channel_e },
+}
+
+impl Channel {
+    pub const fn new(channel: vx_channel_e) -> Self {
+        #[allow(non_upper_case_globals)]
+        match channel {
+            vx_channel_e_VX_CHANNEL_0 => Channel::C0,
+            vx_channel_e_VX_CHANNEL_1 => Channel::C1,
+            vx_channel_e_VX_CHANNEL_2 => Channel::C2,
+            vx_channel_e_VX_CHANNEL_3 => Channel::C3,
+            vx_channel_e_VX_CHANNEL_R => Channel::R,
+            vx_channel_e_VX_CHANNEL_G => Channel::G,
+            vx_channel_e_VX_CHANNEL_B => Channel::B,
+            vx_channel_e_VX_CHANNEL_A => Channel::A,
+            vx_channel_e_VX_CHANNEL_Y => Channel::Y,
+            vx_channel_e_VX_CHANNEL_U => Channel::U,
+            vx_channel_e_VX_CHANNEL_V => Channel::V,
+            channel => Channel::Other { channel },
+        }
+    }
+
+    /// Converts this instance into a [`vx_channel_e`].
+    ///
+    /// [`vx_channel_e`]: ../libopenvx_sys/type.vx_channel_e.html
+    pub const fn to_raw(&self) -> vx_channel_e {
+        match self {
+            Channel::C0 => vx_channel_e_VX_CHANNEL_0,
+            Channel::C1 => vx_channel_e_VX_CHANNEL_1,
+            Channel::C2 => vx_channel_e_VX_CHANNEL_2,
+            Channel::C3 => vx_channel_e_VX_CHANNEL_3,
+            Channel::R => vx_channel_e_VX_CHANNEL_R,
+            Channel::G => vx_channel_e_VX_CHANNEL_G,
+            Channel::B => vx_channel_e_VX_CHANNEL_B,
+            Channel::A => vx_channel_e_VX_CHANNEL_A,
+            Channel::Y => vx_channel_e_VX_CHANNEL_Y,
+            Channel::U => vx_channel_e_VX_CHANNEL_U,
+            Channel::V => vx_channel_e_VX_CHANNEL_V,
+            Channel::Other { channel } => *channel,
+        }
+    }
+}
+
+impl From<vx_channel_e> for Channel {
+    fn from(value: vx_channel_e) -> Self {
+        Channel::new(value)
+    }
+}
+
+impl fmt::Display for Channel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Channel::C0 => write!(f, "VX_CHANNEL_0"),
+            Channel::C1 => write!(f, "VX_CHANNEL_1"),
+            Channel::C2 => write!(f, "VX_CHANNEL_2"),
+            Channel::C3 => write!(f, "VX_CHANNEL_3"),
+            Channel::R => write!(f, "VX_CHANNEL_R"),
+            Channel::G => write!(f, "VX_CHANNEL_G"),
+            Channel::B => write!(f, "VX_CHANNEL_B"),
+            Channel::A => write!(f, "VX_CHANNEL_A"),
+            Channel::Y => write!(f, "VX_CHANNEL_Y"),
+            Channel::U => write!(f, "VX_CHANNEL_U"),
+            Channel::V => write!(f, "VX_CHANNEL_V"),
+            Channel::Other { channel } => write!(f, "VX_CHANNEL_OTHER ({})", channel),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roundtrip() {
+        for value in [
+            Channel::C0,
+            Channel::C1,
+            Channel::C2,
+            Channel::C3,
+            Channel::R,
+            Channel::G,
+            Channel::B,
+            Channel::A,
+            Channel::Y,
+            Channel::U,
+            Channel::V,
+        ] {
+            assert_eq!(Channel::from(value.to_raw()), value);
+        }
+    }
+
+    #[test]
+    fn other() {
+        assert_eq!(Channel::new(0xFFFF), Channel::Other { channel: 0xFFFF });
+    }
+}
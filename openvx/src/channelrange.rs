@@ -0,0 +1,71 @@
+use core::fmt;
+use libopenvx_sys::*;
+
+/// The image channel range list used by the `VX_IMAGE_RANGE` attribute of a `vx_image`.
+#[derive(Debug, Eq, PartialEq, Hash, Copy, Clone)]
+pub enum ChannelRange {
+    /// Full range of the unit of the channel.
+    Full,
+    /// Restricted range of the unit of the channel based on the space given.
+    Restricted,
+    /// A vendor-defined or otherwise unknown value.
+    Other { range: vx_channel_range_e },
+}
+
+impl ChannelRange {
+    pub const fn new(range: vx_channel_range_e) -> Self {
+        #[allow(non_upper_case_globals)]
+        match range {
+            vx_channel_range_e_VX_CHANNEL_RANGE_FULL => ChannelRange::Full,
+            vx_channel_range_e_VX_CHANNEL_RANGE_RESTRICTED => ChannelRange::Restricted,
+            range => ChannelRange::Other { range },
+        }
+    }
+
+    /// Converts this instance into a [`vx_channel_range_e`].
+    ///
+    /// [`vx_channel_range_e`]: ../libopenvx_sys/type.vx_channel_range_e.html
+    pub const fn to_raw(&self) -> vx_channel_range_e {
+        match self {
+            ChannelRange::Full => vx_channel_range_e_VX_CHANNEL_RANGE_FULL,
+            ChannelRange::Restricted => vx_channel_range_e_VX_CHANNEL_RANGE_RESTRICTED,
+            ChannelRange::Other { range } => *range,
+        }
+    }
+}
+
+impl From<vx_channel_range_e> for ChannelRange {
+    fn from(value: vx_channel_range_e) -> Self {
+        ChannelRange::new(value)
+    }
+}
+
+impl fmt::Display for ChannelRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ChannelRange::Full => write!(f, "VX_CHANNEL_RANGE_FULL"),
+            ChannelRange::Restricted => write!(f, "VX_CHANNEL_RANGE_RESTRICTED"),
+            ChannelRange::Other { range } => write!(f, "VX_CHANNEL_RANGE_OTHER ({})", range),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roundtrip() {
+        for value in [ChannelRange::Full, ChannelRange::Restricted] {
+            assert_eq!(ChannelRange::from(value.to_raw()), value);
+        }
+    }
+
+    #[test]
+    fn other() {
+        assert_eq!(
+            ChannelRange::new(0xFFFF),
+            ChannelRange::Other { range: 0xFFFF }
+        );
+    }
+}
@@ -2,4 +2,22 @@ use crate::Result;
 
 pub trait CheckStatus {
     fn check_status(&self) -> Result<&Self>;
+
+    /// Checks the status of a newly created object by calling [`vxGetStatus`] and
+    /// returns it if it is valid.
+    ///
+    /// If the object is an error object, it is not released, since error objects
+    /// are owned by the implementation.
+    ///
+    /// [`vxGetStatus`]: ../libopenvx_sys/fn.vxGetStatus.html
+    fn into_checked(self) -> Result<Self>
+    where
+        Self: Sized,
+    {
+        if let Err(error) = self.check_status() {
+            std::mem::forget(self);
+            return Err(error);
+        }
+        Ok(self)
+    }
 }
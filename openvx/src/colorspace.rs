@@ -0,0 +1,83 @@
+use core::fmt;
+use libopenvx_sys::*;
+
+/// The image color space list used by the `VX_IMAGE_SPACE` attribute of a `vx_image`.
+#[derive(Debug, Eq, PartialEq, Hash, Copy, Clone)]
+pub enum ColorSpace {
+    /// Use to indicate that no color space is used.
+    None,
+    /// Use to indicate that the BT.601 coefficients and SMPTE C primaries are used for conversions.
+    Bt601_525,
+    /// Use to indicate that the BT.601 coefficients and BTU primaries are used for conversions.
+    Bt601_625,
+    /// Use to indicate that the BT.709 coefficients are used for conversions. This is the default color space.
+    Bt709,
+    /// A vendor-defined or otherwise unknown value.
+    Other { space: vx_color_space_e },
+}
+
+impl ColorSpace {
+    pub const fn new(space: vx_color_space_e) -> Self {
+        #[allow(non_upper_case_globals)]
+        match space {
+            vx_color_space_e_VX_COLOR_SPACE_NONE => ColorSpace::None,
+            vx_color_space_e_VX_COLOR_SPACE_BT601_525 => ColorSpace::Bt601_525,
+            vx_color_space_e_VX_COLOR_SPACE_BT601_625 => ColorSpace::Bt601_625,
+            vx_color_space_e_VX_COLOR_SPACE_BT709 => ColorSpace::Bt709,
+            space => ColorSpace::Other { space },
+        }
+    }
+
+    /// Converts this instance into a [`vx_color_space_e`].
+    ///
+    /// [`vx_color_space_e`]: ../libopenvx_sys/type.vx_color_space_e.html
+    pub const fn to_raw(&self) -> vx_color_space_e {
+        match self {
+            ColorSpace::None => vx_color_space_e_VX_COLOR_SPACE_NONE,
+            ColorSpace::Bt601_525 => vx_color_space_e_VX_COLOR_SPACE_BT601_525,
+            ColorSpace::Bt601_625 => vx_color_space_e_VX_COLOR_SPACE_BT601_625,
+            ColorSpace::Bt709 => vx_color_space_e_VX_COLOR_SPACE_BT709,
+            ColorSpace::Other { space } => *space,
+        }
+    }
+}
+
+impl From<vx_color_space_e> for ColorSpace {
+    fn from(value: vx_color_space_e) -> Self {
+        ColorSpace::new(value)
+    }
+}
+
+impl fmt::Display for ColorSpace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ColorSpace::None => write!(f, "VX_COLOR_SPACE_NONE"),
+            ColorSpace::Bt601_525 => write!(f, "VX_COLOR_SPACE_BT601_525"),
+            ColorSpace::Bt601_625 => write!(f, "VX_COLOR_SPACE_BT601_625"),
+            ColorSpace::Bt709 => write!(f, "VX_COLOR_SPACE_BT709"),
+            ColorSpace::Other { space } => write!(f, "VX_COLOR_SPACE_OTHER ({})", space),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roundtrip() {
+        for value in [
+            ColorSpace::None,
+            ColorSpace::Bt601_525,
+            ColorSpace::Bt601_625,
+            ColorSpace::Bt709,
+        ] {
+            assert_eq!(ColorSpace::from(value.to_raw()), value);
+        }
+    }
+
+    #[test]
+    fn other() {
+        assert_eq!(ColorSpace::new(0xFFFF), ColorSpace::Other { space: 0xFFFF });
+    }
+}
@@ -0,0 +1,111 @@
+use crate::{Border, BorderPolicy};
+use core::fmt;
+use libopenvx_sys::vx_uint16;
+
+/// An OpenVX specification version as reported by the `VX_CONTEXT_VERSION` attribute.
+///
+/// # Examples
+///
+/// ```
+/// use openvx::VxVersion;
+///
+/// let version = VxVersion::from(0x0103);
+/// assert_eq!(version, VxVersion::new(1, 3));
+/// assert_eq!(version.to_string(), "1.3");
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct VxVersion {
+    /// The major version.
+    pub major: u8,
+    /// The minor version.
+    pub minor: u8,
+}
+
+impl VxVersion {
+    pub const fn new(major: u8, minor: u8) -> Self {
+        Self { major, minor }
+    }
+
+    /// Converts this instance into the encoding used by `VX_VERSION`, i.e. the
+    /// major version in the upper and the minor version in the lower byte.
+    pub const fn to_raw(&self) -> vx_uint16 {
+        ((self.major as vx_uint16) << 8) | self.minor as vx_uint16
+    }
+}
+
+impl From<vx_uint16> for VxVersion {
+    fn from(version: vx_uint16) -> Self {
+        Self::new((version >> 8) as u8, (version & 0xFF) as u8)
+    }
+}
+
+impl fmt::Display for VxVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}", self.major, self.minor)
+    }
+}
+
+/// Describes the implementation behind a [`VxContext`](struct.VxContext.html).
+///
+/// See [`VxContext::info`](struct.VxContext.html#method.info).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContextInfo {
+    /// The unique vendor ID of the implementation, see `vx_vendor_id_e`.
+    pub vendor_id: u16,
+    /// The OpenVX version of the implementation.
+    pub version: VxVersion,
+    /// The name of the implementation.
+    pub implementation: String,
+    /// The extensions supported by the implementation, e.g. `vx_khr_nn`.
+    pub extensions: Vec<String>,
+    /// The number of loaded modules.
+    pub num_modules: u32,
+    /// The number of unique kernels in the system.
+    pub num_kernels: u32,
+    /// The number of live references in the context.
+    pub num_references: u32,
+    /// The maximum width or height of a convolution matrix.
+    pub convolution_max_dimension: usize,
+    /// The maximum window dimension of the optical flow kernel.
+    pub optical_flow_max_window_dimension: usize,
+    /// The border mode used by immediate mode functions.
+    pub immediate_border: Border,
+    /// The policy for unsupported border modes in immediate mode functions.
+    pub immediate_border_policy: BorderPolicy,
+    /// The maximum number of tensor dimensions supported.
+    pub max_tensor_dims: usize,
+    /// The maximum width or height of the non-linear filter mask.
+    pub nonlinear_max_dimension: usize,
+}
+
+impl ContextInfo {
+    /// Determines whether the implementation supports the given extension.
+    pub fn has_extension(&self, name: &str) -> bool {
+        self.extensions.iter().any(|extension| extension == name)
+    }
+}
+
+/// Splits the space separated `VX_CONTEXT_EXTENSIONS` string.
+pub(crate) fn parse_extensions(extensions: &str) -> Vec<String> {
+    extensions.split_whitespace().map(String::from).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn version_roundtrip() {
+        let version = VxVersion::new(1, 3);
+        assert_eq!(version.to_raw(), 0x0103);
+        assert_eq!(VxVersion::from(version.to_raw()), version);
+        assert!(VxVersion::new(1, 2) < version);
+    }
+
+    #[test]
+    fn extensions_are_split_on_whitespace() {
+        let extensions = parse_extensions("vx_khr_nn  vx_khr_tiling\nvx_khr_ix ");
+        assert_eq!(extensions, ["vx_khr_nn", "vx_khr_tiling", "vx_khr_ix"]);
+        assert!(parse_extensions("").is_empty());
+    }
+}
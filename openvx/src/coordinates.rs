@@ -0,0 +1,252 @@
+use libopenvx_sys::{
+    vx_coordinates2d_t, vx_coordinates2df_t, vx_coordinates3d_t, vx_float32, vx_uint32,
+};
+
+/// Integer coordinates in two dimensions, e.g. of a corner.
+///
+/// The layout matches [`vx_coordinates2d_t`], so the type can be used as item of a
+/// [`VxArray`](struct.VxArray.html) or value of a [`VxScalar`](struct.VxScalar.html).
+///
+/// [`vx_coordinates2d_t`]: ../libopenvx_sys/type.vx_coordinates2d_t.html
+#[repr(C)]
+#[derive(Debug, Default, Eq, PartialEq, Hash, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Coordinates2d {
+    /// The x coordinate.
+    pub x: vx_uint32,
+    /// The y coordinate.
+    pub y: vx_uint32,
+}
+
+/// Floating point coordinates in two dimensions, e.g. of a tracked feature.
+///
+/// The layout matches [`vx_coordinates2df_t`].
+///
+/// [`vx_coordinates2df_t`]: ../libopenvx_sys/type.vx_coordinates2df_t.html
+#[repr(C)]
+#[derive(Debug, Default, PartialEq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Coordinates2df {
+    /// The x coordinate.
+    pub x: vx_float32,
+    /// The y coordinate.
+    pub y: vx_float32,
+}
+
+/// Integer coordinates in three dimensions.
+///
+/// The layout matches [`vx_coordinates3d_t`].
+///
+/// [`vx_coordinates3d_t`]: ../libopenvx_sys/type.vx_coordinates3d_t.html
+#[repr(C)]
+#[derive(Debug, Default, Eq, PartialEq, Hash, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Coordinates3d {
+    /// The x coordinate.
+    pub x: vx_uint32,
+    /// The y coordinate.
+    pub y: vx_uint32,
+    /// The z coordinate.
+    pub z: vx_uint32,
+}
+
+static_assertions::assert_eq_size!(Coordinates2d, vx_coordinates2d_t);
+static_assertions::assert_eq_size!(Coordinates2df, vx_coordinates2df_t);
+static_assertions::assert_eq_size!(Coordinates3d, vx_coordinates3d_t);
+
+impl Coordinates2d {
+    /// Creates coordinates from their components.
+    pub const fn new(x: vx_uint32, y: vx_uint32) -> Self {
+        Self { x, y }
+    }
+
+    /// Converts this instance into a [`vx_coordinates2d_t`].
+    ///
+    /// [`vx_coordinates2d_t`]: ../libopenvx_sys/type.vx_coordinates2d_t.html
+    pub const fn to_raw(&self) -> vx_coordinates2d_t {
+        vx_coordinates2d_t {
+            x: self.x,
+            y: self.y,
+        }
+    }
+}
+
+impl Coordinates2df {
+    /// Creates coordinates from their components.
+    pub const fn new(x: vx_float32, y: vx_float32) -> Self {
+        Self { x, y }
+    }
+
+    /// Converts this instance into a [`vx_coordinates2df_t`].
+    ///
+    /// [`vx_coordinates2df_t`]: ../libopenvx_sys/type.vx_coordinates2df_t.html
+    pub const fn to_raw(&self) -> vx_coordinates2df_t {
+        vx_coordinates2df_t {
+            x: self.x,
+            y: self.y,
+        }
+    }
+}
+
+impl Coordinates3d {
+    /// Creates coordinates from their components.
+    pub const fn new(x: vx_uint32, y: vx_uint32, z: vx_uint32) -> Self {
+        Self { x, y, z }
+    }
+
+    /// Converts this instance into a [`vx_coordinates3d_t`].
+    ///
+    /// [`vx_coordinates3d_t`]: ../libopenvx_sys/type.vx_coordinates3d_t.html
+    pub const fn to_raw(&self) -> vx_coordinates3d_t {
+        vx_coordinates3d_t {
+            x: self.x,
+            y: self.y,
+            z: self.z,
+        }
+    }
+}
+
+impl From<vx_coordinates2d_t> for Coordinates2d {
+    fn from(value: vx_coordinates2d_t) -> Self {
+        Self::new(value.x, value.y)
+    }
+}
+
+impl From<vx_coordinates2df_t> for Coordinates2df {
+    fn from(value: vx_coordinates2df_t) -> Self {
+        Self::new(value.x, value.y)
+    }
+}
+
+impl From<vx_coordinates3d_t> for Coordinates3d {
+    fn from(value: vx_coordinates3d_t) -> Self {
+        Self::new(value.x, value.y, value.z)
+    }
+}
+
+impl From<Coordinates2d> for Coordinates2df {
+    fn from(value: Coordinates2d) -> Self {
+        Self::new(value.x as vx_float32, value.y as vx_float32)
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+mod nalgebra_conversions {
+    use super::*;
+    use nalgebra::{Point2, Point3};
+
+    impl From<Coordinates2d> for Point2<u32> {
+        fn from(value: Coordinates2d) -> Self {
+            Point2::new(value.x, value.y)
+        }
+    }
+
+    impl From<Point2<u32>> for Coordinates2d {
+        fn from(value: Point2<u32>) -> Self {
+            Self::new(value.x, value.y)
+        }
+    }
+
+    impl From<Coordinates2df> for Point2<f32> {
+        fn from(value: Coordinates2df) -> Self {
+            Point2::new(value.x, value.y)
+        }
+    }
+
+    impl From<Point2<f32>> for Coordinates2df {
+        fn from(value: Point2<f32>) -> Self {
+            Self::new(value.x, value.y)
+        }
+    }
+
+    impl From<Coordinates3d> for Point3<u32> {
+        fn from(value: Coordinates3d) -> Self {
+            Point3::new(value.x, value.y, value.z)
+        }
+    }
+
+    impl From<Point3<u32>> for Coordinates3d {
+        fn from(value: Point3<u32>) -> Self {
+            Self::new(value.x, value.y, value.z)
+        }
+    }
+}
+
+#[cfg(feature = "glam")]
+mod glam_conversions {
+    use super::*;
+    use glam::{UVec2, UVec3, Vec2};
+
+    impl From<Coordinates2d> for UVec2 {
+        fn from(value: Coordinates2d) -> Self {
+            UVec2::new(value.x, value.y)
+        }
+    }
+
+    impl From<UVec2> for Coordinates2d {
+        fn from(value: UVec2) -> Self {
+            Self::new(value.x, value.y)
+        }
+    }
+
+    impl From<Coordinates2df> for Vec2 {
+        fn from(value: Coordinates2df) -> Self {
+            Vec2::new(value.x, value.y)
+        }
+    }
+
+    impl From<Vec2> for Coordinates2df {
+        fn from(value: Vec2) -> Self {
+            Self::new(value.x, value.y)
+        }
+    }
+
+    impl From<Coordinates3d> for UVec3 {
+        fn from(value: Coordinates3d) -> Self {
+            UVec3::new(value.x, value.y, value.z)
+        }
+    }
+
+    impl From<UVec3> for Coordinates3d {
+        fn from(value: UVec3) -> Self {
+            Self::new(value.x, value.y, value.z)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roundtrip() {
+        let point = Coordinates2d::new(3, 4);
+        assert_eq!(Coordinates2d::from(point.to_raw()), point);
+        let point = Coordinates2df::new(0.5, -1.0);
+        assert_eq!(Coordinates2df::from(point.to_raw()), point);
+        let point = Coordinates3d::new(1, 2, 3);
+        assert_eq!(Coordinates3d::from(point.to_raw()), point);
+    }
+
+    #[cfg(feature = "nalgebra")]
+    #[test]
+    fn nalgebra_points() {
+        let point: nalgebra::Point2<f32> = Coordinates2df::new(1.5, 2.0).into();
+        assert_eq!(point, nalgebra::Point2::new(1.5, 2.0));
+        assert_eq!(
+            Coordinates3d::from(nalgebra::Point3::new(1, 2, 3)),
+            Coordinates3d::new(1, 2, 3)
+        );
+    }
+
+    #[cfg(feature = "glam")]
+    #[test]
+    fn glam_vectors() {
+        let vector: glam::UVec2 = Coordinates2d::new(7, 8).into();
+        assert_eq!(vector, glam::UVec2::new(7, 8));
+        assert_eq!(
+            Coordinates2df::from(glam::Vec2::new(0.5, 1.0)),
+            Coordinates2df::new(0.5, 1.0)
+        );
+    }
+}
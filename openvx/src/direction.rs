@@ -0,0 +1,71 @@
+use core::fmt;
+use libopenvx_sys::*;
+
+/// An indication of how a kernel will treat the given parameter.
+#[derive(Debug, Eq, PartialEq, Hash, Copy, Clone)]
+pub enum Direction {
+    /// The parameter is an input only.
+    Input,
+    /// The parameter is an output only.
+    Output,
+    /// A vendor-defined or otherwise unknown value.
+    Other { direction: vx_direction_e },
+}
+
+impl Direction {
+    pub const fn new(direction: vx_direction_e) -> Self {
+        #[allow(non_upper_case_globals)]
+        match direction {
+            vx_direction_e_VX_INPUT => Direction::Input,
+            vx_direction_e_VX_OUTPUT => Direction::Output,
+            direction => Direction::Other { direction },
+        }
+    }
+
+    /// Converts this instance into a [`vx_direction_e`].
+    ///
+    /// [`vx_direction_e`]: ../libopenvx_sys/type.vx_direction_e.html
+    pub const fn to_raw(&self) -> vx_direction_e {
+        match self {
+            Direction::Input => vx_direction_e_VX_INPUT,
+            Direction::Output => vx_direction_e_VX_OUTPUT,
+            Direction::Other { direction } => *direction,
+        }
+    }
+}
+
+impl From<vx_direction_e> for Direction {
+    fn from(value: vx_direction_e) -> Self {
+        Direction::new(value)
+    }
+}
+
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Direction::Input => write!(f, "VX_INPUT"),
+            Direction::Output => write!(f, "VX_OUTPUT"),
+            Direction::Other { direction } => write!(f, "VX_DIRECTION_OTHER ({})", direction),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roundtrip() {
+        for value in [Direction::Input, Direction::Output] {
+            assert_eq!(Direction::from(value.to_raw()), value);
+        }
+    }
+
+    #[test]
+    fn other() {
+        assert_eq!(
+            Direction::new(0xFFFF),
+            Direction::Other { direction: 0xFFFF }
+        );
+    }
+}
@@ -0,0 +1,221 @@
+use crate::logging::{self, CapturedEntry};
+use crate::{attr, AsVxReference, QueryAttribute, VxError, VxStatus, VxType};
+use core::fmt;
+use libopenvx_sys::{vx_reference, vx_status, vx_status_e};
+
+/// The error type of all fallible operations of this crate.
+///
+/// In addition to the [`VxError`] code, the error carries the API call that failed,
+/// the name and type of the reference it failed on and the messages the implementation
+/// logged while processing the call, if available.
+///
+/// Log messages are only collected for contexts with enabled logging; see
+/// [`VxContext::enable_logging`](struct.VxContext.html#method.enable_logging).
+///
+/// # Examples
+///
+/// ```
+/// use openvx::{Error, VxError};
+///
+/// let error = Error::from(VxError::InvalidFormat);
+/// assert_eq!(error.code(), VxError::InvalidFormat);
+/// assert_eq!(error, VxError::InvalidFormat);
+/// assert_eq!(error.to_string(), "InvalidFormat");
+/// ```
+///
+/// [`VxError`]: enum.VxError.html
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Error {
+    code: VxError,
+    call: Option<&'static str>,
+    reference_type: Option<VxType>,
+    reference_name: Option<String>,
+    messages: Vec<String>,
+}
+
+impl Error {
+    /// Creates an error for the given code without further context.
+    pub fn new(code: VxError) -> Self {
+        Self {
+            code,
+            call: None,
+            reference_type: None,
+            reference_name: None,
+            messages: Vec::new(),
+        }
+    }
+
+    /// Creates an error for a failed `call` on `reference`, attaching the captured log messages.
+    pub(crate) fn from_call<R: AsVxReference + ?Sized>(
+        code: VxError,
+        call: &'static str,
+        reference: &R,
+        entries: Vec<CapturedEntry>,
+    ) -> Self {
+        let raw: vx_reference = reference.as_reference().into();
+        let reference = reference.as_reference();
+        Self {
+            code,
+            call: Some(call),
+            reference_type: reference.query::<attr::ReferenceType>().ok(),
+            reference_name: reference.query::<attr::Name>().ok().flatten(),
+            messages: entries
+                .into_iter()
+                .map(|entry| format_entry(raw, entry))
+                .collect(),
+        }
+    }
+
+    /// Returns the error code.
+    pub fn code(&self) -> VxError {
+        self.code
+    }
+
+    /// Returns the name of the API call that failed, e.g. `vxVerifyGraph`.
+    pub fn call(&self) -> Option<&'static str> {
+        self.call
+    }
+
+    /// Returns the type of the reference the call failed on.
+    pub fn reference_type(&self) -> Option<VxType> {
+        self.reference_type
+    }
+
+    /// Returns the name of the reference the call failed on, if one was set.
+    pub fn reference_name(&self) -> Option<&str> {
+        self.reference_name.as_deref()
+    }
+
+    /// Returns the messages the implementation logged while processing the call.
+    pub fn messages(&self) -> &[String] {
+        &self.messages
+    }
+}
+
+/// Calls an API function operating on `reference` and converts a failure into an [`Error`]
+/// carrying the name of the call and the messages logged in the meantime.
+pub(crate) fn check_call<R: AsVxReference + ?Sized>(
+    call: &'static str,
+    reference: &R,
+    function: impl FnOnce() -> vx_status,
+) -> crate::Result<()> {
+    let (status, entries) = logging::capture(function);
+    match VxStatus::from(status as vx_status_e) {
+        VxStatus::Success => Ok(()),
+        VxStatus::Error(code) => Err(Error::from_call(code, call, reference, entries)),
+    }
+}
+
+/// Formats a captured entry, prefixing the originating reference unless it is `failed`.
+fn format_entry(failed: vx_reference, entry: CapturedEntry) -> String {
+    if entry.reference == failed || entry.reference.is_null() {
+        return entry.message;
+    }
+    match entry.name {
+        Some(name) => format!("{} '{}' {}", type_name(entry.r#type), name, entry.message),
+        None => format!("{} {}", type_name(entry.r#type), entry.message),
+    }
+}
+
+/// Returns the short, lowercase name of a type, e.g. `graph` for `VX_TYPE_GRAPH`.
+fn type_name(r#type: VxType) -> String {
+    let name = r#type.to_string();
+    name.strip_prefix("VX_TYPE_")
+        .unwrap_or(&name)
+        .to_lowercase()
+}
+
+impl From<VxError> for Error {
+    fn from(code: VxError) -> Self {
+        Self::new(code)
+    }
+}
+
+impl PartialEq<VxError> for Error {
+    fn eq(&self, other: &VxError) -> bool {
+        self.code == *other
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(call) = self.call {
+            write!(f, "{}", call)?;
+            if let Some(r#type) = self.reference_type {
+                write!(f, " on {}", type_name(r#type))?;
+            }
+            if let Some(name) = &self.reference_name {
+                write!(f, " '{}'", name)?;
+            }
+            write!(f, ": ")?;
+        }
+
+        write!(f, "{:?}", self.code)?;
+        for message in &self.messages {
+            write!(f, ": {}", message)?;
+        }
+        Ok(())
+    }
+}
+
+/// The error code is part of the message, so it is not reported as the source as well.
+impl std::error::Error for Error {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display_without_context() {
+        let error = Error::from(VxError::NotSupported);
+        assert_eq!(error.to_string(), "NotSupported");
+        assert_eq!(error.call(), None);
+        assert!(error.messages().is_empty());
+    }
+
+    #[test]
+    fn display_with_context() {
+        let graph = 0x1000 as vx_reference;
+        let node = 0x2000 as vx_reference;
+        let error = Error {
+            code: VxError::InvalidFormat,
+            call: Some("vxVerifyGraph"),
+            reference_type: Some(VxType::Graph),
+            reference_name: Some("CANNY_GRAPH".to_owned()),
+            messages: vec![CapturedEntry {
+                reference: node,
+                name: Some("RGB_TO_YUV".to_owned()),
+                r#type: VxType::Node,
+                message: "parameter 1 expects IYUV".to_owned(),
+            }]
+            .into_iter()
+            .map(|entry| format_entry(graph, entry))
+            .collect(),
+        };
+
+        assert_eq!(
+            error.to_string(),
+            "vxVerifyGraph on graph 'CANNY_GRAPH': InvalidFormat: node 'RGB_TO_YUV' parameter 1 expects IYUV"
+        );
+    }
+
+    #[test]
+    fn messages_of_the_failed_reference_are_not_prefixed() {
+        let graph = 0x1000 as vx_reference;
+        let entry = CapturedEntry {
+            reference: graph,
+            name: Some("CANNY_GRAPH".to_owned()),
+            r#type: VxType::Graph,
+            message: "graph has cycles".to_owned(),
+        };
+        assert_eq!(format_entry(graph, entry), "graph has cycles");
+    }
+
+    #[test]
+    fn code_is_not_the_source() {
+        use std::error::Error as _;
+        let error = Error::from(VxError::InvalidGraph);
+        assert!(error.source().is_none());
+        assert_eq!(error.to_string(), "InvalidGraph");
+    }
+}
@@ -0,0 +1,98 @@
+use core::fmt;
+use libopenvx_sys::*;
+
+/// The memory layout of the exposures of a raw image (`vx_image_raw_exposure_interleaving_e`).
+#[derive(Debug, Eq, PartialEq, Hash, Copy, Clone)]
+pub enum ExposureInterleaving {
+    /// Each exposure is stored in a separate plane.
+    Planar,
+    /// The exposures are stored in interleaved lines.
+    LineInterleaved,
+    /// The exposures are stored in interleaved pixels.
+    PixelInterleaved,
+    /// A vendor-defined or otherwise unknown value.
+    Other {
+        interleaving: vx_image_raw_exposure_interleaving_e,
+    },
+}
+
+impl ExposureInterleaving {
+    pub const fn new(interleaving: vx_image_raw_exposure_interleaving_e) -> Self {
+        #[allow(non_upper_case_globals)]
+        match interleaving {
+            vx_image_raw_exposure_interleaving_e_VX_IMAGE_RAW_PLANAR => {
+                ExposureInterleaving::Planar
+            }
+            vx_image_raw_exposure_interleaving_e_VX_IMAGE_RAW_LINE_INTERLEAVED => {
+                ExposureInterleaving::LineInterleaved
+            }
+            vx_image_raw_exposure_interleaving_e_VX_IMAGE_RAW_PIXEL_INTERLEAVED => {
+                ExposureInterleaving::PixelInterleaved
+            }
+            interleaving => ExposureInterleaving::Other { interleaving },
+        }
+    }
+
+    /// Converts this instance into a [`vx_image_raw_exposure_interleaving_e`].
+    ///
+    /// [`vx_image_raw_exposure_interleaving_e`]: ../libopenvx_sys/type.vx_image_raw_exposure_interleaving_e.html
+    pub const fn to_raw(&self) -> vx_image_raw_exposure_interleaving_e {
+        match self {
+            ExposureInterleaving::Planar => {
+                vx_image_raw_exposure_interleaving_e_VX_IMAGE_RAW_PLANAR
+            }
+            ExposureInterleaving::LineInterleaved => {
+                vx_image_raw_exposure_interleaving_e_VX_IMAGE_RAW_LINE_INTERLEAVED
+            }
+            ExposureInterleaving::PixelInterleaved => {
+                vx_image_raw_exposure_interleaving_e_VX_IMAGE_RAW_PIXEL_INTERLEAVED
+            }
+            ExposureInterleaving::Other { interleaving } => *interleaving,
+        }
+    }
+}
+
+impl From<vx_image_raw_exposure_interleaving_e> for ExposureInterleaving {
+    fn from(value: vx_image_raw_exposure_interleaving_e) -> Self {
+        ExposureInterleaving::new(value)
+    }
+}
+
+impl fmt::Display for ExposureInterleaving {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExposureInterleaving::Planar => write!(f, "VX_IMAGE_RAW_PLANAR"),
+            ExposureInterleaving::LineInterleaved => write!(f, "VX_IMAGE_RAW_LINE_INTERLEAVED"),
+            ExposureInterleaving::PixelInterleaved => write!(f, "VX_IMAGE_RAW_PIXEL_INTERLEAVED"),
+            ExposureInterleaving::Other { interleaving } => {
+                write!(f, "VX_IMAGE_RAW_INTERLEAVING_OTHER ({})", interleaving)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roundtrip() {
+        for value in [
+            ExposureInterleaving::Planar,
+            ExposureInterleaving::LineInterleaved,
+            ExposureInterleaving::PixelInterleaved,
+        ] {
+            assert_eq!(ExposureInterleaving::from(value.to_raw()), value);
+        }
+    }
+
+    #[test]
+    fn other() {
+        assert_eq!(
+            ExposureInterleaving::new(0xFFFF),
+            ExposureInterleaving::Other {
+                interleaving: 0xFFFF
+            }
+        );
+    }
+}
@@ -0,0 +1,94 @@
+use libopenvx_sys::{vx_float32, vx_hog_t, vx_int32};
+
+/// The parameters of the HOG nodes.
+///
+/// The layout matches [`vx_hog_t`], so the type can be used as value of a
+/// [`VxScalar`](struct.VxScalar.html) of type `VX_TYPE_HOG_PARAMS`.
+///
+/// [`vx_hog_t`]: ../libopenvx_sys/type.vx_hog_t.html
+#[repr(C)]
+#[derive(Debug, Default, PartialEq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HogParams {
+    /// The histogram cell width.
+    pub cell_width: vx_int32,
+    /// The histogram cell height.
+    pub cell_height: vx_int32,
+    /// The histogram block width; must be divisible by `cell_width`.
+    pub block_width: vx_int32,
+    /// The histogram block height; must be divisible by `cell_height`.
+    pub block_height: vx_int32,
+    /// The histogram block stride within the window; must be a multiple of the cell size.
+    pub block_stride: vx_int32,
+    /// The histogram size.
+    pub num_bins: vx_int32,
+    /// The feature descriptor window width.
+    pub window_width: vx_int32,
+    /// The feature descriptor window height.
+    pub window_height: vx_int32,
+    /// The feature descriptor window stride.
+    pub window_stride: vx_int32,
+    /// The threshold for the maximum L2-norm value of a histogram.
+    pub threshold: vx_float32,
+}
+
+static_assertions::assert_eq_size!(HogParams, vx_hog_t);
+
+impl HogParams {
+    /// Converts this instance into a [`vx_hog_t`].
+    ///
+    /// [`vx_hog_t`]: ../libopenvx_sys/type.vx_hog_t.html
+    pub const fn to_raw(&self) -> vx_hog_t {
+        vx_hog_t {
+            cell_width: self.cell_width,
+            cell_height: self.cell_height,
+            block_width: self.block_width,
+            block_height: self.block_height,
+            block_stride: self.block_stride,
+            num_bins: self.num_bins,
+            window_width: self.window_width,
+            window_height: self.window_height,
+            window_stride: self.window_stride,
+            threshold: self.threshold,
+        }
+    }
+}
+
+impl From<vx_hog_t> for HogParams {
+    fn from(value: vx_hog_t) -> Self {
+        Self {
+            cell_width: value.cell_width,
+            cell_height: value.cell_height,
+            block_width: value.block_width,
+            block_height: value.block_height,
+            block_stride: value.block_stride,
+            num_bins: value.num_bins,
+            window_width: value.window_width,
+            window_height: value.window_height,
+            window_stride: value.window_stride,
+            threshold: value.threshold,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roundtrip() {
+        let params = HogParams {
+            cell_width: 8,
+            cell_height: 8,
+            block_width: 16,
+            block_height: 16,
+            block_stride: 8,
+            num_bins: 9,
+            window_width: 64,
+            window_height: 128,
+            window_stride: 8,
+            threshold: 0.2,
+        };
+        assert_eq!(HogParams::from(params.to_raw()), params);
+    }
+}
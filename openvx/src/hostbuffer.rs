@@ -0,0 +1,19 @@
+use std::ops::DerefMut;
+use std::pin::Pin;
+
+/// Host memory that can back the planes of a [`VxHostImage`](struct.VxHostImage.html).
+///
+/// # Safety
+///
+/// The slice the buffer dereferences to must keep its address when the buffer is moved,
+/// i.e. the memory must be owned through a pointer rather than inline. The buffer must also
+/// own the memory rather than borrow it: if the image is leaked, e.g. by calling
+/// `std::mem::forget`, the buffers are never detached and must stay valid for as long as the
+/// implementation may access them.
+pub unsafe trait HostBuffer: DerefMut<Target = [u8]> + 'static {}
+
+unsafe impl HostBuffer for Vec<u8> {}
+
+unsafe impl HostBuffer for Box<[u8]> {}
+
+unsafe impl HostBuffer for Pin<Box<[u8]>> {}
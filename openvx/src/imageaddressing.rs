@@ -0,0 +1,129 @@
+use libopenvx_sys::{vx_imagepatch_addressing_t, vx_int32, vx_uint16, vx_uint32, VX_SCALE_UNITY};
+
+/// Describes the memory layout of an image patch, i.e. its dimensions and strides.
+#[derive(Debug, Eq, PartialEq, Hash, Copy, Clone)]
+pub struct ImageAddressing {
+    /// Width of the patch in X dimension in pixels.
+    pub dim_x: vx_uint32,
+    /// Height of the patch in Y dimension in pixels.
+    pub dim_y: vx_uint32,
+    /// Stride in X dimension in bytes.
+    pub stride_x: vx_int32,
+    /// Stride in Y dimension in bytes.
+    pub stride_y: vx_int32,
+    /// Scale of X dimension. For sub-sampled planes this is the scaling factor of the
+    /// dimension of the plane in relation to the zero plane, using `VX_SCALE_UNITY`.
+    pub scale_x: vx_uint32,
+    /// Scale of Y dimension. For sub-sampled planes this is the scaling factor of the
+    /// dimension of the plane in relation to the zero plane, using `VX_SCALE_UNITY`.
+    pub scale_y: vx_uint32,
+    /// Step of X dimension in pixels.
+    pub step_x: vx_uint32,
+    /// Step of Y dimension in pixels.
+    pub step_y: vx_uint16,
+    /// Stride in X dimension in bits; used when `stride_x` is not an integer number of bytes.
+    pub stride_x_bits: vx_uint16,
+}
+
+impl ImageAddressing {
+    /// Creates the addressing of an unscaled plane with the given dimensions and byte strides.
+    pub const fn new(dim_x: u32, dim_y: u32, stride_x: i32, stride_y: i32) -> Self {
+        Self {
+            dim_x,
+            dim_y,
+            stride_x,
+            stride_y,
+            scale_x: VX_SCALE_UNITY,
+            scale_y: VX_SCALE_UNITY,
+            step_x: 1,
+            step_y: 1,
+            stride_x_bits: (stride_x * 8) as vx_uint16,
+        }
+    }
+
+    /// Returns the number of bytes spanned by the rows of the patch, taking the
+    /// vertical scale of sub-sampled planes into account.
+    ///
+    /// Returns `0` for non-positive row strides, which do not describe memory starting at
+    /// the first row.
+    pub const fn buffer_size(&self) -> usize {
+        if self.stride_y <= 0 {
+            return 0;
+        }
+        let rows = self.dim_y as u64 * self.scale_y as u64 / VX_SCALE_UNITY as u64;
+        self.stride_y as usize * rows as usize
+    }
+
+    /// Converts this instance into a [`vx_imagepatch_addressing_t`].
+    ///
+    /// [`vx_imagepatch_addressing_t`]: ../libopenvx_sys/type.vx_imagepatch_addressing_t.html
+    pub const fn to_raw(&self) -> vx_imagepatch_addressing_t {
+        vx_imagepatch_addressing_t {
+            dim_x: self.dim_x,
+            dim_y: self.dim_y,
+            stride_x: self.stride_x,
+            stride_y: self.stride_y,
+            scale_x: self.scale_x,
+            scale_y: self.scale_y,
+            step_x: self.step_x,
+            step_y: self.step_y,
+            stride_x_bits: self.stride_x_bits,
+        }
+    }
+}
+
+impl From<vx_imagepatch_addressing_t> for ImageAddressing {
+    fn from(value: vx_imagepatch_addressing_t) -> Self {
+        Self {
+            dim_x: value.dim_x,
+            dim_y: value.dim_y,
+            stride_x: value.stride_x,
+            stride_y: value.stride_y,
+            scale_x: value.scale_x,
+            scale_y: value.scale_y,
+            step_x: value.step_x,
+            step_y: value.step_y,
+            stride_x_bits: value.stride_x_bits,
+        }
+    }
+}
+
+/// A plane of host memory backing an image created with
+/// [`VxImage::from_handle`](struct.VxImage.html#method.from_handle).
+#[derive(Debug)]
+pub struct ImagePlane<'a> {
+    /// The layout of the plane.
+    pub addressing: ImageAddressing,
+    /// The memory of the plane.
+    pub data: &'a mut [u8],
+}
+
+impl<'a> ImagePlane<'a> {
+    /// Creates a plane from its layout and memory.
+    pub fn new(addressing: ImageAddressing, data: &'a mut [u8]) -> Self {
+        Self { addressing, data }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roundtrip() {
+        let addressing = ImageAddressing::new(640, 480, 3, 1920);
+        assert_eq!(ImageAddressing::from(addressing.to_raw()), addressing);
+        assert_eq!(addressing.stride_x_bits, 24);
+        assert_eq!(addressing.buffer_size(), 1920 * 480);
+    }
+
+    #[test]
+    fn subsampled_buffer_size() {
+        let mut addressing = ImageAddressing::new(640, 480, 1, 320);
+        addressing.scale_y = VX_SCALE_UNITY / 2;
+        assert_eq!(addressing.buffer_size(), 320 * 240);
+
+        addressing.stride_y = -320;
+        assert_eq!(addressing.buffer_size(), 0);
+    }
+}
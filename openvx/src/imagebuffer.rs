@@ -0,0 +1,237 @@
+//! Conversions between [`image`] buffers and [`VxImage`], enabled by the `image` feature.
+
+use crate::{attr, Error, ImageType, PixelType, QueryAttribute, Rect, VxContext, VxError, VxImage};
+use core::fmt;
+use image::{DynamicImage, ImageBuffer, Luma, Pixel, Rgb, Rgba};
+use std::ops::Deref;
+use std::path::Path;
+
+/// A pixel of the [`image`] crate that has an OpenVX image format equivalent.
+///
+/// | Pixel       | Format |
+/// |-------------|--------|
+/// | `Luma<u8>`  | `U8`   |
+/// | `Luma<u16>` | `U16`  |
+/// | `Rgb<u8>`   | `RGB`  |
+/// | `Rgba<u8>`  | `RGBX` |
+///
+/// The fourth byte of `RGBX` pixels is padding rather than alpha: OpenVX nodes neither
+/// interpret nor preserve it. Buffers of `Rgba<u8>` read from images hold whatever the
+/// padding contains in their alpha channel.
+///
+/// # Safety
+///
+/// `Element` must have the size and alignment of `[Self::Subpixel; Self::CHANNEL_COUNT]`.
+pub unsafe trait ImagePixel: Pixel {
+    /// The OpenVX format of images of this pixel.
+    const FORMAT: ImageType;
+    /// The type of the elements of the image plane.
+    type Element: PixelType;
+}
+
+unsafe impl ImagePixel for Luma<u8> {
+    const FORMAT: ImageType = ImageType::U8;
+    type Element = u8;
+}
+
+unsafe impl ImagePixel for Luma<u16> {
+    const FORMAT: ImageType = ImageType::U16;
+    type Element = u16;
+}
+
+unsafe impl ImagePixel for Rgb<u8> {
+    const FORMAT: ImageType = ImageType::RGB;
+    type Element = [u8; 3];
+}
+
+unsafe impl ImagePixel for Rgba<u8> {
+    const FORMAT: ImageType = ImageType::RGBX;
+    type Element = [u8; 4];
+}
+
+/// Reinterprets the samples of an image buffer as plane elements.
+fn elements<P: ImagePixel>(samples: &[P::Subpixel]) -> &[P::Element] {
+    let channels = P::CHANNEL_COUNT as usize;
+    unsafe { std::slice::from_raw_parts(samples.as_ptr().cast(), samples.len() / channels) }
+}
+
+/// Reinterprets plane elements as the samples of an image buffer.
+fn samples<P: ImagePixel>(elements: &[P::Element]) -> &[P::Subpixel] {
+    let channels = P::CHANNEL_COUNT as usize;
+    unsafe { std::slice::from_raw_parts(elements.as_ptr().cast(), elements.len() * channels) }
+}
+
+/// The error returned when loading or saving an image file fails.
+#[derive(Debug)]
+pub enum ImageIoError {
+    /// The file could not be decoded or encoded.
+    Image(image::ImageError),
+    /// The image could not be created or read.
+    OpenVx(Error),
+}
+
+impl fmt::Display for ImageIoError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ImageIoError::Image(error) => error.fmt(f),
+            ImageIoError::OpenVx(error) => error.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for ImageIoError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ImageIoError::Image(error) => Some(error),
+            ImageIoError::OpenVx(error) => Some(error),
+        }
+    }
+}
+
+impl From<image::ImageError> for ImageIoError {
+    fn from(error: image::ImageError) -> Self {
+        ImageIoError::Image(error)
+    }
+}
+
+impl From<Error> for ImageIoError {
+    fn from(error: Error) -> Self {
+        ImageIoError::OpenVx(error)
+    }
+}
+
+impl<'a> VxImage<'a> {
+    /// Creates an image holding a copy of `buffer`.
+    ///
+    /// The format of the image follows from the pixel type; see [`ImagePixel`].
+    ///
+    /// [`ImagePixel`]: trait.ImagePixel.html
+    pub fn from_image_buffer<P, C>(
+        context: &'a VxContext,
+        buffer: &ImageBuffer<P, C>,
+    ) -> crate::Result<Self>
+    where
+        P: ImagePixel,
+        C: Deref<Target = [P::Subpixel]>,
+    {
+        let (width, height) = buffer.dimensions();
+        let image = VxImage::new(context, width, height, P::FORMAT)?;
+        image.copy_from_slice(
+            0,
+            Rect::new(0, 0, width, height),
+            elements::<P>(buffer.as_raw()),
+            width as usize,
+        )?;
+        Ok(image)
+    }
+
+    /// Creates an image holding a copy of `image`.
+    ///
+    /// Images without an OpenVX equivalent are converted to `RGB` or, if they have
+    /// an alpha channel, `RGBX`. The alpha channel is stored in the padding byte of `RGBX`
+    /// and loses its meaning; nodes writing `RGBX` images leave the padding undefined.
+    pub fn from_dynamic_image(context: &'a VxContext, image: &DynamicImage) -> crate::Result<Self> {
+        match image {
+            DynamicImage::ImageLuma8(buffer) => Self::from_image_buffer(context, buffer),
+            DynamicImage::ImageLuma16(buffer) => Self::from_image_buffer(context, buffer),
+            DynamicImage::ImageRgb8(buffer) => Self::from_image_buffer(context, buffer),
+            DynamicImage::ImageRgba8(buffer) => Self::from_image_buffer(context, buffer),
+            image if image.color().has_alpha() => {
+                Self::from_image_buffer(context, &image.to_rgba8())
+            }
+            image => Self::from_image_buffer(context, &image.to_rgb8()),
+        }
+    }
+
+    /// Loads an image file, e.g. a PNG or JPEG, into a new image.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use openvx::{VxContext, VxImage};
+    ///
+    /// let context = VxContext::create().unwrap();
+    /// let image = VxImage::load(&context, "selfie.jpg").unwrap();
+    /// image.save("copy.png").unwrap();
+    /// ```
+    pub fn load<Q: AsRef<Path>>(context: &'a VxContext, path: Q) -> Result<Self, ImageIoError> {
+        let image = image::open(path)?;
+        Ok(Self::from_dynamic_image(context, &image)?)
+    }
+
+    /// Copies the image into a new buffer.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VxError::NotCompatible`] if the format of the image does not match the pixel type.
+    ///
+    /// [`VxError::NotCompatible`]: enum.VxError.html#variant.NotCompatible
+    pub fn to_image_buffer<P: ImagePixel>(
+        &self,
+    ) -> crate::Result<ImageBuffer<P, Vec<P::Subpixel>>> {
+        if self.query::<attr::Format>()? != P::FORMAT {
+            return Err(VxError::NotCompatible.into());
+        }
+
+        let width = self.query::<attr::Width>()?;
+        let height = self.query::<attr::Height>()?;
+        let data = self.copy_to_vec::<P::Element>(0, Rect::new(0, 0, width, height))?;
+        let buffer = ImageBuffer::from_raw(width, height, samples::<P>(&data).to_vec());
+        buffer.ok_or_else(|| VxError::InvalidDimension.into())
+    }
+
+    /// Copies the image into a new [`DynamicImage`].
+    ///
+    /// `RGBX` images are converted to `Rgb8` images, since their fourth byte is padding.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VxError::NotCompatible`] if the format has no [`ImagePixel`] equivalent.
+    ///
+    /// [`VxError::NotCompatible`]: enum.VxError.html#variant.NotCompatible
+    /// [`ImagePixel`]: trait.ImagePixel.html
+    pub fn to_dynamic_image(&self) -> crate::Result<DynamicImage> {
+        Ok(match self.query::<attr::Format>()? {
+            ImageType::U8 => DynamicImage::ImageLuma8(self.to_image_buffer()?),
+            ImageType::U16 => DynamicImage::ImageLuma16(self.to_image_buffer()?),
+            ImageType::RGB => DynamicImage::ImageRgb8(self.to_image_buffer()?),
+            ImageType::RGBX => DynamicImage::ImageRgba8(self.to_image_buffer()?)
+                .into_rgb8()
+                .into(),
+            _ => return Err(VxError::NotCompatible.into()),
+        })
+    }
+
+    /// Saves the image to a file whose format is derived from the extension of `path`.
+    pub fn save<Q: AsRef<Path>>(&self, path: Q) -> Result<(), ImageIoError> {
+        self.to_dynamic_image()?.save(path)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reinterprets_samples() {
+        let buffer: ImageBuffer<Rgb<u8>, Vec<u8>> =
+            ImageBuffer::from_fn(2, 1, |x, _| Rgb([x as u8, 1, 2]));
+
+        let pixels = elements::<Rgb<u8>>(buffer.as_raw());
+        assert_eq!(pixels, [[0, 1, 2], [1, 1, 2]]);
+        assert_eq!(samples::<Rgb<u8>>(pixels), buffer.as_raw().as_slice());
+    }
+
+    #[test]
+    fn formats() {
+        assert!(<Luma<u16> as ImagePixel>::Element::is_compatible(
+            <Luma<u16> as ImagePixel>::FORMAT,
+            0
+        ));
+        assert!(<Rgba<u8> as ImagePixel>::Element::is_compatible(
+            <Rgba<u8> as ImagePixel>::FORMAT,
+            0
+        ));
+    }
+}
@@ -0,0 +1,401 @@
+use crate::error::check_call;
+use crate::mapping::Mapping;
+use crate::{
+    attr, Access, AsRaw, ImageAddressing, MemoryType, PixelType, QueryAttribute, Rect, Release,
+    Result, VxError, VxImage,
+};
+use libopenvx_sys::{
+    vxMapImagePatch, vxUnmapImagePatch, vx_enum, vx_image, vx_imagepatch_addressing_t,
+    vx_map_flag_e_VX_NOGAP_X, vx_map_id, vx_uint32, VX_SCALE_UNITY,
+};
+use std::ffi::c_void;
+use std::marker::PhantomData;
+
+/// A patch of an image plane mapped into host memory by
+/// [`VxImage::map_patch`](struct.VxImage.html#method.map_patch).
+///
+/// The patch is unmapped by calling [`vxUnmapImagePatch`] when the guard is dropped.
+/// Its elements are addressed in coordinates of the plane, i.e. chroma planes of
+/// sub-sampled formats have fewer rows and columns than the rectangle that was mapped.
+///
+/// [`vxUnmapImagePatch`]: ../libopenvx_sys/fn.vxUnmapImagePatch.html
+#[derive(Debug)]
+pub struct ImagePatch<'i, T: PixelType> {
+    mapping: Mapping<vx_image>,
+    addressing: ImageAddressing,
+    _marker: PhantomData<(&'i (), T)>,
+}
+
+impl<'i, T: PixelType> ImagePatch<'i, T> {
+    /// Maps the patch by calling [`vxMapImagePatch`] with `VX_NOGAP_X`.
+    ///
+    /// [`vxMapImagePatch`]: ../libopenvx_sys/fn.vxMapImagePatch.html
+    pub(crate) fn map(
+        image: &'i VxImage<'_>,
+        rect: Rect,
+        plane: u32,
+        access: Access,
+    ) -> Result<Self> {
+        let format = image.query::<attr::Format>()?;
+        if !T::is_compatible(format, plane) {
+            return Err(VxError::NotCompatible.into());
+        }
+        Self::map_with_flags(image, rect, plane, access, vx_map_flag_e_VX_NOGAP_X)
+    }
+
+    /// Maps the patch by calling [`vxMapImagePatch`] with the given flags, without checking
+    /// that `T` matches the format of the plane.
+    ///
+    /// [`vxMapImagePatch`]: ../libopenvx_sys/fn.vxMapImagePatch.html
+    pub(crate) fn map_with_flags(
+        image: &'i VxImage<'_>,
+        rect: Rect,
+        plane: u32,
+        access: Access,
+        flags: vx_uint32,
+    ) -> Result<Self> {
+        let rect = rect.to_raw();
+        let mut map_id: vx_map_id = 0;
+        let mut addressing: vx_imagepatch_addressing_t = unsafe { std::mem::zeroed() };
+        let mut ptr: *mut c_void = std::ptr::null_mut();
+        check_call("vxMapImagePatch", image, || unsafe {
+            vxMapImagePatch(
+                image.as_raw(),
+                &rect,
+                plane,
+                &mut map_id,
+                &mut addressing,
+                &mut ptr,
+                access.to_raw() as vx_enum,
+                MemoryType::Host.to_raw() as vx_enum,
+                flags,
+            )
+        })?;
+
+        let patch = Self {
+            mapping: Mapping::new(image.as_raw(), map_id, ptr, access, vxUnmapImagePatch),
+            addressing: ImageAddressing::from(addressing),
+            _marker: PhantomData,
+        };
+
+        let (stride_x, stride_y) = (patch.addressing.stride_x, patch.addressing.stride_y);
+        if stride_x as usize != std::mem::size_of::<T>() || stride_y < 0 {
+            return Err(VxError::NotSupported.into());
+        }
+        patch.mapping.check_layout::<T>(&[stride_y as usize])?;
+        Ok(patch)
+    }
+
+    /// Returns the addressing structure describing the memory layout of the patch.
+    pub fn addressing(&self) -> &ImageAddressing {
+        &self.addressing
+    }
+
+    /// Returns the accessor the patch was mapped with.
+    pub fn access(&self) -> Access {
+        self.mapping.access()
+    }
+
+    /// Returns the number of elements per row.
+    pub fn width(&self) -> usize {
+        scaled(self.addressing.dim_x, self.addressing.scale_x)
+    }
+
+    /// Returns the number of rows.
+    pub fn height(&self) -> usize {
+        scaled(self.addressing.dim_y, self.addressing.scale_y)
+    }
+
+    /// Returns a pointer to the first element of the patch, e.g. to wrap it in a foreign type.
+    pub fn as_ptr(&self) -> *const T {
+        self.mapping.ptr() as *const T
+    }
+
+    /// Returns row `y` of the patch.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `y` is out of bounds or the patch was mapped write-only.
+    pub fn row(&self, y: usize) -> &[T] {
+        assert!(
+            self.mapping.access().is_readable(),
+            "patch is not mapped for reading"
+        );
+        assert!(y < self.height(), "row {} out of bounds", y);
+        unsafe { std::slice::from_raw_parts(self.row_ptr(y), self.width()) }
+    }
+
+    /// Returns row `y` of the patch for writing.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `y` is out of bounds or the patch was mapped read-only.
+    pub fn row_mut(&mut self, y: usize) -> &mut [T] {
+        assert!(
+            self.mapping.access().is_writable(),
+            "patch is not mapped for writing"
+        );
+        assert!(y < self.height(), "row {} out of bounds", y);
+        unsafe { std::slice::from_raw_parts_mut(self.row_ptr(y), self.width()) }
+    }
+
+    /// Returns an iterator over the rows of the patch.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the patch was mapped write-only.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> + '_ {
+        (0..self.height()).map(move |y| self.row(y))
+    }
+
+    /// Returns an iterator over the rows of the patch for writing.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the patch was mapped read-only.
+    pub fn rows_mut(&mut self) -> impl Iterator<Item = &mut [T]> + '_ {
+        assert!(
+            self.mapping.access().is_writable(),
+            "patch is not mapped for writing"
+        );
+        let (ptr, stride) = (self.mapping.ptr(), self.addressing.stride_y as usize);
+        let (width, height) = (self.width(), self.height());
+        (0..height).map(move |y| unsafe {
+            std::slice::from_raw_parts_mut(ptr.add(y * stride) as *mut T, width)
+        })
+    }
+
+    /// Returns the element at column `x` of row `y`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the coordinates are out of bounds or the patch was mapped write-only.
+    pub fn pixel(&self, x: usize, y: usize) -> &T {
+        &self.row(y)[x]
+    }
+
+    /// Returns the element at column `x` of row `y` for writing.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the coordinates are out of bounds or the patch was mapped read-only.
+    pub fn pixel_mut(&mut self, x: usize, y: usize) -> &mut T {
+        &mut self.row_mut(y)[x]
+    }
+
+    /// Returns all elements of the patch as one slice if there is no gap between its rows.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the patch was mapped write-only.
+    pub fn as_slice(&self) -> Option<&[T]> {
+        assert!(
+            self.mapping.access().is_readable(),
+            "patch is not mapped for reading"
+        );
+        self.is_contiguous().then(|| unsafe {
+            std::slice::from_raw_parts(self.mapping.ptr() as *const T, self.len())
+        })
+    }
+
+    /// Returns all elements of the patch as one slice for writing if there is no gap
+    /// between its rows.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the patch was mapped read-only.
+    pub fn as_mut_slice(&mut self) -> Option<&mut [T]> {
+        assert!(
+            self.mapping.access().is_writable(),
+            "patch is not mapped for writing"
+        );
+        self.is_contiguous().then(|| unsafe {
+            std::slice::from_raw_parts_mut(self.mapping.ptr() as *mut T, self.len())
+        })
+    }
+
+    /// Determines whether the rows of the patch directly follow each other in memory.
+    pub fn is_contiguous(&self) -> bool {
+        self.addressing.stride_y as usize == self.width() * std::mem::size_of::<T>()
+    }
+
+    fn len(&self) -> usize {
+        self.width() * self.height()
+    }
+
+    fn row_ptr(&self, y: usize) -> *mut T {
+        unsafe {
+            self.mapping
+                .ptr()
+                .add(y * self.addressing.stride_y as usize) as *mut T
+        }
+    }
+}
+
+#[cfg(feature = "ndarray")]
+impl<T: PixelType> ImagePatch<'_, T> {
+    /// Returns the patch as an array view with `(height, width)` axes that follows the
+    /// stride of the rows, or `None` if the stride is not a multiple of the element size.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the patch was mapped write-only.
+    pub fn as_array_view(&self) -> Option<ndarray::ArrayView2<'_, T>> {
+        use ndarray::ShapeBuilder;
+        assert!(
+            self.mapping.access().is_readable(),
+            "patch is not mapped for reading"
+        );
+        let shape = (self.height(), self.width()).strides((self.row_stride()?, 1));
+        Some(unsafe { ndarray::ArrayView2::from_shape_ptr(shape, self.mapping.ptr() as *const T) })
+    }
+
+    /// Returns the patch as a mutable array view with `(height, width)` axes that follows
+    /// the stride of the rows, or `None` if the stride is not a multiple of the element size.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the patch was mapped read-only.
+    pub fn as_array_view_mut(&mut self) -> Option<ndarray::ArrayViewMut2<'_, T>> {
+        use ndarray::ShapeBuilder;
+        assert!(
+            self.mapping.access().is_writable(),
+            "patch is not mapped for writing"
+        );
+        let shape = (self.height(), self.width()).strides((self.row_stride()?, 1));
+        Some(unsafe { ndarray::ArrayViewMut2::from_shape_ptr(shape, self.mapping.ptr() as *mut T) })
+    }
+
+    /// Returns the stride of the rows in elements.
+    fn row_stride(&self) -> Option<usize> {
+        let stride = self.addressing.stride_y as usize;
+        let element = std::mem::size_of::<T>();
+        stride.is_multiple_of(element).then_some(stride / element)
+    }
+}
+
+#[cfg(feature = "ndarray")]
+impl<const N: usize> ImagePatch<'_, [u8; N]>
+where
+    [u8; N]: PixelType,
+{
+    /// Returns the patch of a packed format such as `RGB` as an array view with
+    /// `(height, width, channel)` axes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the patch was mapped write-only.
+    pub fn as_channel_view(&self) -> ndarray::ArrayView3<'_, u8> {
+        use ndarray::ShapeBuilder;
+        assert!(
+            self.mapping.access().is_readable(),
+            "patch is not mapped for reading"
+        );
+        let shape = (self.height(), self.width(), N);
+        let strides = (self.addressing.stride_y as usize, N, 1);
+        unsafe { ndarray::ArrayView3::from_shape_ptr(shape.strides(strides), self.mapping.ptr()) }
+    }
+
+    /// Returns the patch of a packed format such as `RGB` as a mutable array view with
+    /// `(height, width, channel)` axes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the patch was mapped read-only.
+    pub fn as_channel_view_mut(&mut self) -> ndarray::ArrayViewMut3<'_, u8> {
+        use ndarray::ShapeBuilder;
+        assert!(
+            self.mapping.access().is_writable(),
+            "patch is not mapped for writing"
+        );
+        let shape = (self.height(), self.width(), N);
+        let strides = (self.addressing.stride_y as usize, N, 1);
+        unsafe {
+            ndarray::ArrayViewMut3::from_shape_ptr(shape.strides(strides), self.mapping.ptr())
+        }
+    }
+}
+
+/// Scales a dimension given in pixels of the zero plane to the plane's resolution.
+fn scaled(dimension: u32, scale: u32) -> usize {
+    (dimension as u64 * scale as u64 / VX_SCALE_UNITY as u64) as usize
+}
+
+impl<T: PixelType> Release for ImagePatch<'_, T> {
+    fn release(&mut self) -> Result<()> {
+        self.mapping.release()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn patch<T: PixelType>(data: &mut [u8], addressing: ImageAddressing) -> ImagePatch<'_, T> {
+        ImagePatch {
+            mapping: Mapping::detached(std::ptr::null_mut(), data.as_mut_ptr(), Access::ReadWrite),
+            addressing,
+            _marker: PhantomData,
+        }
+    }
+
+    #[test]
+    fn strided_rows() {
+        let mut data: Vec<u8> = (0..12).collect();
+        let patch = patch::<u8>(&mut data, ImageAddressing::new(3, 3, 1, 4));
+
+        assert_eq!((patch.width(), patch.height()), (3, 3));
+        assert!(!patch.is_contiguous());
+        assert!(patch.as_slice().is_none());
+        assert_eq!(
+            patch.rows().collect::<Vec<_>>(),
+            [[0, 1, 2], [4, 5, 6], [8, 9, 10]]
+        );
+        assert_eq!(*patch.pixel(2, 1), 6);
+    }
+
+    #[test]
+    fn contiguous_typed_slice() {
+        let mut data = vec![0u8; 12];
+        {
+            let mut patch = patch::<[u8; 3]>(&mut data, ImageAddressing::new(2, 2, 3, 6));
+
+            *patch.pixel_mut(1, 1) = [1, 2, 3];
+            assert_eq!(patch.as_slice().unwrap()[3], [1, 2, 3]);
+        }
+        assert_eq!(data[9..], [1, 2, 3]);
+    }
+
+    #[test]
+    fn subsampled_plane() {
+        let mut data = vec![0u8; 4];
+        let mut addressing = ImageAddressing::new(4, 4, 1, 2);
+        addressing.scale_x = VX_SCALE_UNITY / 2;
+        addressing.scale_y = VX_SCALE_UNITY / 2;
+        let patch = patch::<u8>(&mut data, addressing);
+
+        assert_eq!((patch.width(), patch.height()), (2, 2));
+        assert!(patch.is_contiguous());
+    }
+
+    #[cfg(feature = "ndarray")]
+    #[test]
+    fn array_views() {
+        let mut data: Vec<u8> = (0..16).collect();
+        {
+            let mut patch = patch::<[u8; 3]>(&mut data, ImageAddressing::new(2, 2, 3, 8));
+
+            assert!(patch.as_array_view().is_none());
+            let view = patch.as_channel_view();
+            assert_eq!(view.dim(), (2, 2, 3));
+            assert_eq!(view[[1, 1, 2]], 13);
+            patch.as_channel_view_mut()[[1, 0, 0]] = 0xFF;
+        }
+        assert_eq!(data[8], 0xFF);
+
+        let patch = patch::<u8>(&mut data, ImageAddressing::new(3, 2, 1, 8));
+        let view = patch.as_array_view().unwrap();
+        assert_eq!(view.dim(), (2, 3));
+        assert_eq!(view.row(1).to_vec(), [0xFF, 9, 10]);
+    }
+}
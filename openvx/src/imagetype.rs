@@ -1,4 +1,5 @@
-use libopenvx_sys::vx_df_image_e;
+use crate::ImageAddressing;
+use libopenvx_sys::{vx_df_image_e, VX_SCALE_UNITY};
 
 pub mod constants {
     use libopenvx_sys::vx_df_image_e;
@@ -86,6 +87,9 @@ pub mod constants {
     /// The range of data is not specified, as it may be extracted from a YUV or generated.
     pub const VX_DF_IMAGE_S32: vx_df_image_e = vx_df_image!('S', '0', '3', '2');
 
+    /// A raw sensor image created through the `vx_khr_raw_image` extension.
+    pub const VX_DF_IMAGE_RAW: vx_df_image_e = vx_df_image!('R', 'A', 'W', '0');
+
     #[cfg(test)]
     mod tests {
         use super::*;
@@ -217,6 +221,9 @@ pub enum ImageType {
     /// A single plane of signed 32-bit data.
     /// The range of data is not specified, as it may be extracted from a YUV or generated.
     S32,
+    /// A raw sensor image created through the `vx_khr_raw_image` extension;
+    /// see [`VxRawImage`](struct.VxRawImage.html).
+    Raw,
     /// An unknown image type.
     Other { r#type: vx_df_image_e },
 }
@@ -239,6 +246,7 @@ impl ImageType {
             constants::VX_DF_IMAGE_S16 => ImageType::S16,
             constants::VX_DF_IMAGE_U32 => ImageType::U32,
             constants::VX_DF_IMAGE_S32 => ImageType::S32,
+            constants::VX_DF_IMAGE_RAW => ImageType::Raw,
             other => ImageType::Other { r#type: other },
         }
     }
@@ -274,9 +282,224 @@ impl ImageType {
             ImageType::S16 => constants::VX_DF_IMAGE_S16,
             ImageType::U32 => constants::VX_DF_IMAGE_U32,
             ImageType::S32 => constants::VX_DF_IMAGE_S32,
+            ImageType::Raw => constants::VX_DF_IMAGE_RAW,
             ImageType::Other { r#type } => *r#type,
         }
     }
+
+    /// Creates an image type from the four characters of its FourCC code,
+    /// e.g. for vendor-defined formats.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use openvx::{vx_df_image, ImageType};
+    ///
+    /// let y10 = ImageType::from_fourcc(*b"Y10L");
+    /// assert_eq!(y10, ImageType::from(vx_df_image!('Y', '1', '0', 'L')));
+    /// assert_eq!(y10.fourcc(), *b"Y10L");
+    /// assert_eq!(y10.planes(), None);
+    ///
+    /// assert_eq!(ImageType::from_fourcc(*b"U008"), ImageType::U8);
+    /// ```
+    pub fn from_fourcc(code: [u8; 4]) -> Self {
+        ImageType::new(u32::from_le_bytes(code) as vx_df_image_e)
+    }
+
+    /// Returns the four characters of the FourCC code of this type.
+    pub const fn fourcc(&self) -> [u8; 4] {
+        self.to_raw().to_le_bytes()
+    }
+
+    /// Returns the number of planes of the format.
+    ///
+    /// Returns `None` for [`Virtual`], [`Raw`] and vendor-defined formats, whose layout is unknown.
+    ///
+    /// [`Virtual`]: #variant.Virtual
+    /// [`Raw`]: #variant.Raw
+    pub const fn planes(&self) -> Option<u32> {
+        match self.layout() {
+            Some(layout) => Some(layout.len() as u32),
+            None => None,
+        }
+    }
+
+    /// Returns the number of channels stored in plane `plane`, e.g. `2` for the
+    /// interleaved chroma plane of `NV12`.
+    pub const fn channels(&self, plane: u32) -> Option<u32> {
+        match self.plane(plane) {
+            Some(layout) => Some(layout.channels),
+            None => None,
+        }
+    }
+
+    /// Returns the number of bits per pixel of plane `plane`, e.g. `1` for `U1`
+    /// and `16` for the macro pixels of `UYVY`.
+    pub const fn bits_per_pixel(&self, plane: u32) -> Option<u32> {
+        match self.plane(plane) {
+            Some(layout) => Some(layout.bits),
+            None => None,
+        }
+    }
+
+    /// Returns the number of bytes per pixel of plane `plane`.
+    ///
+    /// Returns `None` for bit-packed formats such as `U1`; use
+    /// [`bits_per_pixel`](#method.bits_per_pixel) instead.
+    pub const fn bytes_per_pixel(&self, plane: u32) -> Option<u32> {
+        match self.bits_per_pixel(plane) {
+            Some(bits) if bits % 8 == 0 => Some(bits / 8),
+            _ => None,
+        }
+    }
+
+    /// Returns the horizontal and vertical sub-sampling factors of plane `plane`
+    /// relative to the first plane, e.g. `(2, 2)` for the chroma planes of `IYUV`.
+    pub const fn subsampling(&self, plane: u32) -> Option<(u32, u32)> {
+        match self.plane(plane) {
+            Some(layout) => Some(layout.subsampling),
+            None => None,
+        }
+    }
+
+    /// Returns the addressing of plane `plane` of a tightly packed image of the given size,
+    /// e.g. to allocate host memory for [`VxImage::from_handle`].
+    ///
+    /// [`VxImage::from_handle`]: struct.VxImage.html#method.from_handle
+    pub const fn plane_addressing(
+        &self,
+        plane: u32,
+        width: u32,
+        height: u32,
+    ) -> Option<ImageAddressing> {
+        let layout = match self.plane(plane) {
+            Some(layout) => layout,
+            None => return None,
+        };
+
+        let (subsampling_x, subsampling_y) = layout.subsampling;
+        let row_bits = (width / subsampling_x) as usize * layout.bits as usize;
+        let stride_y = row_bits.div_ceil(8) as i32;
+
+        let mut addressing =
+            ImageAddressing::new(width, height, (layout.bits / 8) as i32, stride_y);
+        addressing.stride_x_bits = layout.bits as u16;
+        addressing.scale_x = VX_SCALE_UNITY / subsampling_x;
+        addressing.scale_y = VX_SCALE_UNITY / subsampling_y;
+        Some(addressing)
+    }
+
+    /// Returns the number of bytes of plane `plane` of a tightly packed image of the given size.
+    pub const fn plane_size(&self, plane: u32, width: u32, height: u32) -> Option<usize> {
+        match (
+            self.plane_addressing(plane, width, height),
+            self.subsampling(plane),
+        ) {
+            (Some(addressing), Some((_, subsampling_y))) => {
+                Some(addressing.stride_y as usize * (height / subsampling_y) as usize)
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the number of bytes of all planes of a tightly packed image of the given size.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use openvx::ImageType;
+    ///
+    /// assert_eq!(ImageType::RGB.buffer_size(640, 480), Some(640 * 480 * 3));
+    /// assert_eq!(ImageType::IYUV.buffer_size(640, 480), Some(640 * 480 * 3 / 2));
+    /// assert_eq!(ImageType::U1.buffer_size(10, 2), Some(4));
+    /// ```
+    pub const fn buffer_size(&self, width: u32, height: u32) -> Option<usize> {
+        let planes = match self.planes() {
+            Some(planes) => planes,
+            None => return None,
+        };
+
+        let mut size = 0;
+        let mut plane = 0;
+        while plane < planes {
+            size += match self.plane_size(plane, width, height) {
+                Some(plane_size) => plane_size,
+                None => return None,
+            };
+            plane += 1;
+        }
+        Some(size)
+    }
+
+    const fn plane(&self, plane: u32) -> Option<PlaneLayout> {
+        match self.layout() {
+            Some(layout) if (plane as usize) < layout.len() => Some(layout[plane as usize]),
+            _ => None,
+        }
+    }
+
+    const fn layout(&self) -> Option<&'static [PlaneLayout]> {
+        const RGB: &[PlaneLayout] = &[PlaneLayout::full(3, 24)];
+        const RGBX: &[PlaneLayout] = &[PlaneLayout::full(4, 32)];
+        const NV: &[PlaneLayout] = &[PlaneLayout::full(1, 8), PlaneLayout::half(2, 16)];
+        const YUV422: &[PlaneLayout] = &[PlaneLayout::full(2, 16)];
+        const IYUV: &[PlaneLayout] = &[
+            PlaneLayout::full(1, 8),
+            PlaneLayout::half(1, 8),
+            PlaneLayout::half(1, 8),
+        ];
+        const YUV4: &[PlaneLayout] = &[
+            PlaneLayout::full(1, 8),
+            PlaneLayout::full(1, 8),
+            PlaneLayout::full(1, 8),
+        ];
+        const U1: &[PlaneLayout] = &[PlaneLayout::full(1, 1)];
+        const U8: &[PlaneLayout] = &[PlaneLayout::full(1, 8)];
+        const X16: &[PlaneLayout] = &[PlaneLayout::full(1, 16)];
+        const X32: &[PlaneLayout] = &[PlaneLayout::full(1, 32)];
+
+        match self {
+            ImageType::RGB => Some(RGB),
+            ImageType::RGBX => Some(RGBX),
+            ImageType::NV12 | ImageType::NV21 => Some(NV),
+            ImageType::UYVY | ImageType::YUYV => Some(YUV422),
+            ImageType::IYUV => Some(IYUV),
+            ImageType::YUV4 => Some(YUV4),
+            ImageType::U1 => Some(U1),
+            ImageType::U8 => Some(U8),
+            ImageType::U16 | ImageType::S16 => Some(X16),
+            ImageType::U32 | ImageType::S32 => Some(X32),
+            ImageType::Virtual | ImageType::Raw | ImageType::Other { .. } => None,
+        }
+    }
+}
+
+/// The memory layout of a single plane of a format.
+#[derive(Debug, Copy, Clone)]
+struct PlaneLayout {
+    channels: u32,
+    bits: u32,
+    subsampling: (u32, u32),
+}
+
+impl PlaneLayout {
+    /// A plane at the full resolution of the image.
+    const fn full(channels: u32, bits: u32) -> Self {
+        Self {
+            channels,
+            bits,
+            subsampling: (1, 1),
+        }
+    }
+
+    /// A plane sub-sampled by two in both dimensions.
+    const fn half(channels: u32, bits: u32) -> Self {
+        Self {
+            channels,
+            bits,
+            subsampling: (2, 2),
+        }
+    }
 }
 
 impl From<vx_df_image_e> for ImageType {
@@ -289,6 +512,35 @@ impl From<vx_df_image_e> for ImageType {
 mod tests {
     use super::*;
 
+    #[test]
+    fn planar_metadata() {
+        assert_eq!(ImageType::NV12.planes(), Some(2));
+        assert_eq!(ImageType::NV12.channels(1), Some(2));
+        assert_eq!(ImageType::NV12.subsampling(1), Some((2, 2)));
+        assert_eq!(ImageType::NV12.subsampling(2), None);
+        assert_eq!(ImageType::YUV4.subsampling(2), Some((1, 1)));
+        assert_eq!(ImageType::IYUV.plane_size(1, 64, 48), Some(32 * 24));
+        assert_eq!(ImageType::NV21.buffer_size(64, 48), Some(64 * 48 * 3 / 2));
+    }
+
+    #[test]
+    fn packed_metadata() {
+        assert_eq!(ImageType::U1.bits_per_pixel(0), Some(1));
+        assert_eq!(ImageType::U1.bytes_per_pixel(0), None);
+        assert_eq!(ImageType::YUYV.bytes_per_pixel(0), Some(2));
+        assert_eq!(ImageType::S16.bytes_per_pixel(0), Some(2));
+
+        let addressing = ImageType::U1.plane_addressing(0, 17, 3).unwrap();
+        assert_eq!((addressing.stride_x, addressing.stride_x_bits), (0, 1));
+        assert_eq!(addressing.stride_y, 3);
+    }
+
+    #[test]
+    fn unknown_layout() {
+        assert_eq!(ImageType::Virtual.planes(), None);
+        assert_eq!(ImageType::from_fourcc(*b"Y10L").buffer_size(4, 4), None);
+    }
+
     #[test]
     fn type_virt() {
         assert_eq!(ImageType::Virtual.to_raw(), constants::VX_DF_IMAGE_VIRT);
@@ -402,4 +654,10 @@ mod tests {
         assert_eq!(ImageType::S32.to_raw(), constants::VX_DF_IMAGE_S32);
         assert_eq!(ImageType::from(constants::VX_DF_IMAGE_S32), ImageType::S32);
     }
+
+    #[test]
+    fn types_raw() {
+        assert_eq!(ImageType::Raw.fourcc(), *b"RAW0");
+        assert_eq!(ImageType::from(constants::VX_DF_IMAGE_RAW), ImageType::Raw);
+    }
 }
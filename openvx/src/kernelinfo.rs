@@ -0,0 +1,66 @@
+use crate::attr::string_from_chars;
+use crate::{Direction, ParameterState, Result, VxContext, VxKernel, VxType};
+use libopenvx_sys::{vx_enum, vx_kernel_info_t, vx_uint32};
+
+/// An entry of the unique kernel table of a context (`VX_CONTEXT_UNIQUE_KERNEL_TABLE`).
+///
+/// See [`VxContext::kernels`](struct.VxContext.html#method.kernels).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct KernelInfo {
+    /// The kernel enumeration value, e.g. `VX_KERNEL_COLOR_CONVERT`.
+    pub enumeration: vx_enum,
+    /// The name of the kernel, e.g. `org.khronos.openvx.color_convert`.
+    pub name: String,
+}
+
+impl KernelInfo {
+    /// Obtains a reference to the described kernel by its enumeration.
+    pub fn resolve<'ctx>(&self, context: &'ctx VxContext) -> Result<VxKernel<'ctx>> {
+        VxKernel::get_by_enum(context, self.enumeration)
+    }
+}
+
+impl From<vx_kernel_info_t> for KernelInfo {
+    fn from(info: vx_kernel_info_t) -> Self {
+        Self {
+            enumeration: info.enumeration,
+            name: string_from_chars(&info.name),
+        }
+    }
+}
+
+/// Describes a single parameter of a kernel signature.
+///
+/// See [`VxKernel::parameters`](struct.VxKernel.html#method.parameters).
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ParameterInfo {
+    /// The index of the parameter.
+    pub index: vx_uint32,
+    /// Whether the parameter is an input or an output.
+    pub direction: Direction,
+    /// The type of object expected for the parameter, e.g. [`VxType::Image`](enum.VxType.html#variant.Image).
+    pub r#type: VxType,
+    /// Whether the parameter is required or optional.
+    pub state: ParameterState,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use libopenvx_sys::vx_char;
+
+    #[test]
+    fn kernel_info_from_raw() {
+        let mut raw = vx_kernel_info_t {
+            enumeration: 0x12,
+            name: [0 as vx_char; 256],
+        };
+        for (c, b) in raw.name.iter_mut().zip(b"org.khronos.openvx.color_convert") {
+            *c = *b as vx_char;
+        }
+
+        let info = KernelInfo::from(raw);
+        assert_eq!(info.enumeration, 0x12);
+        assert_eq!(info.name, "org.khronos.openvx.color_convert");
+    }
+}
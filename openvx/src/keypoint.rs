@@ -0,0 +1,98 @@
+use crate::Coordinates2df;
+use libopenvx_sys::{vx_float32, vx_int32, vx_keypoint_t};
+
+/// A keypoint as output by corner detectors and optical flow, e.g. `vxHarrisCornersNode`.
+///
+/// The layout matches [`vx_keypoint_t`], so the type can be used as item of a
+/// [`VxArray`](struct.VxArray.html).
+///
+/// [`vx_keypoint_t`]: ../libopenvx_sys/type.vx_keypoint_t.html
+#[repr(C)]
+#[derive(Debug, Default, PartialEq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Keypoint {
+    /// The x coordinate.
+    pub x: vx_int32,
+    /// The y coordinate.
+    pub y: vx_int32,
+    /// The strength of the keypoint; its definition is specific to the corner detector.
+    pub strength: vx_float32,
+    /// Initialized to `0` by corner detectors.
+    pub scale: vx_float32,
+    /// Initialized to `0` by corner detectors.
+    pub orientation: vx_float32,
+    /// A zero indicates a lost point; initialized to `1` by corner detectors.
+    pub tracking_status: vx_int32,
+    /// A tracking method specific error; initialized to `0` by corner detectors.
+    pub error: vx_float32,
+}
+
+static_assertions::assert_eq_size!(Keypoint, vx_keypoint_t);
+
+impl Keypoint {
+    /// Creates a tracked keypoint at the given coordinates.
+    pub const fn new(x: vx_int32, y: vx_int32, strength: vx_float32) -> Self {
+        Self {
+            x,
+            y,
+            strength,
+            scale: 0.0,
+            orientation: 0.0,
+            tracking_status: 1,
+            error: 0.0,
+        }
+    }
+
+    /// Determines whether the keypoint is still tracked.
+    pub const fn is_tracked(&self) -> bool {
+        self.tracking_status != 0
+    }
+
+    /// Returns the coordinates of the keypoint.
+    pub fn position(&self) -> Coordinates2df {
+        Coordinates2df::new(self.x as vx_float32, self.y as vx_float32)
+    }
+
+    /// Converts this instance into a [`vx_keypoint_t`].
+    ///
+    /// [`vx_keypoint_t`]: ../libopenvx_sys/type.vx_keypoint_t.html
+    pub const fn to_raw(&self) -> vx_keypoint_t {
+        vx_keypoint_t {
+            x: self.x,
+            y: self.y,
+            strength: self.strength,
+            scale: self.scale,
+            orientation: self.orientation,
+            tracking_status: self.tracking_status,
+            error: self.error,
+        }
+    }
+}
+
+impl From<vx_keypoint_t> for Keypoint {
+    fn from(value: vx_keypoint_t) -> Self {
+        Self {
+            x: value.x,
+            y: value.y,
+            strength: value.strength,
+            scale: value.scale,
+            orientation: value.orientation,
+            tracking_status: value.tracking_status,
+            error: value.error,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roundtrip() {
+        let mut keypoint = Keypoint::new(10, -2, 0.25);
+        keypoint.error = 1.5;
+        assert_eq!(Keypoint::from(keypoint.to_raw()), keypoint);
+        assert!(keypoint.is_tracked());
+        assert_eq!(keypoint.position(), Coordinates2df::new(10.0, -2.0));
+    }
+}
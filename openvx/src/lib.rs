@@ -1,32 +1,113 @@
 #[macro_use]
 mod macros;
 
+mod access;
+mod arrayitem;
+mod arrayrange;
 mod asraw;
+pub mod attr;
+mod bitpatch;
+mod border;
+mod borderpolicy;
+mod channel;
+mod channelrange;
 mod checkstatus;
+mod colorspace;
+mod contextinfo;
+mod coordinates;
+mod direction;
 mod directives;
+mod error;
+mod exposureinterleaving;
+mod hog;
+mod hostbuffer;
+mod imageaddressing;
+#[cfg(feature = "image")]
+mod imagebuffer;
+mod imagepatch;
 mod imagetype;
+mod kernelinfo;
+mod keypoint;
+mod line2d;
+mod logging;
+mod mapping;
+mod matrixelement;
+mod memorytype;
 pub mod name;
+mod parameterstate;
+mod pattern;
 mod performance;
+mod pixelcontainer;
+mod pixeltype;
+mod pixelvalue;
+mod rawbuffer;
+mod rawformat;
+mod rect;
 mod release;
 mod result;
+mod scalartype;
+mod tensorelement;
+mod tensorpatch;
+mod transform;
 pub mod types;
 mod vxerror;
 mod vxgraphstate;
 mod vxstatus;
+mod vxtype;
 
+pub use crate::access::Access;
+pub use crate::arrayitem::ArrayItem;
+pub use crate::arrayrange::ArrayRange;
 pub use crate::asraw::AsRaw;
+pub use crate::attr::{QueryAttribute, SetAttribute};
+pub use crate::bitpatch::BitPatch;
+pub use crate::border::Border;
+pub use crate::borderpolicy::BorderPolicy;
+pub use crate::channel::Channel;
+pub use crate::channelrange::ChannelRange;
 pub use crate::checkstatus::CheckStatus;
+pub use crate::colorspace::ColorSpace;
+pub use crate::contextinfo::{ContextInfo, VxVersion};
+pub use crate::coordinates::{Coordinates2d, Coordinates2df, Coordinates3d};
+pub use crate::direction::Direction;
 pub use crate::directives::{SetDirective, VxDirective};
+pub use crate::error::Error;
+pub use crate::exposureinterleaving::ExposureInterleaving;
+pub use crate::hog::HogParams;
+pub use crate::hostbuffer::HostBuffer;
+pub use crate::imageaddressing::{ImageAddressing, ImagePlane};
+#[cfg(feature = "image")]
+pub use crate::imagebuffer::{ImageIoError, ImagePixel};
+pub use crate::imagepatch::ImagePatch;
 pub use crate::imagetype::ImageType;
+pub use crate::kernelinfo::{KernelInfo, ParameterInfo};
+pub use crate::keypoint::Keypoint;
+pub use crate::line2d::Line2d;
+pub use crate::logging::LogEntry;
+pub use crate::matrixelement::MatrixElement;
+pub use crate::memorytype::MemoryType;
 pub use crate::name::{GetName, SetName};
+pub use crate::parameterstate::ParameterState;
+pub use crate::pattern::Pattern;
 pub use crate::performance::Performance;
+pub use crate::pixelcontainer::PixelContainer;
+pub use crate::pixeltype::PixelType;
+pub use crate::pixelvalue::PixelValue;
+pub use crate::rawbuffer::RawBuffer;
+pub use crate::rawformat::{CfaPattern, RawFormat};
+pub use crate::rect::Rect;
 pub use crate::reference::{AsVxReference, ReferenceCount, VxReference};
 pub use crate::release::Release;
 pub use crate::result::Result;
+pub use crate::scalartype::{ScalarType, VxChar, VxEnum};
+pub use crate::tensorelement::TensorElement;
+pub use crate::tensorpatch::TensorPatch;
+pub use crate::transform::{AffineTransform, PerspectiveTransform};
 pub use crate::types::*;
 pub use crate::vxerror::VxError;
 pub use crate::vxgraphstate::VxGraphState;
 pub use crate::vxstatus::VxStatus;
+pub use crate::vxtype::VxType;
 
 pub mod constants {
     pub use crate::directives::constants as directives;
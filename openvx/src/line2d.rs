@@ -0,0 +1,87 @@
+use crate::Coordinates2df;
+use libopenvx_sys::{vx_float32, vx_line2d_t};
+
+/// A line segment as output by `vxHoughLinesPNode`.
+///
+/// The layout matches [`vx_line2d_t`], so the type can be used as item of a
+/// [`VxArray`](struct.VxArray.html).
+///
+/// [`vx_line2d_t`]: ../libopenvx_sys/type.vx_line2d_t.html
+#[repr(C)]
+#[derive(Debug, Default, PartialEq, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Line2d {
+    /// The x coordinate of the start point.
+    pub start_x: vx_float32,
+    /// The y coordinate of the start point.
+    pub start_y: vx_float32,
+    /// The x coordinate of the end point.
+    pub end_x: vx_float32,
+    /// The y coordinate of the end point.
+    pub end_y: vx_float32,
+}
+
+static_assertions::assert_eq_size!(Line2d, vx_line2d_t);
+
+impl Line2d {
+    /// Creates a line segment from its start and end point.
+    pub const fn new(start: Coordinates2df, end: Coordinates2df) -> Self {
+        Self {
+            start_x: start.x,
+            start_y: start.y,
+            end_x: end.x,
+            end_y: end.y,
+        }
+    }
+
+    /// Returns the start point.
+    pub const fn start(&self) -> Coordinates2df {
+        Coordinates2df::new(self.start_x, self.start_y)
+    }
+
+    /// Returns the end point.
+    pub const fn end(&self) -> Coordinates2df {
+        Coordinates2df::new(self.end_x, self.end_y)
+    }
+
+    /// Returns the length of the segment.
+    pub fn length(&self) -> vx_float32 {
+        (self.end_x - self.start_x).hypot(self.end_y - self.start_y)
+    }
+
+    /// Converts this instance into a [`vx_line2d_t`].
+    ///
+    /// [`vx_line2d_t`]: ../libopenvx_sys/type.vx_line2d_t.html
+    pub const fn to_raw(&self) -> vx_line2d_t {
+        vx_line2d_t {
+            start_x: self.start_x,
+            start_y: self.start_y,
+            end_x: self.end_x,
+            end_y: self.end_y,
+        }
+    }
+}
+
+impl From<vx_line2d_t> for Line2d {
+    fn from(value: vx_line2d_t) -> Self {
+        Self {
+            start_x: value.start_x,
+            start_y: value.start_y,
+            end_x: value.end_x,
+            end_y: value.end_y,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roundtrip() {
+        let line = Line2d::new(Coordinates2df::new(1.0, 1.0), Coordinates2df::new(4.0, 5.0));
+        assert_eq!(Line2d::from(line.to_raw()), line);
+        assert_eq!(line.end(), Coordinates2df::new(4.0, 5.0));
+        assert_eq!(line.length(), 5.0);
+    }
+}
@@ -0,0 +1,261 @@
+use crate::{attr, QueryAttribute, VxReference, VxStatus, VxType};
+use core::fmt;
+use libopenvx_sys::{vx_char, vx_context, vx_reference, vx_status, vx_status_e};
+use std::cell::RefCell;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::sync::{Arc, Mutex};
+
+/// A log handler registered through [`VxContext::set_log_handler`](struct.VxContext.html#method.set_log_handler).
+pub(crate) type LogHandler = dyn for<'a> Fn(LogEntry<'a>) + Send + Sync;
+
+/// The log handlers of all contexts, keyed by the address of the context.
+static HANDLERS: Mutex<Vec<(usize, Arc<LogHandler>)>> = Mutex::new(Vec::new());
+
+thread_local! {
+    /// The messages logged on this thread while a call is being captured.
+    static CAPTURED: RefCell<Option<Vec<CapturedEntry>>> = const { RefCell::new(None) };
+}
+
+/// An owned copy of a [`LogEntry`] captured during a call; see [`capture`].
+#[derive(Debug, Clone)]
+pub(crate) struct CapturedEntry {
+    pub reference: vx_reference,
+    pub name: Option<String>,
+    pub r#type: VxType,
+    pub message: String,
+}
+
+/// A log message emitted by the implementation through `vxAddLogEntry`.
+///
+/// The entry borrows the reference it originates from and is only valid for the
+/// duration of the handler invocation.
+#[derive(Debug)]
+pub struct LogEntry<'a> {
+    /// The status code the message was logged with.
+    pub status: VxStatus,
+    /// The reference the message originates from.
+    pub reference: VxReference<'a>,
+    /// The name of the reference, if one was set.
+    pub name: Option<String>,
+    /// The type of the reference.
+    pub r#type: VxType,
+    /// The message.
+    pub message: String,
+}
+
+impl fmt::Display for LogEntry<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.name {
+            Some(name) => write!(f, "{} '{}': ", self.r#type, name)?,
+            None => write!(f, "{}: ", self.r#type)?,
+        }
+        write!(f, "{} - {}", self.status, self.message)
+    }
+}
+
+/// The handler installed by [`VxContext::enable_logging`](struct.VxContext.html#method.enable_logging)
+/// if no other handler was registered.
+///
+/// With the `log` feature enabled, messages are forwarded to the [`log`](https://docs.rs/log) crate
+/// using the `openvx` target; otherwise they are written to the standard error output.
+pub(crate) fn default_handler(entry: LogEntry) {
+    #[cfg(feature = "log")]
+    log::error!(target: "openvx", "{}", entry);
+
+    #[cfg(not(feature = "log"))]
+    eprintln!("ERROR: {}", entry);
+}
+
+/// Registers the handler for the given context, replacing any previous handler.
+pub(crate) fn register(context: vx_context, handler: Arc<LogHandler>) {
+    let key = context as usize;
+    let mut handlers = HANDLERS.lock().unwrap_or_else(|e| e.into_inner());
+    handlers.retain(|(k, _)| *k != key);
+    handlers.push((key, handler));
+}
+
+/// Removes the handler of the given context. Returns whether a handler was registered.
+pub(crate) fn unregister(context: vx_context) -> bool {
+    let key = context as usize;
+    let mut handlers = HANDLERS.lock().unwrap_or_else(|e| e.into_inner());
+    let count = handlers.len();
+    handlers.retain(|(k, _)| *k != key);
+    handlers.len() != count
+}
+
+/// Determines whether a handler is registered for the given context.
+pub(crate) fn is_registered(context: vx_context) -> bool {
+    handler_for(context).is_some()
+}
+
+fn handler_for(context: vx_context) -> Option<Arc<LogHandler>> {
+    let key = context as usize;
+    let handlers = HANDLERS.lock().unwrap_or_else(|e| e.into_inner());
+    handlers
+        .iter()
+        .find(|(k, _)| *k == key)
+        .map(|(_, handler)| handler.clone())
+}
+
+/// Invokes `call` and returns its result together with the messages the implementation
+/// logged on the current thread in the meantime.
+///
+/// Messages are only received for contexts with a registered log handler, e.g. after
+/// calling [`VxContext::enable_logging`](struct.VxContext.html#method.enable_logging).
+pub(crate) fn capture<T>(call: impl FnOnce() -> T) -> (T, Vec<CapturedEntry>) {
+    let previous = CAPTURED.with(|captured| captured.replace(Some(Vec::new())));
+    let result = call();
+    let entries = CAPTURED.with(|captured| captured.replace(previous));
+    (result, entries.unwrap_or_default())
+}
+
+/// The callback registered with `vxRegisterLogCallback`; dispatches to the handler of the context.
+pub(crate) extern "C" fn log_callback(
+    context: vx_context,
+    r#ref: vx_reference,
+    status: vx_status,
+    string: *const vx_char,
+) {
+    let message = if string.is_null() {
+        String::new()
+    } else {
+        unsafe { std::ffi::CStr::from_ptr(string) }
+            .to_string_lossy()
+            .trim_end()
+            .to_owned()
+    };
+
+    let reference = VxReference::from(r#ref);
+    let (name, r#type) = if reference.is_null() {
+        (None, VxType::Invalid)
+    } else {
+        (
+            reference.query::<attr::Name>().ok().flatten(),
+            reference
+                .query::<attr::ReferenceType>()
+                .unwrap_or(VxType::Invalid),
+        )
+    };
+
+    CAPTURED.with(|captured| {
+        if let Some(entries) = captured.borrow_mut().as_mut() {
+            entries.push(CapturedEntry {
+                reference: r#ref,
+                name: name.clone(),
+                r#type,
+                message: message.clone(),
+            });
+        }
+    });
+
+    // The lock is released before calling the handler so that it may itself use the context.
+    let handler = match handler_for(context) {
+        Some(handler) => handler,
+        None => return,
+    };
+
+    let entry = LogEntry {
+        status: VxStatus::from(status as vx_status_e),
+        reference,
+        name,
+        r#type,
+        message,
+    };
+
+    // Unwinding into the implementation is undefined; swallow panics of the handler instead.
+    let _ = catch_unwind(AssertUnwindSafe(|| handler(entry)));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::VxError;
+    use libopenvx_sys::vx_status_e_VX_ERROR_INVALID_FORMAT;
+    use std::ffi::CString;
+
+    fn fake_context(id: usize) -> vx_context {
+        // Never dereferenced; only used as a key.
+        (0x1000 + id * 0x10) as vx_context
+    }
+
+    #[test]
+    fn routes_to_the_handler_of_the_context() {
+        let first = Arc::new(Mutex::new(Vec::new()));
+        let second = Arc::new(Mutex::new(Vec::new()));
+
+        let sink = first.clone();
+        register(
+            fake_context(1),
+            Arc::new(move |entry: LogEntry| sink.lock().unwrap().push(entry.message)),
+        );
+        let sink = second.clone();
+        register(
+            fake_context(2),
+            Arc::new(move |entry: LogEntry| sink.lock().unwrap().push(entry.to_string())),
+        );
+
+        let message = CString::new("parameter 1 expects IYUV\n").unwrap();
+        log_callback(
+            fake_context(2),
+            std::ptr::null_mut(),
+            vx_status_e_VX_ERROR_INVALID_FORMAT,
+            message.as_ptr(),
+        );
+
+        assert!(first.lock().unwrap().is_empty());
+        assert_eq!(
+            *second.lock().unwrap(),
+            [format!(
+                "VX_TYPE_INVALID: {} - parameter 1 expects IYUV",
+                VxStatus::Error(VxError::InvalidFormat)
+            )]
+        );
+
+        assert!(unregister(fake_context(1)));
+        assert!(unregister(fake_context(2)));
+        assert!(!is_registered(fake_context(2)));
+    }
+
+    #[test]
+    fn handler_panics_do_not_unwind() {
+        register(fake_context(3), Arc::new(|_: LogEntry| panic!("handler")));
+        log_callback(
+            fake_context(3),
+            std::ptr::null_mut(),
+            vx_status_e_VX_ERROR_INVALID_FORMAT,
+            std::ptr::null(),
+        );
+        assert!(unregister(fake_context(3)));
+    }
+
+    #[test]
+    fn captures_messages_of_the_current_thread() {
+        let message = CString::new("parameter 1 expects IYUV").unwrap();
+        let ((), entries) = capture(|| {
+            log_callback(
+                fake_context(5),
+                std::ptr::null_mut(),
+                vx_status_e_VX_ERROR_INVALID_FORMAT,
+                message.as_ptr(),
+            )
+        });
+
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].message, "parameter 1 expects IYUV");
+        assert_eq!(entries[0].r#type, VxType::Invalid);
+
+        let ((), entries) = capture(|| ());
+        assert!(entries.is_empty());
+    }
+
+    #[test]
+    fn unregistered_contexts_are_ignored() {
+        log_callback(
+            fake_context(4),
+            std::ptr::null_mut(),
+            vx_status_e_VX_ERROR_INVALID_FORMAT,
+            std::ptr::null(),
+        );
+        assert!(!unregister(fake_context(4)));
+    }
+}
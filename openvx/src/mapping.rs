@@ -0,0 +1,144 @@
+use crate::{Access, Release, Result, VxError, VxStatus};
+use libopenvx_sys::{vx_map_id, vx_status};
+use std::ffi::c_void;
+
+/// The `vxUnmap*` function releasing a mapping of an object of type `R`.
+pub(crate) type Unmap<R> = unsafe extern "C" fn(R, vx_map_id) -> vx_status;
+
+/// Host memory of an object mapped by one of the `vxMap*` functions.
+///
+/// This is the common part of the mapping guards such as [`ImagePatch`](struct.ImagePatch.html);
+/// the memory is unmapped when the mapping is released or dropped.
+#[derive(Debug)]
+pub(crate) struct Mapping<R: Copy> {
+    object: R,
+    map_id: vx_map_id,
+    ptr: *mut u8,
+    access: Access,
+    unmap: Option<Unmap<R>>,
+}
+
+impl<R: Copy> Mapping<R> {
+    /// Takes over a mapping established by a successful `vxMap*` call on `object`.
+    ///
+    /// The mapping is released by calling `unmap` from here on, including the error paths of
+    /// the caller that follow, e.g. when [`check_layout`](#method.check_layout) fails.
+    pub(crate) fn new(
+        object: R,
+        map_id: vx_map_id,
+        ptr: *mut c_void,
+        access: Access,
+        unmap: Unmap<R>,
+    ) -> Self {
+        Self {
+            object,
+            map_id,
+            ptr: ptr as *mut u8,
+            access,
+            unmap: Some(unmap),
+        }
+    }
+
+    /// Returns the first mapped byte.
+    pub(crate) fn ptr(&self) -> *mut u8 {
+        self.ptr
+    }
+
+    /// Returns the accessor the memory was mapped with.
+    pub(crate) fn access(&self) -> Access {
+        self.access
+    }
+
+    /// Checks that the memory can be accessed as elements of type `T` located at multiples of
+    /// the given byte strides, i.e. that the pointer is non-null and that it and all strides
+    /// are aligned for `T`.
+    ///
+    /// Returns [`VxError::NotSupported`] otherwise.
+    pub(crate) fn check_layout<T>(&self, strides: &[usize]) -> Result<()> {
+        let alignment = std::mem::align_of::<T>();
+        if self.ptr.is_null()
+            || !(self.ptr as usize).is_multiple_of(alignment)
+            || strides
+                .iter()
+                .any(|stride| !stride.is_multiple_of(alignment))
+        {
+            return Err(VxError::NotSupported.into());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+impl<R: Copy> Mapping<R> {
+    /// Wraps host memory that is not mapped from any object and is never unmapped.
+    pub(crate) fn detached(object: R, ptr: *mut u8, access: Access) -> Self {
+        Self {
+            object,
+            map_id: 0,
+            ptr,
+            access,
+            unmap: None,
+        }
+    }
+}
+
+impl<R: Copy> Release for Mapping<R> {
+    fn release(&mut self) -> Result<()> {
+        if self.ptr.is_null() {
+            return Ok(());
+        }
+
+        let status = match self.unmap {
+            Some(unmap) => unsafe { unmap(self.object, self.map_id) },
+            None => VxStatus::Success.to_raw(),
+        };
+        self.ptr = std::ptr::null_mut();
+
+        VxStatus::new_result(status, ())
+    }
+}
+
+impl<R: Copy> Drop for Mapping<R> {
+    fn drop(&mut self) {
+        self.release().unwrap();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_layout() {
+        let mut data = [0u32; 4];
+        let ptr = data.as_mut_ptr() as *mut u8;
+        let mapping = Mapping::detached((), ptr, Access::ReadWrite);
+        assert!(mapping.check_layout::<u32>(&[4, 16]).is_ok());
+        assert!(mapping.check_layout::<[u8; 3]>(&[3, 7]).is_ok());
+        assert_eq!(
+            mapping.check_layout::<u32>(&[4, 6]).unwrap_err(),
+            VxError::NotSupported
+        );
+
+        let unaligned = Mapping::detached((), unsafe { ptr.add(1) }, Access::Read);
+        assert_eq!(
+            unaligned.check_layout::<u16>(&[]).unwrap_err(),
+            VxError::NotSupported
+        );
+
+        let null = Mapping::detached((), std::ptr::null_mut(), Access::Read);
+        assert_eq!(
+            null.check_layout::<u8>(&[]).unwrap_err(),
+            VxError::NotSupported
+        );
+    }
+
+    #[test]
+    fn release() {
+        let mut data = [0u8; 1];
+        let mut mapping = Mapping::detached((), data.as_mut_ptr(), Access::Read);
+        assert!(mapping.release().is_ok());
+        assert!(mapping.ptr().is_null());
+        assert!(mapping.release().is_ok());
+    }
+}
@@ -0,0 +1,33 @@
+use crate::VxType;
+
+/// A type that can represent the elements of a matrix in host memory.
+///
+/// | Type  | Data type         |
+/// |-------|-------------------|
+/// | `u8`  | `VX_TYPE_UINT8`   |
+/// | `i32` | `VX_TYPE_INT32`   |
+/// | `f32` | `VX_TYPE_FLOAT32` |
+///
+/// # Safety
+///
+/// Implementors must be plain old data with the size of an element of `DATA_TYPE`.
+pub unsafe trait MatrixElement: Copy + Default + 'static {
+    /// The data type of matrices with elements of this type.
+    const DATA_TYPE: VxType;
+}
+
+macro_rules! matrix_elements {
+    ($($ty:ty => $data_type:ident),* $(,)?) => {
+        $(
+            unsafe impl MatrixElement for $ty {
+                const DATA_TYPE: VxType = VxType::$data_type;
+            }
+        )*
+    };
+}
+
+matrix_elements! {
+    u8 => UInt8,
+    i32 => Int32,
+    f32 => Float32,
+}
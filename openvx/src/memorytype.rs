@@ -0,0 +1,75 @@
+use core::fmt;
+use libopenvx_sys::*;
+
+/// An enumeration of memory import types.
+#[derive(Debug, Eq, PartialEq, Hash, Copy, Clone)]
+pub enum MemoryType {
+    /// For memory allocated through OpenVX, this is the import type.
+    None,
+    /// The default memory type to import from the Host.
+    Host,
+    /// A vendor-defined or otherwise unknown value.
+    Other { memory_type: vx_memory_type_e },
+}
+
+impl MemoryType {
+    pub const fn new(memory_type: vx_memory_type_e) -> Self {
+        #[allow(non_upper_case_globals)]
+        match memory_type {
+            vx_memory_type_e_VX_MEMORY_TYPE_NONE => MemoryType::None,
+            vx_memory_type_e_VX_MEMORY_TYPE_HOST => MemoryType::Host,
+            memory_type => MemoryType::Other { memory_type },
+        }
+    }
+
+    /// Converts this instance into a [`vx_memory_type_e`].
+    ///
+    /// [`vx_memory_type_e`]: ../libopenvx_sys/type.vx_memory_type_e.html
+    pub const fn to_raw(&self) -> vx_memory_type_e {
+        match self {
+            MemoryType::None => vx_memory_type_e_VX_MEMORY_TYPE_NONE,
+            MemoryType::Host => vx_memory_type_e_VX_MEMORY_TYPE_HOST,
+            MemoryType::Other { memory_type } => *memory_type,
+        }
+    }
+}
+
+impl From<vx_memory_type_e> for MemoryType {
+    fn from(value: vx_memory_type_e) -> Self {
+        MemoryType::new(value)
+    }
+}
+
+impl fmt::Display for MemoryType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MemoryType::None => write!(f, "VX_MEMORY_TYPE_NONE"),
+            MemoryType::Host => write!(f, "VX_MEMORY_TYPE_HOST"),
+            MemoryType::Other { memory_type } => {
+                write!(f, "VX_MEMORY_TYPE_OTHER ({})", memory_type)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roundtrip() {
+        for value in [MemoryType::None, MemoryType::Host] {
+            assert_eq!(MemoryType::from(value.to_raw()), value);
+        }
+    }
+
+    #[test]
+    fn other() {
+        assert_eq!(
+            MemoryType::new(0xFFFF),
+            MemoryType::Other {
+                memory_type: 0xFFFF
+            }
+        );
+    }
+}
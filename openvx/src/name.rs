@@ -1,8 +1,6 @@
 use crate::types::AsVxReference;
-use libopenvx_sys::{
-    vxQueryReference, vxSetReferenceName, vx_char, vx_enum, vx_reference,
-    vx_reference_attribute_e_VX_REFERENCE_NAME, vx_size,
-};
+use crate::{attr, QueryAttribute, Result};
+use libopenvx_sys::{vxSetReferenceName, vx_reference};
 use std::borrow::Borrow;
 
 pub trait SetName {
@@ -12,7 +10,8 @@ pub trait SetName {
 }
 
 pub trait GetName {
-    fn get_name(&self) -> String;
+    /// Returns the name of the object, or `None` if no name was set.
+    fn get_name(&self) -> Result<Option<String>>;
 }
 
 impl<P> SetName for P
@@ -32,19 +31,8 @@ impl<P> GetName for P
 where
     P: AsVxReference,
 {
-    fn get_name(&self) -> String {
-        let mut ref_name: *mut vx_char = std::ptr::null_mut();
-
-        unsafe {
-            vxQueryReference(
-                self.as_reference().into(),
-                vx_reference_attribute_e_VX_REFERENCE_NAME as vx_enum,
-                &mut ref_name as *mut _ as *mut std::ffi::c_void,
-                std::mem::size_of_val(&ref_name) as vx_size,
-            );
-
-            ref_name_from_cstr(ref_name)
-        }
+    fn get_name(&self) -> Result<Option<String>> {
+        self.as_reference().query::<attr::Name>()
     }
 }
 
@@ -62,13 +50,3 @@ where
 
     reference
 }
-
-unsafe fn ref_name_from_cstr(name: *const vx_char) -> String {
-    if name.is_null() {
-        String::from("INVALID_REF_NAME")
-    } else {
-        std::ffi::CStr::from_ptr(name)
-            .to_string_lossy()
-            .into_owned()
-    }
-}
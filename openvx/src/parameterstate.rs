@@ -0,0 +1,71 @@
+use core::fmt;
+use libopenvx_sys::*;
+
+/// The parameter state type.
+#[derive(Debug, Eq, PartialEq, Hash, Copy, Clone)]
+pub enum ParameterState {
+    /// Default. The parameter must be supplied. If not set, during verify, an error is returned.
+    Required,
+    /// The parameter may be unspecified. The kernel takes care not to deference optional parameters until it is certain they are valid.
+    Optional,
+    /// A vendor-defined or otherwise unknown value.
+    Other { state: vx_parameter_state_e },
+}
+
+impl ParameterState {
+    pub const fn new(state: vx_parameter_state_e) -> Self {
+        #[allow(non_upper_case_globals)]
+        match state {
+            vx_parameter_state_e_VX_PARAMETER_STATE_REQUIRED => ParameterState::Required,
+            vx_parameter_state_e_VX_PARAMETER_STATE_OPTIONAL => ParameterState::Optional,
+            state => ParameterState::Other { state },
+        }
+    }
+
+    /// Converts this instance into a [`vx_parameter_state_e`].
+    ///
+    /// [`vx_parameter_state_e`]: ../libopenvx_sys/type.vx_parameter_state_e.html
+    pub const fn to_raw(&self) -> vx_parameter_state_e {
+        match self {
+            ParameterState::Required => vx_parameter_state_e_VX_PARAMETER_STATE_REQUIRED,
+            ParameterState::Optional => vx_parameter_state_e_VX_PARAMETER_STATE_OPTIONAL,
+            ParameterState::Other { state } => *state,
+        }
+    }
+}
+
+impl From<vx_parameter_state_e> for ParameterState {
+    fn from(value: vx_parameter_state_e) -> Self {
+        ParameterState::new(value)
+    }
+}
+
+impl fmt::Display for ParameterState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParameterState::Required => write!(f, "VX_PARAMETER_STATE_REQUIRED"),
+            ParameterState::Optional => write!(f, "VX_PARAMETER_STATE_OPTIONAL"),
+            ParameterState::Other { state } => write!(f, "VX_PARAMETER_STATE_OTHER ({})", state),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roundtrip() {
+        for value in [ParameterState::Required, ParameterState::Optional] {
+            assert_eq!(ParameterState::from(value.to_raw()), value);
+        }
+    }
+
+    #[test]
+    fn other() {
+        assert_eq!(
+            ParameterState::new(0xFFFF),
+            ParameterState::Other { state: 0xFFFF }
+        );
+    }
+}
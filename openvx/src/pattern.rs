@@ -0,0 +1,79 @@
+use core::fmt;
+use libopenvx_sys::*;
+
+/// The pattern of a matrix created by `vxCreateMatrixFromPattern`, e.g. as mask of
+/// `vxNonLinearFilterNode`.
+#[derive(Debug, Eq, PartialEq, Hash, Copy, Clone)]
+pub enum Pattern {
+    /// A box pattern; all elements are set.
+    Box,
+    /// A cross pattern; only the middle row and column are set.
+    Cross,
+    /// A disk pattern; the elements within the ellipse inscribed in the matrix are set.
+    Disk,
+    /// An arbitrary pattern, e.g. of a matrix created by `vxCreateMatrix`.
+    Other,
+    /// A vendor-defined or otherwise unknown pattern.
+    Unknown { pattern: vx_pattern_e },
+}
+
+impl Pattern {
+    pub const fn new(pattern: vx_pattern_e) -> Self {
+        #[allow(non_upper_case_globals)]
+        match pattern {
+            vx_pattern_e_VX_PATTERN_BOX => Pattern::Box,
+            vx_pattern_e_VX_PATTERN_CROSS => Pattern::Cross,
+            vx_pattern_e_VX_PATTERN_DISK => Pattern::Disk,
+            vx_pattern_e_VX_PATTERN_OTHER => Pattern::Other,
+            pattern => Pattern::Unknown { pattern },
+        }
+    }
+
+    /// Converts this instance into a [`vx_pattern_e`].
+    ///
+    /// [`vx_pattern_e`]: ../libopenvx_sys/type.vx_pattern_e.html
+    pub const fn to_raw(&self) -> vx_pattern_e {
+        match self {
+            Pattern::Box => vx_pattern_e_VX_PATTERN_BOX,
+            Pattern::Cross => vx_pattern_e_VX_PATTERN_CROSS,
+            Pattern::Disk => vx_pattern_e_VX_PATTERN_DISK,
+            Pattern::Other => vx_pattern_e_VX_PATTERN_OTHER,
+            Pattern::Unknown { pattern } => *pattern,
+        }
+    }
+}
+
+impl From<vx_pattern_e> for Pattern {
+    fn from(value: vx_pattern_e) -> Self {
+        Pattern::new(value)
+    }
+}
+
+impl fmt::Display for Pattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Pattern::Box => write!(f, "VX_PATTERN_BOX"),
+            Pattern::Cross => write!(f, "VX_PATTERN_CROSS"),
+            Pattern::Disk => write!(f, "VX_PATTERN_DISK"),
+            Pattern::Other => write!(f, "VX_PATTERN_OTHER"),
+            Pattern::Unknown { pattern } => write!(f, "VX_PATTERN_UNKNOWN ({})", pattern),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roundtrip() {
+        for value in [Pattern::Box, Pattern::Cross, Pattern::Disk, Pattern::Other] {
+            assert_eq!(Pattern::from(value.to_raw()), value);
+        }
+    }
+
+    #[test]
+    fn unknown() {
+        assert_eq!(Pattern::new(0xFFFF), Pattern::Unknown { pattern: 0xFFFF });
+    }
+}
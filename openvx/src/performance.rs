@@ -36,3 +36,17 @@ impl From<vx_perf_t> for Performance {
         }
     }
 }
+
+#[cfg(feature = "tracing")]
+impl Performance {
+    /// Records the measurements as the `perf.*` fields of the given span.
+    ///
+    /// Fields not declared on the span are ignored.
+    pub(crate) fn record(&self, span: &tracing::Span) {
+        span.record("perf.tmp_ns", self.tmp.as_nanos() as u64);
+        span.record("perf.avg_ns", self.avg.as_nanos() as u64);
+        span.record("perf.min_ns", self.min.as_nanos() as u64);
+        span.record("perf.max_ns", self.max.as_nanos() as u64);
+        span.record("perf.num", self.num.as_nanos() as u64);
+    }
+}
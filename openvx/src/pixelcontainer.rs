@@ -0,0 +1,93 @@
+use core::fmt;
+use libopenvx_sys::*;
+
+/// The packing of the pixels of a raw image (`vx_image_raw_pixel_container_e`).
+#[derive(Debug, Eq, PartialEq, Hash, Copy, Clone)]
+pub enum PixelContainer {
+    /// Two bytes per pixel in memory.
+    Bit16,
+    /// One byte per pixel in memory.
+    Bit8,
+    /// Packed 12 bit mode; three bytes per two pixels in memory.
+    Packed12,
+    /// A vendor-defined or otherwise unknown value.
+    Other {
+        container: vx_image_raw_pixel_container_e,
+    },
+}
+
+impl PixelContainer {
+    pub const fn new(container: vx_image_raw_pixel_container_e) -> Self {
+        #[allow(non_upper_case_globals)]
+        match container {
+            vx_image_raw_pixel_container_e_VX_IMAGE_RAW_16_BIT => PixelContainer::Bit16,
+            vx_image_raw_pixel_container_e_VX_IMAGE_RAW_8_BIT => PixelContainer::Bit8,
+            vx_image_raw_pixel_container_e_VX_IMAGE_RAW_P12_BIT => PixelContainer::Packed12,
+            container => PixelContainer::Other { container },
+        }
+    }
+
+    /// Converts this instance into a [`vx_image_raw_pixel_container_e`].
+    ///
+    /// [`vx_image_raw_pixel_container_e`]: ../libopenvx_sys/type.vx_image_raw_pixel_container_e.html
+    pub const fn to_raw(&self) -> vx_image_raw_pixel_container_e {
+        match self {
+            PixelContainer::Bit16 => vx_image_raw_pixel_container_e_VX_IMAGE_RAW_16_BIT,
+            PixelContainer::Bit8 => vx_image_raw_pixel_container_e_VX_IMAGE_RAW_8_BIT,
+            PixelContainer::Packed12 => vx_image_raw_pixel_container_e_VX_IMAGE_RAW_P12_BIT,
+            PixelContainer::Other { container } => *container,
+        }
+    }
+
+    /// Returns the number of bytes per pixel, or `None` for packed containers.
+    pub const fn bytes_per_pixel(&self) -> Option<usize> {
+        match self {
+            PixelContainer::Bit16 => Some(2),
+            PixelContainer::Bit8 => Some(1),
+            PixelContainer::Packed12 | PixelContainer::Other { .. } => None,
+        }
+    }
+}
+
+impl From<vx_image_raw_pixel_container_e> for PixelContainer {
+    fn from(value: vx_image_raw_pixel_container_e) -> Self {
+        PixelContainer::new(value)
+    }
+}
+
+impl fmt::Display for PixelContainer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PixelContainer::Bit16 => write!(f, "VX_IMAGE_RAW_16_BIT"),
+            PixelContainer::Bit8 => write!(f, "VX_IMAGE_RAW_8_BIT"),
+            PixelContainer::Packed12 => write!(f, "VX_IMAGE_RAW_P12_BIT"),
+            PixelContainer::Other { container } => {
+                write!(f, "VX_IMAGE_RAW_OTHER ({})", container)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roundtrip() {
+        for value in [
+            PixelContainer::Bit16,
+            PixelContainer::Bit8,
+            PixelContainer::Packed12,
+        ] {
+            assert_eq!(PixelContainer::from(value.to_raw()), value);
+        }
+    }
+
+    #[test]
+    fn other() {
+        assert_eq!(
+            PixelContainer::new(0xFFFF),
+            PixelContainer::Other { container: 0xFFFF }
+        );
+    }
+}
@@ -0,0 +1,107 @@
+use crate::ImageType;
+
+/// A type that can represent the elements of an image plane in host memory.
+///
+/// The element type of a plane follows from the [`ImageType`] of the image:
+///
+/// | Type      | Formats                                                              |
+/// |-----------|----------------------------------------------------------------------|
+/// | `u8`      | `U8`; every plane of `IYUV` and `YUV4`; the luma plane of `NV12`/`NV21` |
+/// | `[u8; 2]` | `UYVY`, `YUYV`; the chroma plane of `NV12`/`NV21`                    |
+/// | `[u8; 3]` | `RGB`                                                                |
+/// | `[u8; 4]` | `RGBX`                                                               |
+/// | `u16`     | `U16`                                                                |
+/// | `i16`     | `S16`                                                                |
+/// | `u32`     | `U32`                                                                |
+/// | `i32`     | `S32`                                                                |
+///
+/// # Safety
+///
+/// Implementors must be plain old data: every bit pattern of `size_of::<Self>()` bytes must be
+/// a valid value, and the size must match the size of an element of all compatible planes.
+///
+/// [`ImageType`]: enum.ImageType.html
+pub unsafe trait PixelType: Copy + 'static {
+    /// Determines whether this type represents the elements of plane `plane` of `format`.
+    fn is_compatible(format: ImageType, plane: u32) -> bool;
+}
+
+unsafe impl PixelType for u8 {
+    fn is_compatible(format: ImageType, plane: u32) -> bool {
+        match format {
+            ImageType::U8 => plane == 0,
+            ImageType::IYUV | ImageType::YUV4 => plane < 3,
+            ImageType::NV12 | ImageType::NV21 => plane == 0,
+            _ => false,
+        }
+    }
+}
+
+unsafe impl PixelType for [u8; 2] {
+    fn is_compatible(format: ImageType, plane: u32) -> bool {
+        match format {
+            ImageType::UYVY | ImageType::YUYV => plane == 0,
+            ImageType::NV12 | ImageType::NV21 => plane == 1,
+            _ => false,
+        }
+    }
+}
+
+unsafe impl PixelType for [u8; 3] {
+    fn is_compatible(format: ImageType, plane: u32) -> bool {
+        format == ImageType::RGB && plane == 0
+    }
+}
+
+unsafe impl PixelType for [u8; 4] {
+    fn is_compatible(format: ImageType, plane: u32) -> bool {
+        format == ImageType::RGBX && plane == 0
+    }
+}
+
+unsafe impl PixelType for u16 {
+    fn is_compatible(format: ImageType, plane: u32) -> bool {
+        format == ImageType::U16 && plane == 0
+    }
+}
+
+unsafe impl PixelType for i16 {
+    fn is_compatible(format: ImageType, plane: u32) -> bool {
+        format == ImageType::S16 && plane == 0
+    }
+}
+
+unsafe impl PixelType for u32 {
+    fn is_compatible(format: ImageType, plane: u32) -> bool {
+        format == ImageType::U32 && plane == 0
+    }
+}
+
+unsafe impl PixelType for i32 {
+    fn is_compatible(format: ImageType, plane: u32) -> bool {
+        format == ImageType::S32 && plane == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn planar_formats() {
+        assert!(u8::is_compatible(ImageType::IYUV, 2));
+        assert!(!u8::is_compatible(ImageType::IYUV, 3));
+        assert!(u8::is_compatible(ImageType::NV12, 0));
+        assert!(!u8::is_compatible(ImageType::NV12, 1));
+        assert!(<[u8; 2]>::is_compatible(ImageType::NV21, 1));
+    }
+
+    #[test]
+    fn single_plane_formats() {
+        assert!(<[u8; 3]>::is_compatible(ImageType::RGB, 0));
+        assert!(!<[u8; 3]>::is_compatible(ImageType::RGBX, 0));
+        assert!(i16::is_compatible(ImageType::S16, 0));
+        assert!(!u16::is_compatible(ImageType::S16, 0));
+        assert!(!u8::is_compatible(ImageType::U1, 0));
+    }
+}
@@ -0,0 +1,226 @@
+use libopenvx_sys::{vx_bool, vx_bool_e_vx_false_e, vx_bool_e_vx_true_e, vx_pixel_value_t};
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+/// Describes the value of a pixel for any image format.
+///
+/// This wraps the [`vx_pixel_value_t`] union; which of the members is meaningful
+/// depends on the format of the image the value is used with.
+///
+/// # Examples
+///
+/// ```
+/// use openvx::PixelValue;
+///
+/// let value = PixelValue::from_rgb([255, 128, 0]);
+/// assert_eq!(value.as_rgb(), [255, 128, 0]);
+/// assert_eq!(value.as_u8(), 255);
+/// ```
+///
+/// [`vx_pixel_value_t`]: ../libopenvx_sys/type.vx_pixel_value_t.html
+#[derive(Copy, Clone)]
+pub struct PixelValue {
+    raw: vx_pixel_value_t,
+}
+
+impl PixelValue {
+    /// Creates a pixel value with all bytes set to zero.
+    pub const fn zero() -> Self {
+        Self::from_bytes([0; 16])
+    }
+
+    /// Creates a pixel value from its raw byte representation.
+    pub const fn from_bytes(bytes: [u8; 16]) -> Self {
+        Self {
+            raw: vx_pixel_value_t { reserved: bytes },
+        }
+    }
+
+    /// Creates a pixel value for `VX_DF_IMAGE_RGB` images.
+    pub fn from_rgb(value: [u8; 3]) -> Self {
+        let mut pixel = Self::zero();
+        pixel.raw.RGB = value;
+        pixel
+    }
+
+    /// Creates a pixel value for `VX_DF_IMAGE_RGBX` images.
+    pub fn from_rgbx(value: [u8; 4]) -> Self {
+        let mut pixel = Self::zero();
+        pixel.raw.RGBX = value;
+        pixel
+    }
+
+    /// Creates a pixel value for `VX_DF_IMAGE_YUV4`, `VX_DF_IMAGE_IYUV`, `VX_DF_IMAGE_NV12`,
+    /// `VX_DF_IMAGE_NV21`, `VX_DF_IMAGE_UYVY` and `VX_DF_IMAGE_YUYV` images.
+    pub fn from_yuv(value: [u8; 3]) -> Self {
+        let mut pixel = Self::zero();
+        pixel.raw.YUV = value;
+        pixel
+    }
+
+    /// Creates a pixel value for `VX_DF_IMAGE_U1` images.
+    pub fn from_u1(value: bool) -> Self {
+        let mut pixel = Self::zero();
+        pixel.raw.U1 = if value {
+            vx_bool_e_vx_true_e
+        } else {
+            vx_bool_e_vx_false_e
+        } as vx_bool;
+        pixel
+    }
+
+    /// Creates a pixel value for `VX_DF_IMAGE_U8` images.
+    pub fn from_u8(value: u8) -> Self {
+        let mut pixel = Self::zero();
+        pixel.raw.U8 = value;
+        pixel
+    }
+
+    /// Creates a pixel value for `VX_DF_IMAGE_U16` images.
+    pub fn from_u16(value: u16) -> Self {
+        let mut pixel = Self::zero();
+        pixel.raw.U16 = value;
+        pixel
+    }
+
+    /// Creates a pixel value for `VX_DF_IMAGE_S16` images.
+    pub fn from_s16(value: i16) -> Self {
+        let mut pixel = Self::zero();
+        pixel.raw.S16 = value;
+        pixel
+    }
+
+    /// Creates a pixel value for `VX_DF_IMAGE_U32` images.
+    pub fn from_u32(value: u32) -> Self {
+        let mut pixel = Self::zero();
+        pixel.raw.U32 = value;
+        pixel
+    }
+
+    /// Creates a pixel value for `VX_DF_IMAGE_S32` images.
+    pub fn from_s32(value: i32) -> Self {
+        let mut pixel = Self::zero();
+        pixel.raw.S32 = value;
+        pixel
+    }
+
+    /// Returns the raw byte representation of the value.
+    pub fn as_bytes(&self) -> [u8; 16] {
+        // All members are plain integers, so every bit pattern is valid.
+        unsafe { self.raw.reserved }
+    }
+
+    /// Interprets the value as an `RGB` pixel.
+    pub fn as_rgb(&self) -> [u8; 3] {
+        unsafe { self.raw.RGB }
+    }
+
+    /// Interprets the value as an `RGBX` pixel.
+    pub fn as_rgbx(&self) -> [u8; 4] {
+        unsafe { self.raw.RGBX }
+    }
+
+    /// Interprets the value as a `YUV` pixel.
+    pub fn as_yuv(&self) -> [u8; 3] {
+        unsafe { self.raw.YUV }
+    }
+
+    /// Interprets the value as a `U1` pixel.
+    pub fn as_u1(&self) -> bool {
+        unsafe { self.raw.U1 != vx_bool_e_vx_false_e as vx_bool }
+    }
+
+    /// Interprets the value as a `U8` pixel.
+    pub fn as_u8(&self) -> u8 {
+        unsafe { self.raw.U8 }
+    }
+
+    /// Interprets the value as a `U16` pixel.
+    pub fn as_u16(&self) -> u16 {
+        unsafe { self.raw.U16 }
+    }
+
+    /// Interprets the value as an `S16` pixel.
+    pub fn as_s16(&self) -> i16 {
+        unsafe { self.raw.S16 }
+    }
+
+    /// Interprets the value as a `U32` pixel.
+    pub fn as_u32(&self) -> u32 {
+        unsafe { self.raw.U32 }
+    }
+
+    /// Interprets the value as an `S32` pixel.
+    pub fn as_s32(&self) -> i32 {
+        unsafe { self.raw.S32 }
+    }
+
+    /// Converts this instance into a [`vx_pixel_value_t`].
+    ///
+    /// [`vx_pixel_value_t`]: ../libopenvx_sys/type.vx_pixel_value_t.html
+    pub const fn to_raw(&self) -> vx_pixel_value_t {
+        self.raw
+    }
+}
+
+impl Default for PixelValue {
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl From<vx_pixel_value_t> for PixelValue {
+    fn from(raw: vx_pixel_value_t) -> Self {
+        Self { raw }
+    }
+}
+
+impl fmt::Debug for PixelValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("PixelValue").field(&self.as_bytes()).finish()
+    }
+}
+
+impl PartialEq for PixelValue {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_bytes() == other.as_bytes()
+    }
+}
+
+impl Eq for PixelValue {}
+
+impl Hash for PixelValue {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_bytes().hash(state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn size() {
+        assert_eq!(std::mem::size_of::<PixelValue>(), 16);
+    }
+
+    #[test]
+    fn members_share_storage() {
+        let value = PixelValue::from_u16(0x0102);
+        assert_eq!(value.as_u16(), 0x0102);
+        assert_eq!(value.as_bytes()[2..], [0; 14]);
+        assert_eq!(PixelValue::from_s16(-1).as_u16(), 0xFFFF);
+    }
+
+    #[test]
+    fn u1() {
+        assert!(PixelValue::from_u1(true).as_u1());
+        assert!(!PixelValue::from_u1(false).as_u1());
+    }
+
+    #[test]
+    fn equality() {
+        assert_eq!(PixelValue::from_u8(7), PixelValue::from_u32(7));
+        assert_ne!(PixelValue::from_u8(7), PixelValue::default());
+    }
+}
@@ -0,0 +1,85 @@
+use core::fmt;
+use libopenvx_sys::*;
+
+/// The part of a raw image buffer to access (`vx_image_raw_buffer_access_e`).
+#[derive(Debug, Eq, PartialEq, Hash, Copy, Clone)]
+pub enum RawBuffer {
+    /// The full allocated buffer, i.e. the pixels and the meta data lines.
+    Alloc,
+    /// The pixels only.
+    Pixel,
+    /// The meta data lines read out before the pixels.
+    MetaBefore,
+    /// The meta data lines read out after the pixels.
+    MetaAfter,
+    /// A vendor-defined or otherwise unknown value.
+    Other {
+        buffer: vx_image_raw_buffer_access_e,
+    },
+}
+
+impl RawBuffer {
+    pub const fn new(buffer: vx_image_raw_buffer_access_e) -> Self {
+        #[allow(non_upper_case_globals)]
+        match buffer {
+            vx_image_raw_buffer_access_e_VX_IMAGE_RAW_ALLOC_BUFFER => RawBuffer::Alloc,
+            vx_image_raw_buffer_access_e_VX_IMAGE_RAW_PIXEL_BUFFER => RawBuffer::Pixel,
+            vx_image_raw_buffer_access_e_VX_IMAGE_RAW_META_BEFORE_BUFFER => RawBuffer::MetaBefore,
+            vx_image_raw_buffer_access_e_VX_IMAGE_RAW_META_AFTER_BUFFER => RawBuffer::MetaAfter,
+            buffer => RawBuffer::Other { buffer },
+        }
+    }
+
+    /// Converts this instance into a [`vx_image_raw_buffer_access_e`].
+    ///
+    /// [`vx_image_raw_buffer_access_e`]: ../libopenvx_sys/type.vx_image_raw_buffer_access_e.html
+    pub const fn to_raw(&self) -> vx_image_raw_buffer_access_e {
+        match self {
+            RawBuffer::Alloc => vx_image_raw_buffer_access_e_VX_IMAGE_RAW_ALLOC_BUFFER,
+            RawBuffer::Pixel => vx_image_raw_buffer_access_e_VX_IMAGE_RAW_PIXEL_BUFFER,
+            RawBuffer::MetaBefore => vx_image_raw_buffer_access_e_VX_IMAGE_RAW_META_BEFORE_BUFFER,
+            RawBuffer::MetaAfter => vx_image_raw_buffer_access_e_VX_IMAGE_RAW_META_AFTER_BUFFER,
+            RawBuffer::Other { buffer } => *buffer,
+        }
+    }
+}
+
+impl From<vx_image_raw_buffer_access_e> for RawBuffer {
+    fn from(value: vx_image_raw_buffer_access_e) -> Self {
+        RawBuffer::new(value)
+    }
+}
+
+impl fmt::Display for RawBuffer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RawBuffer::Alloc => write!(f, "VX_IMAGE_RAW_ALLOC_BUFFER"),
+            RawBuffer::Pixel => write!(f, "VX_IMAGE_RAW_PIXEL_BUFFER"),
+            RawBuffer::MetaBefore => write!(f, "VX_IMAGE_RAW_META_BEFORE_BUFFER"),
+            RawBuffer::MetaAfter => write!(f, "VX_IMAGE_RAW_META_AFTER_BUFFER"),
+            RawBuffer::Other { buffer } => write!(f, "VX_IMAGE_RAW_BUFFER_OTHER ({})", buffer),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roundtrip() {
+        for value in [
+            RawBuffer::Alloc,
+            RawBuffer::Pixel,
+            RawBuffer::MetaBefore,
+            RawBuffer::MetaAfter,
+        ] {
+            assert_eq!(RawBuffer::from(value.to_raw()), value);
+        }
+    }
+
+    #[test]
+    fn other() {
+        assert_eq!(RawBuffer::new(0xFFFF), RawBuffer::Other { buffer: 0xFFFF });
+    }
+}
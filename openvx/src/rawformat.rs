@@ -0,0 +1,121 @@
+use crate::{Channel, PixelContainer};
+use libopenvx_sys::{vx_image_raw_format_t, vx_uint32};
+
+/// The packing and bit depth of an exposure of a raw image (`vx_image_raw_format_t`).
+#[derive(Debug, Eq, PartialEq, Hash, Copy, Clone)]
+pub struct RawFormat {
+    /// The packing of the pixels in memory.
+    pub container: PixelContainer,
+    /// The most significant bit of a pixel within its container.
+    pub msb: vx_uint32,
+}
+
+impl RawFormat {
+    /// Creates a format from its container and the most significant bit of a pixel.
+    pub const fn new(container: PixelContainer, msb: vx_uint32) -> Self {
+        Self { container, msb }
+    }
+
+    /// Creates a format for pixels of `bits` bits stored in the least significant bits of
+    /// `container`, e.g. 10-bit sensor data in [`PixelContainer::Bit16`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use openvx::{PixelContainer, RawFormat};
+    ///
+    /// let format = RawFormat::with_bit_depth(PixelContainer::Bit16, 10);
+    /// assert_eq!(format.msb, 9);
+    /// assert_eq!(format.bit_depth(), 10);
+    /// ```
+    ///
+    /// [`PixelContainer::Bit16`]: enum.PixelContainer.html#variant.Bit16
+    pub const fn with_bit_depth(container: PixelContainer, bits: vx_uint32) -> Self {
+        Self::new(container, bits.saturating_sub(1))
+    }
+
+    /// Returns the number of significant bits of a pixel.
+    pub const fn bit_depth(&self) -> vx_uint32 {
+        self.msb + 1
+    }
+
+    /// Converts this instance into a [`vx_image_raw_format_t`].
+    ///
+    /// [`vx_image_raw_format_t`]: ../libopenvx_sys/type.vx_image_raw_format_t.html
+    pub const fn to_raw(&self) -> vx_image_raw_format_t {
+        vx_image_raw_format_t {
+            pixel_container: self.container.to_raw(),
+            msb: self.msb,
+        }
+    }
+}
+
+impl From<vx_image_raw_format_t> for RawFormat {
+    fn from(value: vx_image_raw_format_t) -> Self {
+        Self::new(PixelContainer::new(value.pixel_container), value.msb)
+    }
+}
+
+/// The layout of the color filter array of a Bayer sensor, named after its top-left 2×2 tile.
+///
+/// The `vx_khr_raw_image` extension does not describe the color filter array; the pattern is
+/// kept by [`VxRawImage`](struct.VxRawImage.html) for the application only.
+#[derive(Debug, Eq, PartialEq, Hash, Copy, Clone)]
+pub enum CfaPattern {
+    /// Red, green in the first row; green, blue in the second.
+    Rggb,
+    /// Blue, green in the first row; green, red in the second.
+    Bggr,
+    /// Green, red in the first row; blue, green in the second.
+    Grbg,
+    /// Green, blue in the first row; red, green in the second.
+    Gbrg,
+    /// No color filter array.
+    Monochrome,
+}
+
+impl CfaPattern {
+    /// Returns the color channel of the pixel at column `x` of row `y`, or [`Channel::Y`]
+    /// for monochrome sensors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use openvx::{CfaPattern, Channel};
+    ///
+    /// assert_eq!(CfaPattern::Rggb.channel_at(0, 0), Channel::R);
+    /// assert_eq!(CfaPattern::Rggb.channel_at(3, 1), Channel::B);
+    /// assert_eq!(CfaPattern::Grbg.channel_at(1, 0), Channel::R);
+    /// ```
+    ///
+    /// [`Channel::Y`]: enum.Channel.html#variant.Y
+    pub const fn channel_at(&self, x: u32, y: u32) -> Channel {
+        let tile = match self {
+            CfaPattern::Rggb => [[Channel::R, Channel::G], [Channel::G, Channel::B]],
+            CfaPattern::Bggr => [[Channel::B, Channel::G], [Channel::G, Channel::R]],
+            CfaPattern::Grbg => [[Channel::G, Channel::R], [Channel::B, Channel::G]],
+            CfaPattern::Gbrg => [[Channel::G, Channel::B], [Channel::R, Channel::G]],
+            CfaPattern::Monochrome => return Channel::Y,
+        };
+        tile[(y % 2) as usize][(x % 2) as usize]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roundtrip() {
+        let format = RawFormat::new(PixelContainer::Packed12, 11);
+        assert_eq!(RawFormat::from(format.to_raw()), format);
+    }
+
+    #[test]
+    fn cfa_tiles() {
+        assert_eq!(CfaPattern::Bggr.channel_at(1, 1), Channel::R);
+        assert_eq!(CfaPattern::Gbrg.channel_at(2, 0), Channel::G);
+        assert_eq!(CfaPattern::Gbrg.channel_at(3, 0), Channel::B);
+        assert_eq!(CfaPattern::Monochrome.channel_at(1, 0), Channel::Y);
+    }
+}
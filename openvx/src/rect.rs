@@ -0,0 +1,166 @@
+use libopenvx_sys::{vx_rectangle_t, vx_uint32};
+
+/// A rectangle in image coordinates.
+///
+/// The start coordinates are inclusive, the end coordinates are exclusive. The layout matches
+/// [`vx_rectangle_t`], so the type can be used as item of a [`VxArray`](struct.VxArray.html).
+///
+/// [`vx_rectangle_t`]: ../libopenvx_sys/type.vx_rectangle_t.html
+#[repr(C)]
+#[derive(Debug, Default, Eq, PartialEq, Hash, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Rect {
+    /// The start x coordinate.
+    pub start_x: vx_uint32,
+    /// The start y coordinate.
+    pub start_y: vx_uint32,
+    /// The end x coordinate.
+    pub end_x: vx_uint32,
+    /// The end y coordinate.
+    pub end_y: vx_uint32,
+}
+
+static_assertions::assert_eq_size!(Rect, vx_rectangle_t);
+
+impl Rect {
+    /// Creates a rectangle from its start (inclusive) and end (exclusive) coordinates.
+    pub const fn new(start_x: u32, start_y: u32, end_x: u32, end_y: u32) -> Self {
+        Self {
+            start_x,
+            start_y,
+            end_x,
+            end_y,
+        }
+    }
+
+    /// Creates a rectangle from its top-left corner and size.
+    ///
+    /// The end coordinates saturate at `u32::MAX`, so the rectangle is clipped rather than
+    /// inverted if it does not fit into the coordinate range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use openvx::Rect;
+    ///
+    /// let rect = Rect::from_size(10, 20, 64, 48);
+    /// assert_eq!(rect, Rect::new(10, 20, 74, 68));
+    /// assert_eq!((rect.width(), rect.height()), (64, 48));
+    /// ```
+    pub const fn from_size(x: u32, y: u32, width: u32, height: u32) -> Self {
+        Self::new(x, y, x.saturating_add(width), y.saturating_add(height))
+    }
+
+    /// Returns the width of the rectangle.
+    pub const fn width(&self) -> u32 {
+        self.end_x.saturating_sub(self.start_x)
+    }
+
+    /// Returns the height of the rectangle.
+    pub const fn height(&self) -> u32 {
+        self.end_y.saturating_sub(self.start_y)
+    }
+
+    /// Determines whether the rectangle has no area.
+    pub const fn is_empty(&self) -> bool {
+        self.width() == 0 || self.height() == 0
+    }
+
+    /// Returns the intersection of both rectangles, which is empty if they do not overlap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use openvx::Rect;
+    ///
+    /// let image = Rect::from_size(0, 0, 640, 480);
+    /// let valid = Rect::new(2, 2, 638, 478);
+    /// assert_eq!(Rect::from_size(600, 0, 100, 100).intersection(&valid), Rect::new(600, 2, 638, 100));
+    /// assert!(Rect::from_size(700, 0, 10, 10).intersection(&image).is_empty());
+    /// ```
+    pub fn intersection(&self, other: &Rect) -> Rect {
+        let start_x = self.start_x.max(other.start_x);
+        let start_y = self.start_y.max(other.start_y);
+        Self::new(
+            start_x,
+            start_y,
+            self.end_x.min(other.end_x).max(start_x),
+            self.end_y.min(other.end_y).max(start_y),
+        )
+    }
+
+    /// Returns the rectangle moved by `x` columns and `y` rows, e.g. to convert coordinates
+    /// of a region of interest into coordinates of its parent image, or `None` if a
+    /// coordinate overflows.
+    pub const fn offset(&self, x: u32, y: u32) -> Option<Rect> {
+        match (
+            self.start_x.checked_add(x),
+            self.start_y.checked_add(y),
+            self.end_x.checked_add(x),
+            self.end_y.checked_add(y),
+        ) {
+            (Some(start_x), Some(start_y), Some(end_x), Some(end_y)) => {
+                Some(Self::new(start_x, start_y, end_x, end_y))
+            }
+            _ => None,
+        }
+    }
+
+    /// Converts this instance into a [`vx_rectangle_t`].
+    ///
+    /// [`vx_rectangle_t`]: ../libopenvx_sys/type.vx_rectangle_t.html
+    pub const fn to_raw(&self) -> vx_rectangle_t {
+        vx_rectangle_t {
+            start_x: self.start_x,
+            start_y: self.start_y,
+            end_x: self.end_x,
+            end_y: self.end_y,
+        }
+    }
+}
+
+impl From<vx_rectangle_t> for Rect {
+    fn from(value: vx_rectangle_t) -> Self {
+        Self::new(value.start_x, value.start_y, value.end_x, value.end_y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roundtrip() {
+        let rect = Rect::new(1, 2, 3, 4);
+        assert_eq!(Rect::from(rect.to_raw()), rect);
+    }
+
+    #[test]
+    fn empty() {
+        assert!(Rect::new(5, 5, 5, 10).is_empty());
+        assert!(Rect::new(5, 5, 2, 10).is_empty());
+        assert!(!Rect::from_size(0, 0, 1, 1).is_empty());
+    }
+
+    #[test]
+    fn from_size_saturates() {
+        let rect = Rect::from_size(u32::MAX - 10, 5, 64, 48);
+        assert_eq!(rect, Rect::new(u32::MAX - 10, 5, u32::MAX, 53));
+        assert_eq!(rect.width(), 10);
+    }
+
+    #[test]
+    fn intersection_and_offset() {
+        let rect = Rect::new(10, 10, 20, 20);
+        assert_eq!(
+            rect.intersection(&Rect::new(15, 0, 30, 12)),
+            Rect::new(15, 10, 20, 12)
+        );
+        assert_eq!(
+            rect.intersection(&Rect::new(30, 30, 40, 40)),
+            Rect::new(30, 30, 30, 30)
+        );
+        assert_eq!(rect.offset(5, 1), Some(Rect::new(15, 11, 25, 21)));
+        assert_eq!(rect.offset(u32::MAX - 15, 0), None);
+    }
+}
@@ -1,27 +1,27 @@
-use crate::{VxError, VxStatus};
+use crate::{Error, VxStatus};
 
 /// [`Result<T>`][`Result`] is the type used for returning and propagating
 /// errors. It is an enum with the variants, [`Ok(T)`], representing
-/// success and containing a value, and [`Err(VxError)`], representing error
+/// success and containing a value, and [`Err(Error)`], representing error
 /// and containing an error value.
 ///
 /// ```
 /// # #[allow(dead_code)]
 /// enum Result<T> {
 ///    Ok(T),
-///    Err(openvx::VxError),
+///    Err(openvx::Error),
 /// }
 /// ```
 ///
 /// [`Result`]: type.Result.html
 /// [`Ok(T)`]: type.Result.html#variant.Ok
-/// [`Err(VxError)`]: type.Result.html#variant.Err
-pub type Result<T> = std::result::Result<T, VxError>;
+/// [`Err(Error)`]: type.Result.html#variant.Err
+pub type Result<T> = std::result::Result<T, Error>;
 
 impl<T> Into<VxStatus> for Result<T> {
     fn into(self) -> VxStatus {
         if let Err(error) = self {
-            return VxStatus::Error(error);
+            return VxStatus::Error(error.code());
         }
         VxStatus::Success
     }
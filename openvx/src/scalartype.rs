@@ -0,0 +1,238 @@
+use crate::{
+    BorderPolicy, Channel, ChannelRange, ColorSpace, Coordinates2d, Coordinates2df, Coordinates3d,
+    Direction, HogParams, ImageType, Keypoint, Line2d, Pattern, Rect, VxType,
+};
+use libopenvx_sys::{
+    vx_bool, vx_bool_e_vx_false_e, vx_bool_e_vx_true_e, vx_border_policy_e, vx_channel_e,
+    vx_channel_range_e, vx_char, vx_color_space_e, vx_coordinates2d_t, vx_coordinates2df_t,
+    vx_coordinates3d_t, vx_df_image, vx_df_image_e, vx_direction_e, vx_enum, vx_hog_t,
+    vx_hough_lines_p_t, vx_keypoint_t, vx_line2d_t, vx_pattern_e, vx_rectangle_t, vx_size,
+    vx_tensor_matrix_multiply_params_t,
+};
+
+/// A type that can be held by a [`VxScalar`](struct.VxScalar.html).
+///
+/// | Type                                            | Data type                                |
+/// |-------------------------------------------------|------------------------------------------|
+/// | `i8`, `u8`, …, `i64`, `u64`                     | `VX_TYPE_INT8`, …, `VX_TYPE_UINT64`      |
+/// | `f32`, `f64`                                    | `VX_TYPE_FLOAT32`, `VX_TYPE_FLOAT64`     |
+/// | `bool`                                          | `VX_TYPE_BOOL`                           |
+/// | `usize`                                         | `VX_TYPE_SIZE`                           |
+/// | [`VxChar`](struct.VxChar.html)                  | `VX_TYPE_CHAR`                           |
+/// | [`VxEnum`](struct.VxEnum.html)                  | `VX_TYPE_ENUM`                           |
+/// | [`Channel`](enum.Channel.html), …               | `VX_TYPE_ENUM`                           |
+/// | [`ImageType`](enum.ImageType.html)              | `VX_TYPE_DF_IMAGE`                       |
+/// | [`Rect`](struct.Rect.html)                      | `VX_TYPE_RECTANGLE`                      |
+/// | [`Keypoint`](struct.Keypoint.html)              | `VX_TYPE_KEYPOINT`                       |
+/// | [`Coordinates2d`](struct.Coordinates2d.html), … | `VX_TYPE_COORDINATES2D`, …               |
+/// | [`Line2d`](struct.Line2d.html)                  | `VX_TYPE_LINE_2D`                        |
+/// | [`HogParams`](struct.HogParams.html)            | `VX_TYPE_HOG_PARAMS`                     |
+/// | `vx_keypoint_t`, `vx_line2d_t`, …               | `VX_TYPE_KEYPOINT`, `VX_TYPE_LINE_2D`, … |
+///
+/// Besides [`Channel`](enum.Channel.html), the enumerations
+/// [`ChannelRange`](enum.ChannelRange.html), [`ColorSpace`](enum.ColorSpace.html),
+/// [`Direction`](enum.Direction.html), [`Pattern`](enum.Pattern.html) and
+/// [`BorderPolicy`](enum.BorderPolicy.html) are held as `VX_TYPE_ENUM`; any other enumeration
+/// value is held as a [`VxEnum`](struct.VxEnum.html).
+///
+/// User structs implement this trait with `Raw = Self` and their data type, e.g. an offset
+/// from `VX_TYPE_USER_STRUCT_START`. Types registered at runtime by calling
+/// [`vxRegisterUserStruct`] are created with
+/// [`VxScalar::with_type`](struct.VxScalar.html#method.with_type) instead.
+///
+/// # Safety
+///
+/// `Raw` must be plain old data with the layout of a value of data type `TYPE`.
+///
+/// [`vxRegisterUserStruct`]: ../libopenvx_sys/fn.vxRegisterUserStruct.html
+pub unsafe trait ScalarType: Copy + 'static {
+    /// The data type of scalars holding values of this type.
+    const TYPE: VxType;
+
+    /// The representation of a value in the scalar.
+    type Raw: Copy + 'static;
+
+    /// Converts the value into its representation in the scalar.
+    fn to_raw(self) -> Self::Raw;
+
+    /// Converts a representation in the scalar into a value.
+    fn from_raw(raw: Self::Raw) -> Self;
+}
+
+macro_rules! scalar_types {
+    ($($ty:ty => $data_type:ident),* $(,)?) => {
+        $(
+            unsafe impl ScalarType for $ty {
+                const TYPE: VxType = VxType::$data_type;
+                type Raw = Self;
+
+                fn to_raw(self) -> Self::Raw {
+                    self
+                }
+
+                fn from_raw(raw: Self::Raw) -> Self {
+                    raw
+                }
+            }
+        )*
+    };
+}
+
+scalar_types! {
+    i8 => Int8,
+    u8 => UInt8,
+    i16 => Int16,
+    u16 => UInt16,
+    i32 => Int32,
+    u32 => UInt32,
+    i64 => Int64,
+    u64 => UInt64,
+    f32 => Float32,
+    f64 => Float64,
+    vx_size => Size,
+    vx_rectangle_t => Rectangle,
+    vx_keypoint_t => Keypoint,
+    vx_coordinates2d_t => Coordinates2D,
+    vx_coordinates3d_t => Coordinates3D,
+    vx_coordinates2df_t => Coordinates2DF,
+    vx_hog_t => HogParams,
+    vx_hough_lines_p_t => HoughLinesParams,
+    vx_line2d_t => Line2D,
+    vx_tensor_matrix_multiply_params_t => TensorMatrixMultiplyParams,
+    Rect => Rectangle,
+    Keypoint => Keypoint,
+    Coordinates2d => Coordinates2D,
+    Coordinates3d => Coordinates3D,
+    Coordinates2df => Coordinates2DF,
+    Line2d => Line2D,
+    HogParams => HogParams,
+}
+
+unsafe impl ScalarType for bool {
+    const TYPE: VxType = VxType::Bool;
+    type Raw = vx_bool;
+
+    fn to_raw(self) -> Self::Raw {
+        if self {
+            vx_bool_e_vx_true_e as vx_bool
+        } else {
+            vx_bool_e_vx_false_e as vx_bool
+        }
+    }
+
+    fn from_raw(raw: Self::Raw) -> Self {
+        raw != vx_bool_e_vx_false_e as vx_bool
+    }
+}
+
+/// A character held by a scalar of data type `VX_TYPE_CHAR`.
+///
+/// `vx_char` is the same type as `i8` on most platforms, which is held as `VX_TYPE_INT8`.
+#[repr(transparent)]
+#[derive(Debug, Eq, PartialEq, Hash, Copy, Clone, Default)]
+pub struct VxChar(pub vx_char);
+
+/// An enumeration value held by a scalar of data type `VX_TYPE_ENUM`.
+///
+/// This holds values of enumerations the crate has no type for, e.g. a
+/// `vx_interpolation_type_e` or a vendor extension's enumeration.
+#[repr(transparent)]
+#[derive(Debug, Eq, PartialEq, Hash, Copy, Clone, Default)]
+pub struct VxEnum(pub vx_enum);
+
+unsafe impl ScalarType for VxChar {
+    const TYPE: VxType = VxType::Char;
+    type Raw = vx_char;
+
+    fn to_raw(self) -> Self::Raw {
+        self.0
+    }
+
+    fn from_raw(raw: Self::Raw) -> Self {
+        VxChar(raw)
+    }
+}
+
+unsafe impl ScalarType for VxEnum {
+    const TYPE: VxType = VxType::Enum;
+    type Raw = vx_enum;
+
+    fn to_raw(self) -> Self::Raw {
+        self.0
+    }
+
+    fn from_raw(raw: Self::Raw) -> Self {
+        VxEnum(raw)
+    }
+}
+
+macro_rules! enum_scalar_types {
+    ($($ty:ident => $raw:ty),* $(,)?) => {
+        $(
+            unsafe impl ScalarType for $ty {
+                const TYPE: VxType = VxType::Enum;
+                type Raw = vx_enum;
+
+                fn to_raw(self) -> Self::Raw {
+                    $ty::to_raw(&self) as vx_enum
+                }
+
+                fn from_raw(raw: Self::Raw) -> Self {
+                    $ty::from(raw as $raw)
+                }
+            }
+        )*
+    };
+}
+
+enum_scalar_types! {
+    Channel => vx_channel_e,
+    ChannelRange => vx_channel_range_e,
+    ColorSpace => vx_color_space_e,
+    Direction => vx_direction_e,
+    Pattern => vx_pattern_e,
+    BorderPolicy => vx_border_policy_e,
+}
+
+unsafe impl ScalarType for ImageType {
+    const TYPE: VxType = VxType::DfImage;
+    type Raw = vx_df_image;
+
+    fn to_raw(self) -> vx_df_image {
+        ImageType::to_raw(&self) as vx_df_image
+    }
+
+    fn from_raw(raw: vx_df_image) -> Self {
+        ImageType::from(raw as vx_df_image_e)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bool_roundtrip() {
+        assert_eq!(true.to_raw(), vx_bool_e_vx_true_e as vx_bool);
+        assert!(bool::from_raw(true.to_raw()));
+        assert!(!bool::from_raw(false.to_raw()));
+    }
+
+    #[test]
+    fn enum_roundtrip() {
+        assert_eq!(
+            Channel::from_raw(ScalarType::to_raw(Channel::R)),
+            Channel::R
+        );
+        assert_eq!(
+            Pattern::from_raw(ScalarType::to_raw(Pattern::Cross)),
+            Pattern::Cross
+        );
+        assert_eq!(
+            ImageType::from_raw(ScalarType::to_raw(ImageType::U16)),
+            ImageType::U16
+        );
+        assert_eq!(VxEnum::from_raw(VxEnum(7).to_raw()), VxEnum(7));
+        assert_eq!(<VxChar as ScalarType>::TYPE, VxType::Char);
+    }
+}
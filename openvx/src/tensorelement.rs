@@ -0,0 +1,50 @@
+use crate::VxType;
+
+/// A type that can represent the elements of a tensor in host memory.
+///
+/// | Type  | Data type              |
+/// |-------|------------------------|
+/// | `i8`  | `VX_TYPE_INT8`         |
+/// | `u8`  | `VX_TYPE_UINT8`        |
+/// | `i16` | `VX_TYPE_INT16`        |
+/// | `u16` | `VX_TYPE_UINT16`       |
+/// | `i32` | `VX_TYPE_INT32`        |
+/// | `u32` | `VX_TYPE_UINT32`       |
+/// | `i64` | `VX_TYPE_INT64`        |
+/// | `u64` | `VX_TYPE_UINT64`       |
+/// | `f32` | `VX_TYPE_FLOAT32`      |
+/// | `f64` | `VX_TYPE_FLOAT64`      |
+///
+/// Fixed point tensors use the integer type of their data type; the fixed point position
+/// has to be applied by the caller.
+///
+/// # Safety
+///
+/// Implementors must be plain old data with the size of an element of `DATA_TYPE`.
+pub unsafe trait TensorElement: Copy + 'static {
+    /// The data type of tensors with elements of this type.
+    const DATA_TYPE: VxType;
+}
+
+macro_rules! tensor_elements {
+    ($($ty:ty => $data_type:ident),* $(,)?) => {
+        $(
+            unsafe impl TensorElement for $ty {
+                const DATA_TYPE: VxType = VxType::$data_type;
+            }
+        )*
+    };
+}
+
+tensor_elements! {
+    i8 => Int8,
+    u8 => UInt8,
+    i16 => Int16,
+    u16 => UInt16,
+    i32 => Int32,
+    u32 => UInt32,
+    i64 => Int64,
+    u64 => UInt64,
+    f32 => Float32,
+    f64 => Float64,
+}
@@ -0,0 +1,244 @@
+use crate::error::check_call;
+use crate::mapping::Mapping;
+use crate::{
+    attr, Access, AsRaw, MemoryType, QueryAttribute, Release, Result, TensorElement, VxError,
+    VxTensor,
+};
+use libopenvx_sys::{vxMapTensorPatch, vxUnmapTensorPatch, vx_enum, vx_map_id, vx_size, vx_tensor};
+use std::ffi::c_void;
+use std::marker::PhantomData;
+
+/// A patch of a tensor mapped into host memory by
+/// [`VxTensor::map_patch`](struct.VxTensor.html#method.map_patch).
+///
+/// The patch is unmapped by calling [`vxUnmapTensorPatch`] when the guard is dropped.
+/// Dimensions are given in the order of the tensor, i.e. dimension `0` is the one whose
+/// elements are adjacent in memory.
+///
+/// [`vxUnmapTensorPatch`]: ../libopenvx_sys/fn.vxUnmapTensorPatch.html
+#[derive(Debug)]
+pub struct TensorPatch<'t, T: TensorElement> {
+    mapping: Mapping<vx_tensor>,
+    dims: Vec<usize>,
+    strides: Vec<usize>,
+    _marker: PhantomData<(&'t (), T)>,
+}
+
+impl<'t, T: TensorElement> TensorPatch<'t, T> {
+    /// Maps the patch from `start` (inclusive) to `end` (exclusive) by calling [`vxMapTensorPatch`].
+    ///
+    /// [`vxMapTensorPatch`]: ../libopenvx_sys/fn.vxMapTensorPatch.html
+    pub(crate) fn map(
+        tensor: &'t VxTensor<'_>,
+        start: &[usize],
+        end: &[usize],
+        access: Access,
+    ) -> Result<Self> {
+        if tensor.query::<attr::DataType>()? != T::DATA_TYPE {
+            return Err(VxError::NotCompatible.into());
+        }
+
+        let number_of_dims = tensor.query::<attr::NumberOfDims>()?;
+        if start.len() != number_of_dims
+            || end.len() != number_of_dims
+            || start.iter().zip(end).any(|(start, end)| start > end)
+        {
+            return Err(VxError::InvalidParameters.into());
+        }
+
+        let mut map_id: vx_map_id = 0;
+        let mut strides: Vec<vx_size> = vec![0; number_of_dims];
+        let mut ptr: *mut c_void = std::ptr::null_mut();
+        check_call("vxMapTensorPatch", tensor, || unsafe {
+            vxMapTensorPatch(
+                tensor.as_raw(),
+                number_of_dims,
+                start.as_ptr(),
+                end.as_ptr(),
+                &mut map_id,
+                strides.as_mut_ptr(),
+                &mut ptr,
+                access.to_raw() as vx_enum,
+                MemoryType::Host.to_raw() as vx_enum,
+            )
+        })?;
+
+        let patch = Self {
+            mapping: Mapping::new(tensor.as_raw(), map_id, ptr, access, vxUnmapTensorPatch),
+            dims: start
+                .iter()
+                .zip(end)
+                .map(|(start, end)| end - start)
+                .collect(),
+            strides,
+            _marker: PhantomData,
+        };
+
+        // The strides have to be whole elements to be expressed in elements, e.g. for ndarray.
+        let element = std::mem::size_of::<T>();
+        if patch
+            .strides
+            .iter()
+            .any(|stride| !stride.is_multiple_of(element))
+        {
+            return Err(VxError::NotSupported.into());
+        }
+        patch.mapping.check_layout::<T>(&patch.strides)?;
+        Ok(patch)
+    }
+
+    /// Returns the size of each dimension of the patch.
+    pub fn dims(&self) -> &[usize] {
+        &self.dims
+    }
+
+    /// Returns the stride of each dimension of the patch in bytes.
+    pub fn strides(&self) -> &[usize] {
+        &self.strides
+    }
+
+    /// Returns the accessor the patch was mapped with.
+    pub fn access(&self) -> Access {
+        self.mapping.access()
+    }
+
+    /// Returns a pointer to the first element of the patch, e.g. to wrap it in a foreign type.
+    pub fn as_ptr(&self) -> *const T {
+        self.mapping.ptr() as *const T
+    }
+
+    /// Returns the element at the given index, with one coordinate per dimension.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of bounds or the patch was mapped write-only.
+    pub fn get(&self, index: &[usize]) -> &T {
+        assert!(
+            self.mapping.access().is_readable(),
+            "patch is not mapped for reading"
+        );
+        unsafe { &*self.element_ptr(index) }
+    }
+
+    /// Returns the element at the given index for writing, with one coordinate per dimension.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of bounds or the patch was mapped read-only.
+    pub fn get_mut(&mut self, index: &[usize]) -> &mut T {
+        assert!(
+            self.mapping.access().is_writable(),
+            "patch is not mapped for writing"
+        );
+        unsafe { &mut *self.element_ptr(index) }
+    }
+
+    fn element_ptr(&self, index: &[usize]) -> *mut T {
+        assert_eq!(
+            index.len(),
+            self.dims.len(),
+            "index has the wrong dimension"
+        );
+        let mut offset = 0;
+        for ((&i, &dim), &stride) in index.iter().zip(&self.dims).zip(&self.strides) {
+            assert!(i < dim, "index {:?} out of bounds", index);
+            offset += i * stride;
+        }
+        unsafe { self.mapping.ptr().add(offset) as *mut T }
+    }
+}
+
+#[cfg(feature = "ndarray")]
+impl<T: TensorElement> TensorPatch<'_, T> {
+    /// Returns the patch as an array view that follows the strides of the tensor.
+    ///
+    /// Axis `i` of the view is dimension `i` of the tensor; use
+    /// [`reversed_axes`](https://docs.rs/ndarray/latest/ndarray/struct.ArrayBase.html#method.reversed_axes)
+    /// to obtain the row-major axis order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the patch was mapped write-only.
+    pub fn as_array_view(&self) -> ndarray::ArrayViewD<'_, T> {
+        use ndarray::ShapeBuilder;
+        assert!(
+            self.mapping.access().is_readable(),
+            "patch is not mapped for reading"
+        );
+        let shape = ndarray::IxDyn(&self.dims).strides(self.element_strides());
+        unsafe { ndarray::ArrayViewD::from_shape_ptr(shape, self.mapping.ptr() as *const T) }
+    }
+
+    /// Returns the patch as a mutable array view that follows the strides of the tensor.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the patch was mapped read-only.
+    pub fn as_array_view_mut(&mut self) -> ndarray::ArrayViewMutD<'_, T> {
+        use ndarray::ShapeBuilder;
+        assert!(
+            self.mapping.access().is_writable(),
+            "patch is not mapped for writing"
+        );
+        let shape = ndarray::IxDyn(&self.dims).strides(self.element_strides());
+        unsafe { ndarray::ArrayViewMutD::from_shape_ptr(shape, self.mapping.ptr() as *mut T) }
+    }
+
+    /// Returns the strides of the patch in elements.
+    fn element_strides(&self) -> ndarray::IxDyn {
+        let element = std::mem::size_of::<T>();
+        let strides: Vec<usize> = self.strides.iter().map(|stride| stride / element).collect();
+        ndarray::IxDyn(&strides)
+    }
+}
+
+impl<T: TensorElement> Release for TensorPatch<'_, T> {
+    fn release(&mut self) -> Result<()> {
+        self.mapping.release()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn patch<T: TensorElement>(
+        data: &mut [u8],
+        dims: &[usize],
+        strides: &[usize],
+    ) -> TensorPatch<'static, T> {
+        TensorPatch {
+            mapping: Mapping::detached(std::ptr::null_mut(), data.as_mut_ptr(), Access::ReadWrite),
+            dims: dims.to_vec(),
+            strides: strides.to_vec(),
+            _marker: PhantomData,
+        }
+    }
+
+    #[test]
+    fn strided_elements() {
+        let mut data: Vec<i16> = (0..12).collect();
+        let bytes = unsafe { std::slice::from_raw_parts_mut(data.as_mut_ptr().cast(), 24) };
+        let mut patch = patch::<i16>(bytes, &[2, 3], &[2, 8]);
+
+        assert_eq!(*patch.get(&[1, 2]), 9);
+        *patch.get_mut(&[0, 1]) = -1;
+        assert_eq!(data[4], -1);
+    }
+
+    #[cfg(feature = "ndarray")]
+    #[test]
+    fn array_view() {
+        let mut data: Vec<f32> = (0..6).map(|i| i as f32).collect();
+        let bytes = unsafe { std::slice::from_raw_parts_mut(data.as_mut_ptr().cast(), 24) };
+        let mut patch = patch::<f32>(bytes, &[3, 2], &[4, 12]);
+
+        let view = patch.as_array_view();
+        assert_eq!(view.shape(), [3, 2]);
+        assert_eq!(view[[2, 1]], 5.0);
+        assert_eq!(view.reversed_axes()[[0, 2]], 2.0);
+
+        patch.as_array_view_mut()[[1, 0]] = -1.0;
+        assert_eq!(data[1], -1.0);
+    }
+}
@@ -0,0 +1,422 @@
+use crate::Coordinates2df;
+use libopenvx_sys::vx_float32;
+
+/// A 2D affine transform for `vxWarpAffineNode`.
+///
+/// The transform is stored in the usual mathematical convention, i.e. as the upper two rows of
+/// a 3x3 matrix applied to column vectors `(x, y, 1)`. [`to_vx_layout`](#method.to_vx_layout)
+/// converts it into the transposed 2x3 layout OpenVX expects.
+///
+/// The warp nodes map each output pixel to a location in the input image. To move the content
+/// of an image by a transform, pass its [`inverse`](#method.inverse) to the node.
+///
+/// # Examples
+///
+/// ```
+/// use openvx::{AffineTransform, Coordinates2df};
+///
+/// let transform = AffineTransform::scale(2.0, 2.0).then(&AffineTransform::translation(10.0, 0.0));
+/// assert_eq!(transform.apply(Coordinates2df::new(1.0, 1.0)), Coordinates2df::new(12.0, 2.0));
+///
+/// // Column 2 of the OpenVX layout holds the translation.
+/// assert_eq!(transform.to_vx_layout()[2], [10.0, 0.0]);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct AffineTransform {
+    /// The rows of the transform.
+    pub m: [[vx_float32; 3]; 2],
+}
+
+impl AffineTransform {
+    /// The transform that maps every point onto itself.
+    pub const IDENTITY: Self = Self {
+        m: [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0]],
+    };
+
+    /// Creates a transform moving points by `(x, y)`.
+    pub const fn translation(x: vx_float32, y: vx_float32) -> Self {
+        Self {
+            m: [[1.0, 0.0, x], [0.0, 1.0, y]],
+        }
+    }
+
+    /// Creates a transform scaling points by `x` and `y` about the origin.
+    pub const fn scale(x: vx_float32, y: vx_float32) -> Self {
+        Self {
+            m: [[x, 0.0, 0.0], [0.0, y, 0.0]],
+        }
+    }
+
+    /// Creates a transform rotating points by `angle` radians about the origin.
+    ///
+    /// Since the y axis of images points down, positive angles rotate clockwise on screen.
+    pub fn rotation(angle: vx_float32) -> Self {
+        let (sin, cos) = angle.sin_cos();
+        Self {
+            m: [[cos, -sin, 0.0], [sin, cos, 0.0]],
+        }
+    }
+
+    /// Creates a transform rotating points by `angle` radians about `center`.
+    pub fn rotation_about(angle: vx_float32, center: Coordinates2df) -> Self {
+        Self::translation(-center.x, -center.y)
+            .then(&Self::rotation(angle))
+            .then(&Self::translation(center.x, center.y))
+    }
+
+    /// Creates a transform shearing points by the factors `sx` and `sy`, i.e. mapping `(x, y)`
+    /// to `x' = x + sx·y, y' = y + sy·x`.
+    pub const fn shear(sx: vx_float32, sy: vx_float32) -> Self {
+        Self {
+            m: [[1.0, sx, 0.0], [sy, 1.0, 0.0]],
+        }
+    }
+
+    /// Returns the transform that applies this transform first and `next` second.
+    pub fn then(&self, next: &AffineTransform) -> Self {
+        let [a, b] = next.m;
+        let [c, d] = self.m;
+        Self {
+            m: [
+                [
+                    a[0] * c[0] + a[1] * d[0],
+                    a[0] * c[1] + a[1] * d[1],
+                    a[0] * c[2] + a[1] * d[2] + a[2],
+                ],
+                [
+                    b[0] * c[0] + b[1] * d[0],
+                    b[0] * c[1] + b[1] * d[1],
+                    b[0] * c[2] + b[1] * d[2] + b[2],
+                ],
+            ],
+        }
+    }
+
+    /// Returns the inverse transform, or `None` if the transform is singular.
+    pub fn inverse(&self) -> Option<Self> {
+        let [[a, b, tx], [c, d, ty]] = self.m;
+        let det = a * d - b * c;
+        if det == 0.0 || !det.is_finite() {
+            return None;
+        }
+
+        let (a, b, c, d) = (d / det, -b / det, -c / det, a / det);
+        Some(Self {
+            m: [[a, b, -(a * tx + b * ty)], [c, d, -(c * tx + d * ty)]],
+        })
+    }
+
+    /// Maps a point through the transform.
+    pub fn apply(&self, point: Coordinates2df) -> Coordinates2df {
+        let [r0, r1] = self.m;
+        Coordinates2df::new(
+            r0[0] * point.x + r0[1] * point.y + r0[2],
+            r1[0] * point.x + r1[1] * point.y + r1[2],
+        )
+    }
+
+    /// Returns the transform in the layout of the 2x3 matrix of `vxWarpAffineNode`, i.e. as
+    /// three rows of two columns where element `[j][i]` is row `i`, column `j` of the transform.
+    pub const fn to_vx_layout(&self) -> [[vx_float32; 2]; 3] {
+        let [r0, r1] = self.m;
+        [[r0[0], r1[0]], [r0[1], r1[1]], [r0[2], r1[2]]]
+    }
+}
+
+impl Default for AffineTransform {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+/// A 2D perspective transform (homography) for `vxWarpPerspectiveNode`.
+///
+/// The transform is stored in the usual mathematical convention, i.e. as a 3x3 matrix applied
+/// to column vectors `(x, y, 1)` followed by the division by the third component.
+/// [`to_vx_layout`](#method.to_vx_layout) converts it into the transposed layout OpenVX expects.
+///
+/// As for [`AffineTransform`](struct.AffineTransform.html), the warp node maps output pixels
+/// to input locations.
+///
+/// # Examples
+///
+/// ```
+/// use openvx::{Coordinates2df, PerspectiveTransform};
+///
+/// // Rectify a quadrilateral of the input into a 100x100 output image.
+/// let quad = [(10.0, 12.0), (90.0, 5.0), (95.0, 98.0), (4.0, 90.0)];
+/// let square = [(0.0, 0.0), (100.0, 0.0), (100.0, 100.0), (0.0, 100.0)];
+/// let to_input = PerspectiveTransform::from_points(
+///     square.map(|(x, y)| Coordinates2df::new(x, y)),
+///     quad.map(|(x, y)| Coordinates2df::new(x, y)),
+/// )
+/// .unwrap();
+///
+/// let corner = to_input.apply(Coordinates2df::new(100.0, 100.0)).unwrap();
+/// assert!((corner.x - 95.0).abs() < 1e-3 && (corner.y - 98.0).abs() < 1e-3);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct PerspectiveTransform {
+    /// The rows of the transform.
+    pub m: [[vx_float32; 3]; 3],
+}
+
+impl PerspectiveTransform {
+    /// The transform that maps every point onto itself.
+    pub const IDENTITY: Self = Self {
+        m: [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]],
+    };
+
+    /// Computes the transform mapping each point of `from` onto the point of `to` at the
+    /// same index, or `None` if three of the points are collinear.
+    pub fn from_points(from: [Coordinates2df; 4], to: [Coordinates2df; 4]) -> Option<Self> {
+        // Solve for the first eight elements with the last one fixed to 1.
+        let mut system = [[0f64; 9]; 8];
+        for (i, (from, to)) in from.iter().zip(&to).enumerate() {
+            let (x, y) = (from.x as f64, from.y as f64);
+            let (u, v) = (to.x as f64, to.y as f64);
+            system[2 * i] = [x, y, 1.0, 0.0, 0.0, 0.0, -u * x, -u * y, u];
+            system[2 * i + 1] = [0.0, 0.0, 0.0, x, y, 1.0, -v * x, -v * y, v];
+        }
+
+        let h = solve(system)?;
+        Some(Self {
+            m: [
+                [h[0] as f32, h[1] as f32, h[2] as f32],
+                [h[3] as f32, h[4] as f32, h[5] as f32],
+                [h[6] as f32, h[7] as f32, 1.0],
+            ],
+        })
+    }
+
+    /// Returns the transform that applies this transform first and `next` second.
+    pub fn then(&self, next: &PerspectiveTransform) -> Self {
+        let mut m = [[0.0; 3]; 3];
+        for (i, row) in m.iter_mut().enumerate() {
+            for (j, value) in row.iter_mut().enumerate() {
+                *value = (0..3).map(|k| next.m[i][k] * self.m[k][j]).sum();
+            }
+        }
+        Self { m }
+    }
+
+    /// Returns the inverse transform, or `None` if the transform is singular.
+    pub fn inverse(&self) -> Option<Self> {
+        let m = &self.m;
+        let cofactor = |r0: usize, r1: usize, c0: usize, c1: usize| {
+            m[r0][c0] * m[r1][c1] - m[r0][c1] * m[r1][c0]
+        };
+
+        // The adjugate is the transposed cofactor matrix.
+        let adjugate = [
+            [
+                cofactor(1, 2, 1, 2),
+                -cofactor(0, 2, 1, 2),
+                cofactor(0, 1, 1, 2),
+            ],
+            [
+                -cofactor(1, 2, 0, 2),
+                cofactor(0, 2, 0, 2),
+                -cofactor(0, 1, 0, 2),
+            ],
+            [
+                cofactor(1, 2, 0, 1),
+                -cofactor(0, 2, 0, 1),
+                cofactor(0, 1, 0, 1),
+            ],
+        ];
+        let det = (0..3).map(|k| m[0][k] * adjugate[k][0]).sum::<vx_float32>();
+        if det == 0.0 || !det.is_finite() {
+            return None;
+        }
+
+        Some(Self {
+            m: adjugate.map(|row| row.map(|value| value / det)),
+        })
+    }
+
+    /// Maps a point through the transform, or returns `None` if it is mapped to infinity.
+    pub fn apply(&self, point: Coordinates2df) -> Option<Coordinates2df> {
+        let [r0, r1, r2] = self.m;
+        let w = r2[0] * point.x + r2[1] * point.y + r2[2];
+        if w == 0.0 {
+            return None;
+        }
+        Some(Coordinates2df::new(
+            (r0[0] * point.x + r0[1] * point.y + r0[2]) / w,
+            (r1[0] * point.x + r1[1] * point.y + r1[2]) / w,
+        ))
+    }
+
+    /// Returns the transform in the layout of the 3x3 matrix of `vxWarpPerspectiveNode`, i.e.
+    /// transposed, so that element `[j][i]` is row `i`, column `j` of the transform.
+    pub const fn to_vx_layout(&self) -> [[vx_float32; 3]; 3] {
+        let [r0, r1, r2] = self.m;
+        [
+            [r0[0], r1[0], r2[0]],
+            [r0[1], r1[1], r2[1]],
+            [r0[2], r1[2], r2[2]],
+        ]
+    }
+}
+
+impl Default for PerspectiveTransform {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl From<AffineTransform> for PerspectiveTransform {
+    fn from(value: AffineTransform) -> Self {
+        let [r0, r1] = value.m;
+        Self {
+            m: [r0, r1, [0.0, 0.0, 1.0]],
+        }
+    }
+}
+
+/// Solves a linear system given as augmented matrix by Gaussian elimination with partial
+/// pivoting, or returns `None` if it is singular.
+fn solve<const N: usize, const M: usize>(mut system: [[f64; M]; N]) -> Option<[f64; N]> {
+    for column in 0..N {
+        let pivot = (column..N)
+            .max_by(|&a, &b| system[a][column].abs().total_cmp(&system[b][column].abs()))?;
+        if system[pivot][column].abs() < 1e-12 {
+            return None;
+        }
+        system.swap(column, pivot);
+
+        let (above, below) = system.split_at_mut(column + 1);
+        let pivot_row = &above[column];
+        for row in below {
+            let factor = row[column] / pivot_row[column];
+            for (value, pivot_value) in row.iter_mut().zip(pivot_row).skip(column) {
+                *value -= factor * pivot_value;
+            }
+        }
+    }
+
+    let mut solution = [0f64; N];
+    for row in (0..N).rev() {
+        let sum: f64 = (row + 1..N).map(|k| system[row][k] * solution[k]).sum();
+        solution[row] = (system[row][N] - sum) / system[row][row];
+    }
+    Some(solution)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(a: Coordinates2df, b: Coordinates2df) {
+        assert!(
+            (a.x - b.x).abs() < 1e-3 && (a.y - b.y).abs() < 1e-3,
+            "{:?} != {:?}",
+            a,
+            b
+        );
+    }
+
+    /// Maps a point as `vxWarpAffineNode` does with the matrix `m`.
+    fn warp_affine(m: [[f32; 2]; 3], p: Coordinates2df) -> Coordinates2df {
+        Coordinates2df::new(
+            m[0][0] * p.x + m[1][0] * p.y + m[2][0],
+            m[0][1] * p.x + m[1][1] * p.y + m[2][1],
+        )
+    }
+
+    /// Maps a point as `vxWarpPerspectiveNode` does with the matrix `m`.
+    fn warp_perspective(m: [[f32; 3]; 3], p: Coordinates2df) -> Coordinates2df {
+        let z = m[0][2] * p.x + m[1][2] * p.y + m[2][2];
+        Coordinates2df::new(
+            (m[0][0] * p.x + m[1][0] * p.y + m[2][0]) / z,
+            (m[0][1] * p.x + m[1][1] * p.y + m[2][1]) / z,
+        )
+    }
+
+    const POINTS: [(f32, f32); 4] = [(0.0, 0.0), (3.0, -1.0), (-2.5, 4.0), (10.0, 7.0)];
+
+    #[test]
+    fn affine_builders() {
+        let p = Coordinates2df::new(2.0, 3.0);
+        assert_close(
+            AffineTransform::translation(1.0, -1.0).apply(p),
+            Coordinates2df::new(3.0, 2.0),
+        );
+        assert_close(
+            AffineTransform::scale(2.0, 0.5).apply(p),
+            Coordinates2df::new(4.0, 1.5),
+        );
+        assert_close(
+            AffineTransform::rotation(std::f32::consts::FRAC_PI_2).apply(p),
+            Coordinates2df::new(-3.0, 2.0),
+        );
+        assert_close(
+            AffineTransform::shear(1.0, 0.0).apply(p),
+            Coordinates2df::new(5.0, 3.0),
+        );
+
+        let center = Coordinates2df::new(5.0, 5.0);
+        assert_close(
+            AffineTransform::rotation_about(1.0, center).apply(center),
+            center,
+        );
+    }
+
+    #[test]
+    fn affine_layout_matches_direct_mapping() {
+        let transform = AffineTransform::rotation(0.3)
+            .then(&AffineTransform::shear(0.2, -0.1))
+            .then(&AffineTransform::scale(1.5, 0.75))
+            .then(&AffineTransform::translation(4.0, -2.0));
+        let inverse = transform.inverse().unwrap();
+
+        for (x, y) in POINTS {
+            let p = Coordinates2df::new(x, y);
+            let mapped = transform.apply(p);
+            assert_close(warp_affine(transform.to_vx_layout(), p), mapped);
+            assert_close(inverse.apply(mapped), p);
+        }
+
+        assert_eq!(AffineTransform::scale(0.0, 1.0).inverse(), None);
+    }
+
+    #[test]
+    fn perspective_layout_matches_direct_mapping() {
+        let transform = PerspectiveTransform {
+            m: [[1.2, 0.1, 3.0], [-0.2, 0.9, 1.0], [0.001, 0.002, 1.0]],
+        };
+        let inverse = transform.inverse().unwrap();
+
+        for (x, y) in POINTS {
+            let p = Coordinates2df::new(x, y);
+            let mapped = transform.apply(p).unwrap();
+            assert_close(warp_perspective(transform.to_vx_layout(), p), mapped);
+            assert_close(inverse.apply(mapped).unwrap(), p);
+        }
+
+        let affine = AffineTransform::rotation(0.5).then(&AffineTransform::translation(1.0, 2.0));
+        let p = Coordinates2df::new(3.0, 4.0);
+        assert_close(
+            PerspectiveTransform::from(affine).apply(p).unwrap(),
+            affine.apply(p),
+        );
+    }
+
+    #[test]
+    fn homography_from_points() {
+        let from = POINTS.map(|(x, y)| Coordinates2df::new(x, y));
+        let to = [(1.0, 1.0), (5.0, 0.0), (-1.0, 6.0), (12.0, 11.0)]
+            .map(|(x, y)| Coordinates2df::new(x, y));
+
+        let transform = PerspectiveTransform::from_points(from, to).unwrap();
+        for (from, to) in from.iter().zip(&to) {
+            assert_close(transform.apply(*from).unwrap(), *to);
+            assert_close(warp_perspective(transform.to_vx_layout(), *from), *to);
+        }
+
+        let collinear = [(0.0, 0.0), (1.0, 1.0), (2.0, 2.0), (0.0, 5.0)]
+            .map(|(x, y)| Coordinates2df::new(x, y));
+        assert_eq!(PerspectiveTransform::from_points(collinear, to), None);
+    }
+}
@@ -1,8 +1,10 @@
+mod array;
 mod context;
 mod convolution;
 mod delay;
 mod distribution;
 mod graph;
+mod hostimage;
 mod image;
 mod kernel;
 mod lut;
@@ -10,14 +12,20 @@ mod matrix;
 mod node;
 mod parameter;
 mod pyramid;
+mod rawimage;
 pub mod reference;
+mod remap;
 mod scalar;
+mod tensor;
 mod threshold;
 
+pub use array::VxArray;
 pub use context::VxContext;
 pub use convolution::VxConvolution;
+pub use delay::VxDelay;
 pub use distribution::VxDistribution;
 pub use graph::VxGraph;
+pub use hostimage::VxHostImage;
 pub use image::VxImage;
 pub use kernel::VxKernel;
 pub use lut::VxLut;
@@ -25,6 +33,9 @@ pub use matrix::VxMatrix;
 pub use node::VxNode;
 pub use parameter::VxParameter;
 pub use pyramid::VxPyramid;
+pub use rawimage::{RawImageParams, VxRawImage};
 pub use reference::{AsVxReference, VxReference};
+pub use remap::VxRemap;
 pub use scalar::VxScalar;
-pub use threshold::VxThreshold;
+pub use tensor::VxTensor;
+pub use threshold::{ThresholdType, VxThreshold};
@@ -0,0 +1,294 @@
+use crate::error::check_call;
+use crate::types::reference::retain_reference;
+use crate::{
+    attr, Access, ArrayItem, ArrayRange, AsRaw, AsVxReference, CheckStatus, MemoryType,
+    QueryAttribute, Release, Result, VxContext, VxError, VxGraph, VxReference, VxStatus, VxType,
+};
+use libopenvx_sys::{
+    vxAddArrayItems, vxCopyArrayRange, vxCreateArray, vxCreateVirtualArray, vxQueryArray,
+    vxReleaseArray, vxTruncateArray, vx_array, vx_enum, vx_reference, vx_size, vx_status,
+};
+use std::ffi::c_void;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+use std::ops::Range;
+
+/// The Array Object. Array is a strongly-typed container for other data structures.
+///
+/// The items are represented by `T` in host memory; see [`ArrayItem`](trait.ArrayItem.html)
+/// for the supported types.
+///
+/// The object is bound to the lifetime `'a` of the [`VxContext`](struct.VxContext.html) it was
+/// created in or, for virtual objects, of the [`VxGraph`](struct.VxGraph.html) that owns it.
+///
+/// # Examples
+///
+/// ```no_run
+/// use openvx::{Access, Coordinates2d, VxArray, VxContext};
+///
+/// let context = VxContext::create().unwrap();
+/// let points = VxArray::<Coordinates2d>::new(&context, 16).unwrap();
+/// points.push(Coordinates2d::new(4, 2)).unwrap();
+///
+/// let range = points.map_range(0..points.len().unwrap(), Access::Read).unwrap();
+/// assert!(range.iter().all(|point| point.x == 4));
+/// ```
+pub struct VxArray<'a, T> {
+    raw: vx_array,
+    _marker: PhantomData<(&'a (), T)>,
+}
+
+impl<'a, T: ArrayItem> VxArray<'a, T> {
+    /// Creates a reference to an array object by calling [`vxCreateArray`].
+    ///
+    /// # Arguments
+    ///
+    /// * `context` - The context in which to create the array.
+    /// * `capacity` - The maximal number of items that the array can hold.
+    ///
+    /// [`vxCreateArray`]: ../../libopenvx_sys/fn.vxCreateArray.html
+    pub fn new(context: &'a VxContext, capacity: vx_size) -> Result<Self> {
+        Self::with_type(context, T::ITEM_TYPE, capacity)
+    }
+
+    /// Creates an array of a user struct registered at runtime by calling [`vxCreateArray`].
+    ///
+    /// `item_type` is the type returned by [`vxRegisterUserStruct`]; its size is checked against
+    /// the size of `T` when items are accessed.
+    ///
+    /// [`vxCreateArray`]: ../../libopenvx_sys/fn.vxCreateArray.html
+    /// [`vxRegisterUserStruct`]: ../../libopenvx_sys/fn.vxRegisterUserStruct.html
+    pub fn with_type(context: &'a VxContext, item_type: VxType, capacity: vx_size) -> Result<Self> {
+        let array =
+            unsafe { vxCreateArray(context.as_raw(), item_type.to_raw() as vx_enum, capacity) };
+        VxArray::from(array).into_checked()
+    }
+
+    /// Creates an opaque reference to a virtual array with no direct user access
+    /// by calling [`vxCreateVirtualArray`].
+    ///
+    /// The capacity may be `0`, in which case it is inferred during graph verification.
+    ///
+    /// [`vxCreateVirtualArray`]: ../../libopenvx_sys/fn.vxCreateVirtualArray.html
+    pub fn virtual_array(graph: &'a VxGraph<'_>, capacity: vx_size) -> Result<Self> {
+        let array = unsafe {
+            vxCreateVirtualArray(graph.as_raw(), T::ITEM_TYPE.to_raw() as vx_enum, capacity)
+        };
+        VxArray::from(array).into_checked()
+    }
+
+    /// Returns the number of items in the array.
+    pub fn len(&self) -> Result<usize> {
+        self.query::<attr::NumItems>()
+    }
+
+    /// Determines whether the array holds no items.
+    pub fn is_empty(&self) -> Result<bool> {
+        Ok(self.len()? == 0)
+    }
+
+    /// Returns the maximal number of items the array can hold.
+    pub fn capacity(&self) -> Result<usize> {
+        self.query::<attr::Capacity>()
+    }
+
+    /// Appends an item by calling [`vxAddArrayItems`].
+    ///
+    /// [`vxAddArrayItems`]: ../../libopenvx_sys/fn.vxAddArrayItems.html
+    pub fn push(&self, item: T) -> Result<()> {
+        self.extend(std::slice::from_ref(&item))
+    }
+
+    /// Appends items by calling [`vxAddArrayItems`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VxError::NotCompatible`] if the item size of the array differs from the size
+    /// of `T`, and [`VxError::InvalidParameters`] if the items exceed the capacity.
+    ///
+    /// [`vxAddArrayItems`]: ../../libopenvx_sys/fn.vxAddArrayItems.html
+    /// [`VxError::NotCompatible`]: ../enum.VxError.html#variant.NotCompatible
+    /// [`VxError::InvalidParameters`]: ../enum.VxError.html#variant.InvalidParameters
+    pub fn extend(&self, items: &[T]) -> Result<()> {
+        self.check_item_size()?;
+        if items.is_empty() {
+            return Ok(());
+        }
+
+        check_call("vxAddArrayItems", self, || unsafe {
+            vxAddArrayItems(
+                self.raw,
+                items.len(),
+                items.as_ptr() as *const c_void,
+                std::mem::size_of::<T>(),
+            )
+        })
+    }
+
+    /// Shortens the array to `len` items by calling [`vxTruncateArray`].
+    ///
+    /// [`vxTruncateArray`]: ../../libopenvx_sys/fn.vxTruncateArray.html
+    pub fn truncate(&self, len: usize) -> Result<()> {
+        check_call("vxTruncateArray", self, || unsafe {
+            vxTruncateArray(self.raw, len)
+        })
+    }
+
+    /// Maps the items in `range` into host memory by calling [`vxMapArrayRange`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VxError::NotCompatible`] if the item size of the array differs from the size
+    /// of `T`, and [`VxError::InvalidParameters`] if the range is empty.
+    ///
+    /// [`vxMapArrayRange`]: ../../libopenvx_sys/fn.vxMapArrayRange.html
+    /// [`VxError::NotCompatible`]: ../enum.VxError.html#variant.NotCompatible
+    /// [`VxError::InvalidParameters`]: ../enum.VxError.html#variant.InvalidParameters
+    pub fn map_range(&self, range: Range<usize>, access: Access) -> Result<ArrayRange<'_, T>> {
+        ArrayRange::map(self, range.start, range.end, access)
+    }
+
+    /// Reads all items into a vector by calling [`vxCopyArrayRange`].
+    ///
+    /// [`vxCopyArrayRange`]: ../../libopenvx_sys/fn.vxCopyArrayRange.html
+    pub fn to_vec(&self) -> Result<Vec<T>> {
+        self.check_item_size()?;
+        let len = self.len()?;
+        let mut items = Vec::with_capacity(len);
+        if len == 0 {
+            return Ok(items);
+        }
+
+        check_call("vxCopyArrayRange", self, || unsafe {
+            vxCopyArrayRange(
+                self.raw,
+                0,
+                len,
+                std::mem::size_of::<T>(),
+                items.as_mut_ptr() as *mut c_void,
+                Access::Read.to_raw() as vx_enum,
+                MemoryType::Host.to_raw() as vx_enum,
+            )
+        })?;
+        unsafe { items.set_len(len) };
+        Ok(items)
+    }
+
+    /// Checks that `T` has the size of the items of the array.
+    pub(crate) fn check_item_size(&self) -> Result<()> {
+        if self.query::<attr::ItemSize>()? != std::mem::size_of::<T>() {
+            return Err(VxError::NotCompatible.into());
+        }
+        Ok(())
+    }
+}
+
+impl<'a, T> VxArray<'a, T> {
+    #[allow(dead_code)]
+    pub fn is_null(&self) -> bool {
+        self.raw.is_null()
+    }
+}
+
+impl<T> fmt::Debug for VxArray<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("VxArray").field("raw", &self.raw).finish()
+    }
+}
+
+impl<T> PartialEq for VxArray<'_, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.raw == other.raw
+    }
+}
+
+impl<T> Eq for VxArray<'_, T> {}
+
+impl<T> Hash for VxArray<'_, T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.raw.hash(state);
+    }
+}
+
+impl<'a, T> AsRaw for VxArray<'a, T> {
+    type Result = vx_array;
+
+    fn as_raw(&self) -> Self::Result {
+        self.raw
+    }
+}
+
+impl<'a, T> AsVxReference for VxArray<'a, T> {
+    fn as_reference(&self) -> VxReference<'_> {
+        VxReference::from(self.raw)
+    }
+}
+
+impl<T> QueryAttribute for VxArray<'_, T> {
+    unsafe fn query_raw(&self, attribute: vx_enum, ptr: *mut c_void, size: vx_size) -> vx_status {
+        vxQueryArray(self.raw, attribute, ptr, size)
+    }
+}
+
+impl<'a, T> Release for VxArray<'a, T> {
+    fn release(&mut self) -> Result<()> {
+        if self.raw.is_null() {
+            return Ok(());
+        }
+
+        let status = unsafe { vxReleaseArray(&mut self.raw) };
+        self.raw = std::ptr::null_mut();
+
+        VxStatus::new_result(status, ())
+    }
+}
+
+impl<'a, T> Drop for VxArray<'a, T> {
+    fn drop(&mut self) {
+        self.release().unwrap();
+    }
+}
+
+impl<'a, T> Clone for VxArray<'a, T> {
+    /// Creates a new handle to the same object, incrementing its reference count
+    /// by calling [`vxRetainReference`](../../libopenvx_sys/fn.vxRetainReference.html).
+    fn clone(&self) -> Self {
+        retain_reference(self.raw as vx_reference);
+        VxArray {
+            raw: self.raw,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, T> From<vx_array> for VxArray<'a, T> {
+    /// Takes ownership of the reference; it will be released when the wrapper is dropped.
+    ///
+    /// The item type of the array is not checked against `T`.
+    fn from(value: vx_array) -> Self {
+        VxArray {
+            raw: value,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, T> From<VxArray<'a, T>> for vx_array {
+    /// Gives up ownership of the reference; the caller becomes responsible for releasing it.
+    fn from(value: VxArray<'a, T>) -> Self {
+        let raw = value.raw;
+        std::mem::forget(value);
+        raw
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn is_null() {
+        assert!(VxArray::<u8>::from(std::ptr::null_mut()).is_null());
+    }
+}
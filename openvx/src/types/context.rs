@@ -1,6 +1,14 @@
+use crate::attr::string_from_chars;
+use crate::contextinfo::parse_extensions;
+use crate::logging;
 use crate::types::{AsVxReference, VxReference};
-use crate::{AsRaw, Release, Result, SetDirective, VxDirective, VxStatus};
+use crate::{
+    attr, AsRaw, CheckStatus, ContextInfo, KernelInfo, LogEntry, QueryAttribute, Release, Result,
+    SetAttribute, SetDirective, VxDirective, VxError, VxStatus,
+};
 use libopenvx_sys::*;
+use std::ffi::c_void;
+use std::sync::Arc;
 
 /// An opaque reference to the implementation context.
 pub struct VxContext {
@@ -14,34 +22,211 @@ impl VxContext {
 
     /// Creates a new [`VxContext`] by calling [`vxCreateContext`].
     ///
+    /// # Errors
+    ///
+    /// Returns [`VxError::NoResources`] if no context was created, as [`vxGetStatus`] reports
+    /// for a null reference, and the error reported by [`vxGetStatus`] for an erroneous one.
+    ///
+    /// [`VxError::NoResources`]: ../enum.VxError.html#variant.NoResources
     /// [`VxContext`]: struct.VxContext.html
     /// [`vxCreateContext`]: ../../libopenvx_sys/fn.vxCreateContext.html
-    pub fn create() -> Self {
+    /// [`vxGetStatus`]: ../../libopenvx_sys/fn.vxGetStatus.html
+    pub fn create() -> Result<Self> {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::info_span!("vxCreateContext").entered();
+
         let context = unsafe { vxCreateContext() };
-        Self::wrap(context)
+        Self::checked(context)
+    }
+
+    /// Wraps a context returned by [`vxCreateContext`] and checks it for errors.
+    ///
+    /// [`vxCreateContext`]: ../../libopenvx_sys/fn.vxCreateContext.html
+    fn checked(context: vx_context) -> Result<Self> {
+        if context.is_null() {
+            return Err(VxError::NoResources.into());
+        }
+        Self::wrap(context).into_checked()
+    }
+
+    /// Returns the extensions supported by the implementation by querying
+    /// `VX_CONTEXT_EXTENSIONS`.
+    pub fn extensions(&self) -> Result<Vec<String>> {
+        let size = self.query::<attr::ExtensionsSize>()?;
+        if size == 0 {
+            return Ok(Vec::new());
+        }
+
+        let mut buffer = vec![0 as vx_char; size];
+        let status = unsafe {
+            vxQueryContext(
+                self.raw,
+                vx_context_attribute_e_VX_CONTEXT_EXTENSIONS as vx_enum,
+                buffer.as_mut_ptr() as *mut c_void,
+                size as vx_size,
+            )
+        };
+        VxStatus::new_result(status, ())?;
+
+        Ok(parse_extensions(&string_from_chars(&buffer)))
+    }
+
+    /// Queries the attributes describing the implementation behind this context.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use openvx::VxContext;
+    ///
+    /// let context = VxContext::create().unwrap();
+    /// let info = context.info().unwrap();
+    /// println!("{} (OpenVX {})", info.implementation, info.version);
+    ///
+    /// if info.has_extension("vx_khr_nn") {
+    ///     println!("Neural network extension available");
+    /// }
+    /// ```
+    pub fn info(&self) -> Result<ContextInfo> {
+        Ok(ContextInfo {
+            vendor_id: self.query::<attr::VendorId>()?,
+            version: self.query::<attr::Version>()?,
+            implementation: self.query::<attr::Implementation>()?,
+            extensions: self.extensions()?,
+            num_modules: self.query::<attr::Modules>()?,
+            num_kernels: self.query::<attr::UniqueKernels>()?,
+            num_references: self.query::<attr::References>()?,
+            convolution_max_dimension: self.query::<attr::ConvolutionMaxDimension>()?,
+            optical_flow_max_window_dimension: self
+                .query::<attr::OpticalFlowMaxWindowDimension>()?,
+            immediate_border: self.query::<attr::BorderMode>()?,
+            immediate_border_policy: self.query::<attr::ImmediateBorderPolicy>()?,
+            max_tensor_dims: self.query::<attr::MaxTensorDims>()?,
+            nonlinear_max_dimension: self.query::<attr::NonlinearMaxDimension>()?,
+        })
+    }
+
+    /// Returns the kernels available in this context by querying
+    /// `VX_CONTEXT_UNIQUE_KERNEL_TABLE`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use openvx::VxContext;
+    ///
+    /// let context = VxContext::create().unwrap();
+    /// for info in context.kernels().unwrap() {
+    ///     let kernel = info.resolve(&context).unwrap();
+    ///     println!("{}: {:?}", info.name, kernel.parameters().unwrap());
+    /// }
+    /// ```
+    pub fn kernels(&self) -> Result<std::vec::IntoIter<KernelInfo>> {
+        let count = self.query::<attr::UniqueKernels>()? as usize;
+        if count == 0 {
+            return Ok(Vec::new().into_iter());
+        }
+
+        let mut table: Vec<vx_kernel_info_t> = vec![unsafe { std::mem::zeroed() }; count];
+        let status = unsafe {
+            vxQueryContext(
+                self.raw,
+                vx_context_attribute_e_VX_CONTEXT_UNIQUE_KERNEL_TABLE as vx_enum,
+                table.as_mut_ptr() as *mut c_void,
+                (count * std::mem::size_of::<vx_kernel_info_t>()) as vx_size,
+            )
+        };
+        VxStatus::new_result(status, ())?;
+
+        let kernels: Vec<KernelInfo> = table.into_iter().map(KernelInfo::from).collect();
+        Ok(kernels.into_iter())
     }
 
     /// Enables recording information for graph debugging.
+    ///
+    /// Unless a handler was installed with [`set_log_handler`](#method.set_log_handler),
+    /// log messages are printed to standard error, or forwarded to the `log` crate
+    /// with the `openvx` target if the `log` feature is enabled.
     pub fn enable_logging(&self) -> Result<&Self> {
         self.set_directive(VxDirective::EnableLogging)?;
 
+        if !logging::is_registered(self.raw) {
+            self.set_log_handler(logging::default_handler)?;
+        }
+        Ok(self)
+    }
+
+    /// Disables recording information for graph debugging and removes the log handler.
+    pub fn disable_logging(&self) -> Result<&Self> {
+        self.set_directive(VxDirective::DisableLogging)?;
+        self.clear_log_handler()
+    }
+
+    /// Installs a handler receiving the log messages of this context by calling
+    /// [`vxRegisterLogCallback`], replacing any previously installed handler.
+    ///
+    /// The handler may be invoked from multiple threads simultaneously; use
+    /// [`set_log_handler_with`](#method.set_log_handler_with) to have the implementation
+    /// serialize the calls instead.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use openvx::VxContext;
+    ///
+    /// let context = VxContext::create().unwrap();
+    /// context
+    ///     .set_log_handler(|entry| {
+    ///         eprintln!("{:?} {}: {}", entry.name, entry.status, entry.message);
+    ///     })
+    ///     .unwrap();
+    /// ```
+    ///
+    /// [`vxRegisterLogCallback`]: ../../libopenvx_sys/fn.vxRegisterLogCallback.html
+    pub fn set_log_handler<F>(&self, handler: F) -> Result<&Self>
+    where
+        F: Fn(LogEntry) + Send + Sync + 'static,
+    {
+        self.set_log_handler_with(true, handler)
+    }
+
+    /// Installs a handler receiving the log messages of this context by calling
+    /// [`vxRegisterLogCallback`], replacing any previously installed handler.
+    ///
+    /// # Arguments
+    ///
+    /// * `reentrant` - If `true`, the handler may be invoked from multiple threads
+    ///   simultaneously. If `false`, the implementation serializes the calls.
+    /// * `handler` - The handler to invoke for each log message.
+    ///
+    /// [`vxRegisterLogCallback`]: ../../libopenvx_sys/fn.vxRegisterLogCallback.html
+    pub fn set_log_handler_with<F>(&self, reentrant: bool, handler: F) -> Result<&Self>
+    where
+        F: Fn(LogEntry) + Send + Sync + 'static,
+    {
+        logging::register(self.raw, Arc::new(handler));
+
+        let reentrant = if reentrant {
+            vx_bool_e_vx_true_e
+        } else {
+            vx_bool_e_vx_false_e
+        };
+
         // https://www.khronos.org/registry/OpenVX/specs/1.3/html/OpenVX_Specification_1_3.html#group_log
         unsafe {
             vxRegisterLogCallback(
                 self.as_raw(),
-                Some(log_callback),
-                vx_bool_e_vx_true_e as vx_enum, // TODO: Pass re-entrancy flag to caller
+                Some(logging::log_callback),
+                reentrant as vx_bool,
             );
         }
         Ok(self)
     }
 
-    /// Disables recording information for graph debugging.
-    pub fn disable_logging(&self) -> Result<&Self> {
-        self.set_directive(VxDirective::DisableLogging)?;
-
-        unsafe {
-            vxRegisterLogCallback(self.as_raw(), None, vx_bool_e_vx_false_e as vx_enum);
+    /// Removes the log handler of this context.
+    pub fn clear_log_handler(&self) -> Result<&Self> {
+        if logging::unregister(self.raw) {
+            unsafe {
+                vxRegisterLogCallback(self.as_raw(), None, vx_bool_e_vx_false_e as vx_bool);
+            }
         }
         Ok(self)
     }
@@ -59,38 +244,6 @@ impl VxContext {
     }
 }
 
-#[allow(unused_variables)]
-extern "C" fn log_callback(
-    context: vx_context,
-    r#ref: vx_reference,
-    status: vx_status,
-    string: *const vx_char,
-) {
-    // TODO: Correlate vx_context with context instance for passing state
-
-    debug_assert_ne!(status, vx_status_e_VX_SUCCESS);
-    let status = VxStatus::from(status);
-
-    if string.is_null() {
-        return;
-    }
-
-    let string = unsafe { std::ffi::CStr::from_ptr(string) }
-        .to_string_lossy()
-        .into_owned();
-
-    if string.len() == 0 {
-        return;
-    }
-
-    eprint!("ERROR: {} - {}", status, string);
-
-    let require_linebreak = string.chars().last().unwrap() != '\n';
-    if require_linebreak {
-        eprintln!();
-    }
-}
-
 impl AsRaw for VxContext {
     type Result = vx_context;
 
@@ -100,18 +253,32 @@ impl AsRaw for VxContext {
 }
 
 impl AsVxReference for VxContext {
-    fn as_reference(&self) -> VxReference {
+    fn as_reference(&self) -> VxReference<'_> {
         assert!(!self.raw.is_null());
         VxReference::from(self.raw as vx_reference)
     }
 }
 
+impl QueryAttribute for VxContext {
+    unsafe fn query_raw(&self, attribute: vx_enum, ptr: *mut c_void, size: vx_size) -> vx_status {
+        vxQueryContext(self.raw, attribute, ptr, size)
+    }
+}
+
+impl SetAttribute for VxContext {
+    unsafe fn set_raw(&self, attribute: vx_enum, ptr: *const c_void, size: vx_size) -> vx_status {
+        vxSetContextAttribute(self.raw, attribute, ptr, size)
+    }
+}
+
 impl Release for VxContext {
     fn release(&mut self) -> Result<()> {
         if self.raw.is_null() {
             return Ok(());
         }
 
+        logging::unregister(self.raw);
+
         let status = unsafe { vxReleaseContext(&mut self.raw) };
         self.raw = std::ptr::null_mut();
 
@@ -124,3 +291,16 @@ impl Drop for VxContext {
         self.release().unwrap();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn create_null() {
+        match VxContext::checked(std::ptr::null_mut()) {
+            Err(error) => assert_eq!(error, VxError::NoResources),
+            Ok(_) => panic!("a null context must be rejected"),
+        }
+    }
+}
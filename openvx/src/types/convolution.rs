@@ -1,37 +1,380 @@
-use crate::{AsVxReference, VxReference};
-use libopenvx_sys::vx_convolution;
+use crate::error::check_call;
+use crate::types::reference::retain_reference;
+use crate::{
+    attr, Access, AsRaw, AsVxReference, CheckStatus, MemoryType, QueryAttribute, Release, Result,
+    SetAttribute, VxContext, VxError, VxGraph, VxReference, VxStatus,
+};
+use libopenvx_sys::{
+    vxCopyConvolutionCoefficients, vxCreateConvolution, vxCreateVirtualConvolution,
+    vxQueryConvolution, vxReleaseConvolution, vxSetConvolutionAttribute, vx_convolution, vx_enum,
+    vx_int16, vx_reference, vx_size, vx_status, vx_uint32,
+};
+use std::ffi::c_void;
+use std::marker::PhantomData;
 
 /// The Convolution Object. A user-defined convolution kernel of MxM elements.
-#[derive(Debug, Hash, PartialEq, Eq, Copy, Clone)]
-pub struct VxConvolution {
+///
+/// The coefficients are stored row by row and applied by `vxConvolveNode` as a true
+/// convolution, i.e. mirrored in both directions. The sum of the products is divided by the
+/// scale, which must be a power of two.
+///
+/// The object is bound to the lifetime `'a` of the [`VxContext`](struct.VxContext.html) it was
+/// created in or, for virtual objects, of the [`VxGraph`](struct.VxGraph.html) that owns it.
+///
+/// # Examples
+///
+/// ```no_run
+/// use openvx::{VxContext, VxConvolution};
+///
+/// let context = VxContext::create().unwrap();
+/// let sharpen = VxConvolution::new(&context, 3, 3, &[0, -1, 0, -1, 8, -1, 0, -1, 0], 4).unwrap();
+/// assert_eq!(sharpen.scale().unwrap(), 4);
+///
+/// let blur = VxConvolution::gaussian(&context, 5).unwrap();
+/// assert_eq!(blur.to_vec().unwrap()[12], 36);
+/// ```
+#[derive(Debug, Hash, PartialEq, Eq)]
+pub struct VxConvolution<'a> {
     raw: vx_convolution,
+    _marker: PhantomData<&'a ()>,
 }
 
-impl VxConvolution {
+impl<'a> VxConvolution<'a> {
+    /// Creates a convolution holding `coefficients` by calling [`vxCreateConvolution`].
+    ///
+    /// # Arguments
+    ///
+    /// * `context` - The context in which to create the convolution.
+    /// * `columns` - The columns dimension of the convolution.
+    /// * `rows` - The rows dimension of the convolution.
+    /// * `coefficients` - The coefficients, stored row by row.
+    /// * `scale` - The power of two the sum of the products is divided by.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VxError::InvalidDimension`] if a dimension is even, less than 3 or exceeds
+    /// `VX_CONTEXT_CONVOLUTION_MAX_DIMENSION`, or if `coefficients` does not hold
+    /// `columns * rows` elements, and [`VxError::InvalidValue`] if `scale` is not a power of two.
+    ///
+    /// [`vxCreateConvolution`]: ../../libopenvx_sys/fn.vxCreateConvolution.html
+    /// [`VxError::InvalidDimension`]: ../enum.VxError.html#variant.InvalidDimension
+    /// [`VxError::InvalidValue`]: ../enum.VxError.html#variant.InvalidValue
+    pub fn new(
+        context: &'a VxContext,
+        columns: vx_size,
+        rows: vx_size,
+        coefficients: &[vx_int16],
+        scale: vx_uint32,
+    ) -> Result<VxConvolution<'a>> {
+        check_scale(scale)?;
+        if coefficients.len() != columns * rows {
+            return Err(VxError::InvalidDimension.into());
+        }
+
+        let convolution = Self::create(context, columns, rows)?;
+        convolution.copy_from(coefficients)?;
+        convolution.set_scale(scale)?;
+        Ok(convolution)
+    }
+
+    /// Creates a convolution holding `rows` with a scale of `scale`.
+    ///
+    /// See [`new`](#method.new) for the possible errors.
+    pub fn from_rows<const C: usize, const R: usize>(
+        context: &'a VxContext,
+        rows: &[[vx_int16; C]; R],
+        scale: vx_uint32,
+    ) -> Result<VxConvolution<'a>> {
+        Self::new(context, C, R, rows.as_flattened(), scale)
+    }
+
+    /// Creates the 3x3 Sobel kernel responding positively to values increasing to the right.
+    pub fn sobel_x(context: &'a VxContext) -> Result<VxConvolution<'a>> {
+        Self::from_rows(context, &SOBEL_X, 1)
+    }
+
+    /// Creates the 3x3 Sobel kernel responding positively to values increasing downwards.
+    pub fn sobel_y(context: &'a VxContext) -> Result<VxConvolution<'a>> {
+        Self::from_rows(context, &transpose(SOBEL_X), 1)
+    }
+
+    /// Creates the 3x3 Scharr kernel responding positively to values increasing to the right.
+    pub fn scharr_x(context: &'a VxContext) -> Result<VxConvolution<'a>> {
+        Self::from_rows(context, &SCHARR_X, 1)
+    }
+
+    /// Creates the 3x3 Scharr kernel responding positively to values increasing downwards.
+    pub fn scharr_y(context: &'a VxContext) -> Result<VxConvolution<'a>> {
+        Self::from_rows(context, &transpose(SCHARR_X), 1)
+    }
+
+    /// Creates the 3x3 Laplacian kernel with 4-connectivity.
+    pub fn laplacian(context: &'a VxContext) -> Result<VxConvolution<'a>> {
+        Self::from_rows(context, &[[0, 1, 0], [1, -4, 1], [0, 1, 0]], 1)
+    }
+
+    /// Creates a `size`x`size` box filter with a scale of 1.
+    ///
+    /// The result is the unnormalized sum of the `size * size` pixels, since the scale must be
+    /// a power of two and the area of an odd size never is. `vxConvolveNode` saturates the sum
+    /// to the range of its output image. Use [`vxBox3x3Node`] for the normalized 3x3 mean.
+    ///
+    /// [`vxBox3x3Node`]: ../../libopenvx_sys/fn.vxBox3x3Node.html
+    pub fn box_filter(context: &'a VxContext, size: vx_size) -> Result<VxConvolution<'a>> {
+        Self::new(context, size, size, &vec![1; size * size], 1)
+    }
+
+    /// Creates a normalized `size`x`size` Gaussian filter from binomial coefficients.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VxError::InvalidDimension`] if the coefficients of `size` do not fit into
+    /// 16 bits, i.e. for sizes greater than 9, in addition to the errors of [`new`](#method.new).
+    ///
+    /// [`VxError::InvalidDimension`]: ../enum.VxError.html#variant.InvalidDimension
+    pub fn gaussian(context: &'a VxContext, size: vx_size) -> Result<VxConvolution<'a>> {
+        let (coefficients, scale) = gaussian_coefficients(size)?;
+        Self::new(context, size, size, &coefficients, scale)
+    }
+
+    /// Creates a convolution with unset coefficients by calling [`vxCreateConvolution`] after
+    /// checking the dimensions as described for [`new`](#method.new).
+    ///
+    /// [`vxCreateConvolution`]: ../../libopenvx_sys/fn.vxCreateConvolution.html
+    fn create(
+        context: &'a VxContext,
+        columns: vx_size,
+        rows: vx_size,
+    ) -> Result<VxConvolution<'a>> {
+        let max_dimension = context.query::<attr::ConvolutionMaxDimension>()?;
+        check_dimensions(columns, rows, max_dimension)?;
+
+        let convolution = unsafe { vxCreateConvolution(context.as_raw(), columns, rows) };
+        VxConvolution::from(convolution).into_checked()
+    }
+
+    /// Creates an opaque reference to a convolution matrix object without direct user access
+    /// by calling [`vxCreateVirtualConvolution`].
+    ///
+    /// [`vxCreateVirtualConvolution`]: ../../libopenvx_sys/fn.vxCreateVirtualConvolution.html
+    pub fn virtual_convolution(
+        graph: &'a VxGraph<'_>,
+        columns: vx_size,
+        rows: vx_size,
+    ) -> Result<VxConvolution<'a>> {
+        let convolution = unsafe { vxCreateVirtualConvolution(graph.as_raw(), columns, rows) };
+        VxConvolution::from(convolution).into_checked()
+    }
+
+    /// Returns the columns dimension of the convolution.
+    pub fn columns(&self) -> Result<usize> {
+        self.query::<attr::Columns>()
+    }
+
+    /// Returns the rows dimension of the convolution.
+    pub fn rows(&self) -> Result<usize> {
+        self.query::<attr::Rows>()
+    }
+
+    /// Returns the power of two the sum of the products is divided by.
+    pub fn scale(&self) -> Result<vx_uint32> {
+        self.query::<attr::Scale>()
+    }
+
+    /// Sets the power of two the sum of the products is divided by (`VX_CONVOLUTION_SCALE`).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VxError::InvalidValue`] if `scale` is not a power of two.
+    ///
+    /// [`VxError::InvalidValue`]: ../enum.VxError.html#variant.InvalidValue
+    pub fn set_scale(&self, scale: vx_uint32) -> Result<()> {
+        check_scale(scale)?;
+        self.set::<attr::Scale>(scale)
+    }
+
+    /// Writes all coefficients, stored row by row, by calling [`vxCopyConvolutionCoefficients`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VxError::InvalidDimension`] if `coefficients` does not hold all coefficients.
+    ///
+    /// [`vxCopyConvolutionCoefficients`]: ../../libopenvx_sys/fn.vxCopyConvolutionCoefficients.html
+    /// [`VxError::InvalidDimension`]: ../enum.VxError.html#variant.InvalidDimension
+    pub fn copy_from(&self, coefficients: &[vx_int16]) -> Result<()> {
+        self.check_len(coefficients.len())?;
+        self.copy(coefficients.as_ptr() as *mut c_void, Access::Write)
+    }
+
+    /// Reads all coefficients, stored row by row, by calling [`vxCopyConvolutionCoefficients`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VxError::InvalidDimension`] if `coefficients` does not hold all coefficients.
+    ///
+    /// [`vxCopyConvolutionCoefficients`]: ../../libopenvx_sys/fn.vxCopyConvolutionCoefficients.html
+    /// [`VxError::InvalidDimension`]: ../enum.VxError.html#variant.InvalidDimension
+    pub fn copy_to(&self, coefficients: &mut [vx_int16]) -> Result<()> {
+        self.check_len(coefficients.len())?;
+        self.copy(coefficients.as_mut_ptr() as *mut c_void, Access::Read)
+    }
+
+    /// Reads all coefficients, stored row by row, into a vector by calling
+    /// [`vxCopyConvolutionCoefficients`].
+    ///
+    /// [`vxCopyConvolutionCoefficients`]: ../../libopenvx_sys/fn.vxCopyConvolutionCoefficients.html
+    pub fn to_vec(&self) -> Result<Vec<vx_int16>> {
+        let mut coefficients = vec![0; self.columns()? * self.rows()?];
+        self.copy_to(&mut coefficients)?;
+        Ok(coefficients)
+    }
+
+    /// Checks that the convolution holds `len` coefficients.
+    fn check_len(&self, len: usize) -> Result<()> {
+        if len != self.columns()? * self.rows()? {
+            return Err(VxError::InvalidDimension.into());
+        }
+        Ok(())
+    }
+
+    fn copy(&self, ptr: *mut c_void, access: Access) -> Result<()> {
+        check_call("vxCopyConvolutionCoefficients", self, || unsafe {
+            vxCopyConvolutionCoefficients(
+                self.raw,
+                ptr,
+                access.to_raw() as vx_enum,
+                MemoryType::Host.to_raw() as vx_enum,
+            )
+        })
+    }
+
     #[allow(dead_code)]
     pub fn is_null(&self) -> bool {
         self.raw.is_null()
     }
 }
 
-impl AsVxReference for VxConvolution {
-    fn as_reference(&self) -> VxReference {
+impl<'a> AsRaw for VxConvolution<'a> {
+    type Result = vx_convolution;
+
+    fn as_raw(&self) -> Self::Result {
+        self.raw
+    }
+}
+
+impl<'a> AsVxReference for VxConvolution<'a> {
+    fn as_reference(&self) -> VxReference<'_> {
         VxReference::from(self.raw)
     }
 }
 
-impl From<vx_convolution> for VxConvolution {
+impl QueryAttribute for VxConvolution<'_> {
+    unsafe fn query_raw(&self, attribute: vx_enum, ptr: *mut c_void, size: vx_size) -> vx_status {
+        vxQueryConvolution(self.raw, attribute, ptr, size)
+    }
+}
+
+impl SetAttribute for VxConvolution<'_> {
+    unsafe fn set_raw(&self, attribute: vx_enum, ptr: *const c_void, size: vx_size) -> vx_status {
+        vxSetConvolutionAttribute(self.raw, attribute, ptr, size)
+    }
+}
+
+impl<'a> Release for VxConvolution<'a> {
+    fn release(&mut self) -> Result<()> {
+        if self.raw.is_null() {
+            return Ok(());
+        }
+
+        let status = unsafe { vxReleaseConvolution(&mut self.raw) };
+        self.raw = std::ptr::null_mut();
+
+        VxStatus::new_result(status, ())
+    }
+}
+
+impl<'a> Drop for VxConvolution<'a> {
+    fn drop(&mut self) {
+        self.release().unwrap();
+    }
+}
+
+impl<'a> Clone for VxConvolution<'a> {
+    /// Creates a new handle to the same object, incrementing its reference count
+    /// by calling [`vxRetainReference`](../../libopenvx_sys/fn.vxRetainReference.html).
+    fn clone(&self) -> Self {
+        retain_reference(self.raw as vx_reference);
+        VxConvolution {
+            raw: self.raw,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'a> From<vx_convolution> for VxConvolution<'a> {
+    /// Takes ownership of the reference; it will be released when the wrapper is dropped.
     fn from(value: vx_convolution) -> Self {
-        VxConvolution { raw: value }
+        VxConvolution {
+            raw: value,
+            _marker: PhantomData,
+        }
     }
 }
 
-impl Into<vx_convolution> for VxConvolution {
+impl<'a> Into<vx_convolution> for VxConvolution<'a> {
+    /// Gives up ownership of the reference; the caller becomes responsible for releasing it.
     fn into(self) -> vx_convolution {
-        return self.raw;
+        let raw = self.raw;
+        std::mem::forget(self);
+        raw
     }
 }
 
+const SOBEL_X: [[vx_int16; 3]; 3] = [[1, 0, -1], [2, 0, -2], [1, 0, -1]];
+const SCHARR_X: [[vx_int16; 3]; 3] = [[3, 0, -3], [10, 0, -10], [3, 0, -3]];
+
+fn transpose(kernel: [[vx_int16; 3]; 3]) -> [[vx_int16; 3]; 3] {
+    std::array::from_fn(|row| std::array::from_fn(|column| kernel[column][row]))
+}
+
+/// Checks that both dimensions are odd, at least 3 and at most `max_dimension`.
+fn check_dimensions(columns: vx_size, rows: vx_size, max_dimension: vx_size) -> Result<()> {
+    let valid = |size: vx_size| size % 2 == 1 && size >= 3 && size <= max_dimension;
+    if !valid(columns) || !valid(rows) {
+        return Err(VxError::InvalidDimension.into());
+    }
+    Ok(())
+}
+
+fn check_scale(scale: vx_uint32) -> Result<()> {
+    if !scale.is_power_of_two() {
+        return Err(VxError::InvalidValue.into());
+    }
+    Ok(())
+}
+
+/// Computes the outer product of the binomial coefficients of `size` and its sum.
+fn gaussian_coefficients(size: vx_size) -> Result<(Vec<vx_int16>, vx_uint32)> {
+    if size == 0 || size > 9 {
+        return Err(VxError::InvalidDimension.into());
+    }
+
+    let mut binomial = vec![1u32; size];
+    for k in 1..size {
+        binomial[k] = binomial[k - 1] * (size - k) as u32 / k as u32;
+    }
+
+    let coefficients = binomial
+        .iter()
+        .flat_map(|&row| {
+            binomial
+                .iter()
+                .map(move |&column| (row * column) as vx_int16)
+        })
+        .collect();
+    Ok((coefficients, 1 << (2 * (size - 1))))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -40,4 +383,35 @@ mod tests {
     fn is_null() {
         assert!(VxConvolution::from(std::ptr::null_mut()).is_null());
     }
+
+    #[test]
+    fn validation() {
+        assert!(check_dimensions(3, 5, 9).is_ok());
+        assert!(check_dimensions(9, 9, 9).is_ok());
+        assert!(check_dimensions(1, 3, 9).is_err());
+        assert!(check_dimensions(4, 3, 9).is_err());
+        assert!(check_dimensions(3, 11, 9).is_err());
+
+        assert!(check_scale(1).is_ok());
+        assert!(check_scale(16).is_ok());
+        assert!(check_scale(0).is_err());
+        assert!(check_scale(9).is_err());
+    }
+
+    #[test]
+    fn presets() {
+        assert_eq!(transpose(SOBEL_X), [[1, 2, 1], [0, 0, 0], [-1, -2, -1]]);
+
+        let (coefficients, scale) = gaussian_coefficients(3).unwrap();
+        assert_eq!(coefficients, [1, 2, 1, 2, 4, 2, 1, 2, 1]);
+        assert_eq!(scale, 16);
+
+        for size in [3, 5, 7, 9] {
+            let (coefficients, scale) = gaussian_coefficients(size).unwrap();
+            let sum: i32 = coefficients.iter().map(|&c| c as i32).sum();
+            assert_eq!(sum as u32, scale);
+            assert!(coefficients.iter().all(|&c| c > 0));
+        }
+        assert!(gaussian_coefficients(11).is_err());
+    }
 }
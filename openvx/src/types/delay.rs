@@ -1,35 +1,120 @@
-use crate::{AsVxReference, VxReference};
-use libopenvx_sys::vx_delay;
+use crate::types::reference::retain_reference;
+use crate::{
+    AsRaw, AsVxReference, CheckStatus, QueryAttribute, Release, Result, VxContext, VxReference,
+    VxStatus,
+};
+use libopenvx_sys::{
+    vxCreateDelay, vxQueryDelay, vxReleaseDelay, vx_delay, vx_enum, vx_reference, vx_size,
+    vx_status,
+};
+use std::ffi::c_void;
+use std::marker::PhantomData;
 
 /// The delay object. This is like a ring buffer of objects that is
 /// maintained by the OpenVX implementation.
-#[derive(Debug, Hash, PartialEq, Eq, Copy, Clone)]
-pub struct VxDelay {
+///
+/// The object is bound to the lifetime `'a` of the [`VxContext`](struct.VxContext.html) it was
+/// created in or, for virtual objects, of the [`VxGraph`](struct.VxGraph.html) that owns it.
+#[derive(Debug, Hash, PartialEq, Eq)]
+pub struct VxDelay<'a> {
     raw: vx_delay,
+    _marker: PhantomData<&'a ()>,
 }
 
-impl VxDelay {
+impl<'a> VxDelay<'a> {
+    /// Creates a delay object by calling [`vxCreateDelay`].
+    ///
+    /// # Arguments
+    ///
+    /// * `context` - The context in which to create the delay.
+    /// * `exemplar` - The exemplar object. Supported exemplar object types are arrays,
+    ///   convolutions, distributions, images, LUTs, matrices, pyramids, remaps, scalars,
+    ///   thresholds and tensors.
+    /// * `slots` - The number of objects in the delay. This value must be greater than zero.
+    ///
+    /// [`vxCreateDelay`]: ../../libopenvx_sys/fn.vxCreateDelay.html
+    pub fn new<E>(context: &'a VxContext, exemplar: &E, slots: vx_size) -> Result<VxDelay<'a>>
+    where
+        E: AsVxReference,
+    {
+        let delay =
+            unsafe { vxCreateDelay(context.as_raw(), exemplar.as_reference().into(), slots) };
+        VxDelay::from(delay).into_checked()
+    }
+
     #[allow(dead_code)]
     pub fn is_null(&self) -> bool {
         self.raw.is_null()
     }
 }
 
-impl AsVxReference for VxDelay {
-    fn as_reference(&self) -> VxReference {
+impl<'a> AsRaw for VxDelay<'a> {
+    type Result = vx_delay;
+
+    fn as_raw(&self) -> Self::Result {
+        self.raw
+    }
+}
+
+impl<'a> AsVxReference for VxDelay<'a> {
+    fn as_reference(&self) -> VxReference<'_> {
         VxReference::from(self.raw)
     }
 }
 
-impl From<vx_delay> for VxDelay {
+impl QueryAttribute for VxDelay<'_> {
+    unsafe fn query_raw(&self, attribute: vx_enum, ptr: *mut c_void, size: vx_size) -> vx_status {
+        vxQueryDelay(self.raw, attribute, ptr, size)
+    }
+}
+
+impl<'a> Release for VxDelay<'a> {
+    fn release(&mut self) -> Result<()> {
+        if self.raw.is_null() {
+            return Ok(());
+        }
+
+        let status = unsafe { vxReleaseDelay(&mut self.raw) };
+        self.raw = std::ptr::null_mut();
+
+        VxStatus::new_result(status, ())
+    }
+}
+
+impl<'a> Drop for VxDelay<'a> {
+    fn drop(&mut self) {
+        self.release().unwrap();
+    }
+}
+
+impl<'a> Clone for VxDelay<'a> {
+    /// Creates a new handle to the same object, incrementing its reference count
+    /// by calling [`vxRetainReference`](../../libopenvx_sys/fn.vxRetainReference.html).
+    fn clone(&self) -> Self {
+        retain_reference(self.raw as vx_reference);
+        VxDelay {
+            raw: self.raw,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'a> From<vx_delay> for VxDelay<'a> {
+    /// Takes ownership of the reference; it will be released when the wrapper is dropped.
     fn from(value: vx_delay) -> Self {
-        VxDelay { raw: value }
+        VxDelay {
+            raw: value,
+            _marker: PhantomData,
+        }
     }
 }
 
-impl Into<vx_delay> for VxDelay {
+impl<'a> Into<vx_delay> for VxDelay<'a> {
+    /// Gives up ownership of the reference; the caller becomes responsible for releasing it.
     fn into(self) -> vx_delay {
-        return self.raw;
+        let raw = self.raw;
+        std::mem::forget(self);
+        raw
     }
 }
 
@@ -1,35 +1,135 @@
-use crate::{AsVxReference, VxReference};
-use libopenvx_sys::vx_distribution;
+use crate::types::reference::retain_reference;
+use crate::{
+    AsRaw, AsVxReference, CheckStatus, QueryAttribute, Release, Result, VxContext, VxGraph,
+    VxReference, VxStatus,
+};
+use libopenvx_sys::{
+    vxCreateDistribution, vxCreateVirtualDistribution, vxQueryDistribution, vxReleaseDistribution,
+    vx_distribution, vx_enum, vx_int32, vx_reference, vx_size, vx_status, vx_uint32,
+};
+use std::ffi::c_void;
+use std::marker::PhantomData;
 
 /// The Distribution object. This has a user-defined number of bins over
 /// a user-defined range (within a uint32_t range).
-#[derive(Debug, Hash, PartialEq, Eq, Copy, Clone)]
-pub struct VxDistribution {
+///
+/// The object is bound to the lifetime `'a` of the [`VxContext`](struct.VxContext.html) it was
+/// created in or, for virtual objects, of the [`VxGraph`](struct.VxGraph.html) that owns it.
+#[derive(Debug, Hash, PartialEq, Eq)]
+pub struct VxDistribution<'a> {
     raw: vx_distribution,
+    _marker: PhantomData<&'a ()>,
 }
 
-impl VxDistribution {
+impl<'a> VxDistribution<'a> {
+    /// Creates a reference to a 1D distribution by calling [`vxCreateDistribution`].
+    ///
+    /// # Arguments
+    ///
+    /// * `context` - The context in which to create the distribution.
+    /// * `bins` - The number of bins in the distribution.
+    /// * `offset` - The start offset into the range value that marks the beginning of the 1D distribution.
+    /// * `range` - The total number of the consecutive values of the distribution interval.
+    ///
+    /// [`vxCreateDistribution`]: ../../libopenvx_sys/fn.vxCreateDistribution.html
+    pub fn new(
+        context: &'a VxContext,
+        bins: vx_size,
+        offset: vx_int32,
+        range: vx_uint32,
+    ) -> Result<VxDistribution<'a>> {
+        let distribution = unsafe { vxCreateDistribution(context.as_raw(), bins, offset, range) };
+        VxDistribution::from(distribution).into_checked()
+    }
+
+    /// Creates an opaque reference to a 1D distribution object without direct user access
+    /// by calling [`vxCreateVirtualDistribution`].
+    ///
+    /// [`vxCreateVirtualDistribution`]: ../../libopenvx_sys/fn.vxCreateVirtualDistribution.html
+    pub fn virtual_distribution(
+        graph: &'a VxGraph<'_>,
+        bins: vx_size,
+        offset: vx_int32,
+        range: vx_uint32,
+    ) -> Result<VxDistribution<'a>> {
+        let distribution =
+            unsafe { vxCreateVirtualDistribution(graph.as_raw(), bins, offset, range) };
+        VxDistribution::from(distribution).into_checked()
+    }
+
     #[allow(dead_code)]
     pub fn is_null(&self) -> bool {
         self.raw.is_null()
     }
 }
 
-impl AsVxReference for VxDistribution {
-    fn as_reference(&self) -> VxReference {
+impl<'a> AsRaw for VxDistribution<'a> {
+    type Result = vx_distribution;
+
+    fn as_raw(&self) -> Self::Result {
+        self.raw
+    }
+}
+
+impl<'a> AsVxReference for VxDistribution<'a> {
+    fn as_reference(&self) -> VxReference<'_> {
         VxReference::from(self.raw)
     }
 }
 
-impl From<vx_distribution> for VxDistribution {
+impl QueryAttribute for VxDistribution<'_> {
+    unsafe fn query_raw(&self, attribute: vx_enum, ptr: *mut c_void, size: vx_size) -> vx_status {
+        vxQueryDistribution(self.raw, attribute, ptr, size)
+    }
+}
+
+impl<'a> Release for VxDistribution<'a> {
+    fn release(&mut self) -> Result<()> {
+        if self.raw.is_null() {
+            return Ok(());
+        }
+
+        let status = unsafe { vxReleaseDistribution(&mut self.raw) };
+        self.raw = std::ptr::null_mut();
+
+        VxStatus::new_result(status, ())
+    }
+}
+
+impl<'a> Drop for VxDistribution<'a> {
+    fn drop(&mut self) {
+        self.release().unwrap();
+    }
+}
+
+impl<'a> Clone for VxDistribution<'a> {
+    /// Creates a new handle to the same object, incrementing its reference count
+    /// by calling [`vxRetainReference`](../../libopenvx_sys/fn.vxRetainReference.html).
+    fn clone(&self) -> Self {
+        retain_reference(self.raw as vx_reference);
+        VxDistribution {
+            raw: self.raw,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'a> From<vx_distribution> for VxDistribution<'a> {
+    /// Takes ownership of the reference; it will be released when the wrapper is dropped.
     fn from(value: vx_distribution) -> Self {
-        VxDistribution { raw: value }
+        VxDistribution {
+            raw: value,
+            _marker: PhantomData,
+        }
     }
 }
 
-impl Into<vx_distribution> for VxDistribution {
+impl<'a> Into<vx_distribution> for VxDistribution<'a> {
+    /// Gives up ownership of the reference; the caller becomes responsible for releasing it.
     fn into(self) -> vx_distribution {
-        return self.raw;
+        let raw = self.raw;
+        std::mem::forget(self);
+        raw
     }
 }
 
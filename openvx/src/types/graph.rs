@@ -1,117 +1,148 @@
-use crate::performance::Performance;
+use crate::error::check_call;
+use crate::types::reference::retain_reference;
 use crate::{
-    AsRaw, AsVxReference, Release, Result, VxContext, VxGraphState, VxReference, VxStatus,
+    AsRaw, AsVxReference, CheckStatus, QueryAttribute, Release, Result, SetAttribute, VxContext,
+    VxReference, VxStatus,
 };
 use libopenvx_sys::{
-    vxCreateGraph, vxQueryGraph, vxReleaseGraph, vx_enum, vx_graph,
-    vx_graph_attribute_e_VX_GRAPH_NUMNODES, vx_graph_attribute_e_VX_GRAPH_NUMPARAMETERS,
-    vx_graph_attribute_e_VX_GRAPH_PERFORMANCE, vx_graph_attribute_e_VX_GRAPH_STATE,
-    vx_graph_state_e, vx_perf_t, vx_size, vx_uint32,
+    vxCreateGraph, vxProcessGraph, vxQueryGraph, vxReleaseGraph, vxSetGraphAttribute,
+    vxVerifyGraph, vx_enum, vx_graph, vx_reference, vx_size, vx_status,
 };
+use std::ffi::c_void;
+use std::marker::PhantomData;
 
 /// An opaque reference to a graph.
+///
+/// The graph is bound to the lifetime `'ctx` of the [`VxContext`](struct.VxContext.html) it was
+/// created in, so the context cannot be released while the graph is still alive.
+///
+/// ```compile_fail
+/// use openvx::{VxContext, VxGraph};
+///
+/// let graph = {
+///     let context = VxContext::create().unwrap();
+///     VxGraph::create(&context).unwrap()
+/// };
+/// ```
 #[derive(Debug, Hash, PartialEq, Eq)]
-pub struct VxGraph {
+pub struct VxGraph<'ctx> {
     raw: vx_graph,
+    _marker: PhantomData<&'ctx VxContext>,
 }
 
-impl VxGraph {
+impl<'ctx> VxGraph<'ctx> {
     /// Creates a new [`VxGraph`] by calling [`vxCreateGraph`].
     ///
+    /// # Errors
+    ///
+    /// Returns the error reported by [`vxGetStatus`] if the graph could not be created.
+    ///
     /// [`VxGraph`]: struct.VxGraph.html
     /// [`vxCreateGraph`]: ../../libopenvx_sys/fn.vxCreateGraph.html
-    pub fn create(context: &VxContext) -> VxGraph {
+    /// [`vxGetStatus`]: ../../libopenvx_sys/fn.vxGetStatus.html
+    pub fn create(context: &'ctx VxContext) -> Result<VxGraph<'ctx>> {
         let graph = unsafe { vxCreateGraph(context.as_raw()) };
-        VxGraph::from(graph)
+        VxGraph::from(graph).into_checked()
     }
 
-    pub fn as_raw(&self) -> vx_graph {
-        self.raw
-    }
+    /// Verifies the state of the graph before it is executed by calling [`vxVerifyGraph`].
+    ///
+    /// With the `tracing` feature enabled, the call is wrapped in a `vxVerifyGraph` span.
+    ///
+    /// # Errors
+    ///
+    /// If the graph is invalid, the returned [`Error`](struct.Error.html) carries the messages
+    /// the implementation logged during verification, given that logging is enabled.
+    ///
+    /// ```no_run
+    /// use openvx::{SetName, VxContext, VxGraph};
+    ///
+    /// let context = VxContext::create().unwrap();
+    /// context.enable_logging().unwrap();
+    ///
+    /// let graph = VxGraph::create(&context).unwrap();
+    /// graph.set_name("CANNY_GRAPH");
+    ///
+    /// if let Err(error) = graph.verify() {
+    ///     // e.g. "vxVerifyGraph on graph 'CANNY_GRAPH': InvalidFormat: ..."
+    ///     eprintln!("{}", error);
+    /// }
+    /// ```
+    ///
+    /// [`vxVerifyGraph`]: ../../libopenvx_sys/fn.vxVerifyGraph.html
+    pub fn verify(&self) -> Result<()> {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::info_span!("vxVerifyGraph", graph = %self.trace_name()).entered();
 
-    #[allow(dead_code)]
-    pub fn is_null(&self) -> bool {
-        self.raw.is_null()
+        check_call("vxVerifyGraph", self, || unsafe { vxVerifyGraph(self.raw) })
     }
 
-    /// Returns the number of nodes in the graph.
-    pub fn get_num_nodes(&self) -> usize {
-        let mut num_nodes: vx_uint32 = 0;
-
-        unsafe {
-            vxQueryGraph(
-                self.raw,
-                vx_graph_attribute_e_VX_GRAPH_NUMNODES as vx_enum,
-                &mut num_nodes as *mut _ as *mut std::ffi::c_void,
-                std::mem::size_of_val(&num_nodes) as vx_size,
-            );
+    /// Executes the graph synchronously by calling [`vxProcessGraph`].
+    ///
+    /// With the `tracing` feature enabled, the call is wrapped in a `vxProcessGraph` span
+    /// carrying the graph's performance measurements as `perf.*` fields. Nodes registered
+    /// with [`VxNode::trace_execution`](struct.VxNode.html#method.trace_execution) emit
+    /// their completion spans within it.
+    ///
+    /// [`vxProcessGraph`]: ../../libopenvx_sys/fn.vxProcessGraph.html
+    pub fn process(&self) -> Result<()> {
+        #[cfg(feature = "tracing")]
+        let span = tracing::info_span!(
+            "vxProcessGraph",
+            graph = %self.trace_name(),
+            perf.tmp_ns = tracing::field::Empty,
+            perf.avg_ns = tracing::field::Empty,
+            perf.min_ns = tracing::field::Empty,
+            perf.max_ns = tracing::field::Empty,
+            perf.num = tracing::field::Empty,
+        )
+        .entered();
+
+        let result = check_call("vxProcessGraph", self, || unsafe {
+            vxProcessGraph(self.raw)
+        });
+
+        #[cfg(feature = "tracing")]
+        if let Ok(performance) = self.query::<crate::attr::Performance>() {
+            performance.record(&span);
         }
 
-        num_nodes as usize
+        result
     }
 
-    /// Returns the number of explicitly declared parameters on the graph.
-    pub fn get_num_parameters(&self) -> usize {
-        let mut num_params: vx_uint32 = 0;
-
-        unsafe {
-            vxQueryGraph(
-                self.raw,
-                vx_graph_attribute_e_VX_GRAPH_NUMPARAMETERS as vx_enum,
-                &mut num_params as *mut _ as *mut std::ffi::c_void,
-                std::mem::size_of_val(&num_params) as vx_size,
-            );
-        }
+    #[cfg(feature = "tracing")]
+    fn trace_name(&self) -> String {
+        use crate::GetName;
+        self.get_name().ok().flatten().unwrap_or_default()
+    }
 
-        num_params as usize
+    #[allow(dead_code)]
+    pub fn is_null(&self) -> bool {
+        self.raw.is_null()
     }
+}
 
-    /// Returns the state of the graph.
-    pub fn get_state(&self) -> VxGraphState {
-        let mut state: vx_graph_state_e = 0 as vx_graph_state_e;
-
-        unsafe {
-            vxQueryGraph(
-                self.raw,
-                vx_graph_attribute_e_VX_GRAPH_STATE as vx_enum,
-                &mut state as *mut _ as *mut std::ffi::c_void,
-                std::mem::size_of_val(&state) as vx_size,
-            );
-        }
+impl AsRaw for VxGraph<'_> {
+    type Result = vx_graph;
 
-        VxGraphState::from(state)
+    fn as_raw(&self) -> Self::Result {
+        self.raw
     }
+}
 
-    /// Returns the overall performance of the graph.
-    ///
-    /// The accuracy of timing information is platform dependent.
-    /// Performance tracking must have been enabled through [`VxContext.enable_performance_counters`](struct.VxContext.html#method.enable_performance_counters).
-    pub fn get_performance(&self) -> Performance {
-        let mut perf = vx_perf_t {
-            min: 0,
-            max: 0,
-            beg: 0,
-            end: 0,
-            num: 0,
-            sum: 0,
-            avg: 0,
-            tmp: 0,
-        };
-
-        unsafe {
-            vxQueryGraph(
-                self.raw,
-                vx_graph_attribute_e_VX_GRAPH_PERFORMANCE as vx_enum,
-                &mut perf as *mut _ as *mut std::ffi::c_void,
-                std::mem::size_of_val(&perf) as vx_size,
-            );
-        }
+impl QueryAttribute for VxGraph<'_> {
+    unsafe fn query_raw(&self, attribute: vx_enum, ptr: *mut c_void, size: vx_size) -> vx_status {
+        vxQueryGraph(self.raw, attribute, ptr, size)
+    }
+}
 
-        Performance::from(perf)
+impl SetAttribute for VxGraph<'_> {
+    unsafe fn set_raw(&self, attribute: vx_enum, ptr: *const c_void, size: vx_size) -> vx_status {
+        vxSetGraphAttribute(self.raw, attribute, ptr, size)
     }
 }
 
-impl Release for VxGraph {
+impl Release for VxGraph<'_> {
     fn release(&mut self) -> Result<()> {
         if self.raw.is_null() {
             return Ok(());
@@ -124,27 +155,46 @@ impl Release for VxGraph {
     }
 }
 
-impl Drop for VxGraph {
+impl Drop for VxGraph<'_> {
     fn drop(&mut self) {
         self.release().unwrap();
     }
 }
 
-impl AsVxReference for VxGraph {
-    fn as_reference(&self) -> VxReference {
+impl Clone for VxGraph<'_> {
+    /// Creates a new handle to the same graph, incrementing its reference count
+    /// by calling [`vxRetainReference`](../../libopenvx_sys/fn.vxRetainReference.html).
+    fn clone(&self) -> Self {
+        retain_reference(self.raw as vx_reference);
+        VxGraph {
+            raw: self.raw,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl AsVxReference for VxGraph<'_> {
+    fn as_reference(&self) -> VxReference<'_> {
         VxReference::from(self.raw)
     }
 }
 
-impl From<vx_graph> for VxGraph {
+impl From<vx_graph> for VxGraph<'_> {
+    /// Takes ownership of the reference; it will be released when the wrapper is dropped.
     fn from(value: vx_graph) -> Self {
-        VxGraph { raw: value }
+        VxGraph {
+            raw: value,
+            _marker: PhantomData,
+        }
     }
 }
 
-impl Into<vx_graph> for VxGraph {
+impl Into<vx_graph> for VxGraph<'_> {
+    /// Gives up ownership of the reference; the caller becomes responsible for releasing it.
     fn into(self) -> vx_graph {
-        return self.raw;
+        let raw = self.raw;
+        std::mem::forget(self);
+        raw
     }
 }
 
@@ -0,0 +1,225 @@
+use crate::error::check_call;
+use crate::{
+    AsRaw, CheckStatus, HostBuffer, ImageAddressing, ImageType, MemoryType, Release, Result,
+    VxContext, VxError, VxImage,
+};
+use libopenvx_sys::{
+    vxCreateImageFromHandle, vxSwapImageHandle, vx_enum, vx_imagepatch_addressing_t, vx_uint32,
+};
+use std::ffi::c_void;
+use std::ops::Deref;
+
+/// An image backed by host buffers it owns.
+///
+/// The buffers are attached to the image by calling [`vxCreateImageFromHandle`] and can be
+/// exchanged without copying by calling [`swap_handles`](#method.swap_handles), e.g. to
+/// ping-pong between buffers written by a camera and a verified graph reading them.
+///
+/// The wrapper dereferences to the [`VxImage`](struct.VxImage.html), so it can be passed to
+/// nodes directly. Before the buffers are dropped or handed out, they are detached from the
+/// image; other references to the image then no longer have access to any memory.
+///
+/// # Examples
+///
+/// ```no_run
+/// use openvx::{ImageType, VxContext, VxHostImage};
+///
+/// let context = VxContext::create().unwrap();
+/// let size = ImageType::U8.buffer_size(640, 480).unwrap();
+/// let mut image =
+///     VxHostImage::new(&context, 640, 480, ImageType::U8, vec![vec![0u8; size]]).unwrap();
+///
+/// // Attach a freshly filled buffer and take back the previous one.
+/// let previous = image.swap_handles(vec![vec![0xFFu8; size]]).unwrap();
+/// assert_eq!(previous[0].len(), size);
+/// ```
+///
+/// [`vxCreateImageFromHandle`]: ../../libopenvx_sys/fn.vxCreateImageFromHandle.html
+#[derive(Debug)]
+pub struct VxHostImage<'a, B: HostBuffer> {
+    image: VxImage<'a>,
+    addressing: Vec<ImageAddressing>,
+    buffers: Vec<B>,
+}
+
+impl<'a, B: HostBuffer> VxHostImage<'a, B> {
+    /// Creates an image from one tightly packed buffer per plane of `format`; see
+    /// [`ImageType::plane_size`](../enum.ImageType.html#method.plane_size) for the sizes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VxError::InvalidFormat`] if the layout of `format` is unknown,
+    /// [`VxError::InvalidParameters`] if the number of buffers does not match the number of
+    /// planes and [`VxError::InvalidDimension`] if a buffer is too small.
+    ///
+    /// [`VxError::InvalidFormat`]: ../enum.VxError.html#variant.InvalidFormat
+    /// [`VxError::InvalidParameters`]: ../enum.VxError.html#variant.InvalidParameters
+    /// [`VxError::InvalidDimension`]: ../enum.VxError.html#variant.InvalidDimension
+    pub fn new(
+        context: &'a VxContext,
+        width: vx_uint32,
+        height: vx_uint32,
+        format: ImageType,
+        mut buffers: Vec<B>,
+    ) -> Result<Self> {
+        let addressing = plane_addressing(format, width, height)?;
+        check_buffers(&addressing, &buffers)?;
+
+        let raw_addressing: Vec<vx_imagepatch_addressing_t> =
+            addressing.iter().map(ImageAddressing::to_raw).collect();
+        let pointers = pointers(&mut buffers);
+        let image = unsafe {
+            vxCreateImageFromHandle(
+                context.as_raw(),
+                format.to_raw(),
+                raw_addressing.as_ptr(),
+                pointers.as_ptr(),
+                MemoryType::Host.to_raw() as vx_enum,
+            )
+        };
+
+        Ok(Self {
+            image: VxImage::from(image).into_checked()?,
+            addressing,
+            buffers,
+        })
+    }
+
+    /// Returns the image the buffers are attached to.
+    pub fn image(&self) -> &VxImage<'a> {
+        &self.image
+    }
+
+    /// Attaches `buffers` to the image by calling [`vxSwapImageHandle`] and returns the
+    /// previously attached buffers.
+    ///
+    /// The image must not be in use by a graph that is being executed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VxError::InvalidParameters`] if the number of buffers does not match the
+    /// number of planes and [`VxError::InvalidDimension`] if a buffer is too small. In case of
+    /// an error, the previous buffers stay attached.
+    ///
+    /// [`vxSwapImageHandle`]: ../../libopenvx_sys/fn.vxSwapImageHandle.html
+    /// [`VxError::InvalidParameters`]: ../enum.VxError.html#variant.InvalidParameters
+    /// [`VxError::InvalidDimension`]: ../enum.VxError.html#variant.InvalidDimension
+    pub fn swap_handles(&mut self, mut buffers: Vec<B>) -> Result<Vec<B>> {
+        check_buffers(&self.addressing, &buffers)?;
+
+        let pointers = pointers(&mut buffers);
+        self.swap(pointers.as_ptr())?;
+        Ok(std::mem::replace(&mut self.buffers, buffers))
+    }
+
+    /// Detaches the buffers from the image and returns them.
+    pub fn into_buffers(mut self) -> Result<Vec<B>> {
+        self.detach()?;
+        Ok(std::mem::take(&mut self.buffers))
+    }
+
+    /// Reclaims the attached buffers from the implementation, leaving the image without memory.
+    fn detach(&mut self) -> Result<()> {
+        if self.buffers.is_empty() {
+            return Ok(());
+        }
+        self.swap(std::ptr::null())
+    }
+
+    fn swap(&self, pointers: *const *mut c_void) -> Result<()> {
+        check_call("vxSwapImageHandle", &self.image, || unsafe {
+            vxSwapImageHandle(
+                self.image.as_raw(),
+                pointers,
+                std::ptr::null_mut(),
+                self.addressing.len(),
+            )
+        })
+    }
+}
+
+/// Returns the addressing of the tightly packed planes of an image.
+fn plane_addressing(
+    format: ImageType,
+    width: vx_uint32,
+    height: vx_uint32,
+) -> Result<Vec<ImageAddressing>> {
+    let planes = format.planes().ok_or(VxError::InvalidFormat)?;
+    (0..planes)
+        .map(|plane| {
+            format
+                .plane_addressing(plane, width, height)
+                .ok_or_else(|| VxError::InvalidFormat.into())
+        })
+        .collect()
+}
+
+/// Checks that there is one buffer per plane and that each covers its addressing.
+fn check_buffers<B: HostBuffer>(addressing: &[ImageAddressing], buffers: &[B]) -> Result<()> {
+    if addressing.len() != buffers.len() {
+        return Err(VxError::InvalidParameters.into());
+    }
+    if addressing
+        .iter()
+        .zip(buffers)
+        .any(|(addressing, buffer)| buffer.len() < addressing.buffer_size())
+    {
+        return Err(VxError::InvalidDimension.into());
+    }
+    Ok(())
+}
+
+fn pointers<B: HostBuffer>(buffers: &mut [B]) -> Vec<*mut c_void> {
+    buffers
+        .iter_mut()
+        .map(|buffer| buffer.as_mut_ptr() as *mut c_void)
+        .collect()
+}
+
+impl<'a, B: HostBuffer> Deref for VxHostImage<'a, B> {
+    type Target = VxImage<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.image
+    }
+}
+
+impl<B: HostBuffer> Release for VxHostImage<'_, B> {
+    fn release(&mut self) -> Result<()> {
+        self.detach()?;
+        self.buffers.clear();
+        self.image.release()
+    }
+}
+
+impl<B: HostBuffer> Drop for VxHostImage<'_, B> {
+    fn drop(&mut self) {
+        self.release().unwrap();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checks_buffers_against_planes() {
+        let addressing = plane_addressing(ImageType::NV12, 4, 2).unwrap();
+        assert_eq!(addressing.len(), 2);
+
+        assert_eq!(
+            check_buffers(&addressing, &[vec![0u8; 8]]).unwrap_err(),
+            VxError::InvalidParameters
+        );
+        assert_eq!(
+            check_buffers(&addressing, &[vec![0u8; 8], vec![0u8; 3]]).unwrap_err(),
+            VxError::InvalidDimension
+        );
+        assert!(check_buffers(&addressing, &[vec![0u8; 8], vec![0u8; 4]]).is_ok());
+
+        assert_eq!(
+            plane_addressing(ImageType::Virtual, 4, 2).unwrap_err(),
+            VxError::InvalidFormat
+        );
+    }
+}
@@ -1,37 +1,783 @@
-use crate::{AsVxReference, VxReference};
-use libopenvx_sys::vx_image;
+use crate::bitpatch::{bit_addressing, pack_bits, unpack_bits};
+use crate::error::check_call;
+use crate::types::reference::retain_reference;
+use crate::{
+    attr, Access, AsRaw, AsVxReference, BitPatch, Channel, CheckStatus, ImageAddressing,
+    ImagePatch, ImagePlane, ImageType, MemoryType, PixelType, PixelValue, QueryAttribute, Rect,
+    Release, Result, SetAttribute, VxContext, VxError, VxGraph, VxReference, VxStatus,
+};
+use libopenvx_sys::{
+    vxCopyImagePatch, vxCopyImagePatchWithFlags, vxCreateImage, vxCreateImageFromChannel,
+    vxCreateImageFromHandle, vxCreateImageFromROI, vxCreateUniformImage, vxCreateVirtualImage,
+    vxGetValidRegionImage, vxQueryImage, vxReleaseImage, vxSetImageAttribute,
+    vxSetImageValidRectangle, vx_enum, vx_image, vx_imagepatch_addressing_t, vx_reference, vx_size,
+    vx_status, vx_uint32, VX_SCALE_UNITY,
+};
+use std::ffi::c_void;
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
 
 /// An opaque reference to an image.
-#[derive(Debug, Hash, PartialEq, Eq, Copy, Clone)]
-pub struct VxImage {
+///
+/// The object is bound to the lifetime `'a` of the [`VxContext`](struct.VxContext.html) it was
+/// created in or, for virtual objects, of the [`VxGraph`](struct.VxGraph.html) that owns it.
+#[derive(Debug)]
+pub struct VxImage<'a> {
     raw: vx_image,
+    /// The parent and the region it was created from, for images created by
+    /// [`from_roi`](#method.from_roi).
+    roi: Option<(Box<VxImage<'a>>, Rect)>,
+    _marker: PhantomData<&'a ()>,
 }
 
-impl VxImage {
+impl<'a> VxImage<'a> {
+    /// Creates an image by calling [`vxCreateImage`].
+    ///
+    /// # Arguments
+    ///
+    /// * `context` - The context in which to create the image.
+    /// * `width` - The image width in pixels. The image in the formats of `NV12`, `NV21`, `IYUV`,
+    ///   `UYVY`, `YUYV` must have even width.
+    /// * `height` - The image height in pixels. The image in the formats of `NV12`, `NV21`, `IYUV`
+    ///   must have even height.
+    /// * `format` - The format of the image.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use openvx::{ImageType, VxContext, VxImage};
+    ///
+    /// let context = VxContext::create().unwrap();
+    /// let image = VxImage::new(&context, 640, 480, ImageType::RGB).unwrap();
+    /// ```
+    ///
+    /// [`vxCreateImage`]: ../../libopenvx_sys/fn.vxCreateImage.html
+    pub fn new(
+        context: &'a VxContext,
+        width: vx_uint32,
+        height: vx_uint32,
+        format: ImageType,
+    ) -> Result<VxImage<'a>> {
+        let image = unsafe { vxCreateImage(context.as_raw(), width, height, format.to_raw()) };
+        VxImage::from(image).into_checked()
+    }
+
+    /// Creates a virtual image by calling [`vxCreateVirtualImage`].
+    ///
+    /// Virtual images are owned by the graph and can only be used as intermediate buffers
+    /// between nodes of that graph. Width, height and format may be left unspecified
+    /// (`0` or [`ImageType::Virtual`]), in which case they are inferred during graph verification.
+    ///
+    /// [`vxCreateVirtualImage`]: ../../libopenvx_sys/fn.vxCreateVirtualImage.html
+    /// [`ImageType::Virtual`]: ../enum.ImageType.html#variant.Virtual
+    pub fn virtual_image(
+        graph: &'a VxGraph<'_>,
+        width: vx_uint32,
+        height: vx_uint32,
+        format: ImageType,
+    ) -> Result<VxImage<'a>> {
+        let image = unsafe { vxCreateVirtualImage(graph.as_raw(), width, height, format.to_raw()) };
+        VxImage::from(image).into_checked()
+    }
+
+    /// Creates a virtual image whose dimensions and format are inferred from the nodes it
+    /// is connected to during graph verification.
+    ///
+    /// This is equivalent to calling [`virtual_image`](#method.virtual_image) with a size of `0`
+    /// and [`ImageType::Virtual`](../enum.ImageType.html#variant.Virtual).
+    pub fn virtual_image_inferred(graph: &'a VxGraph<'_>) -> Result<VxImage<'a>> {
+        Self::virtual_image(graph, 0, 0, ImageType::Virtual)
+    }
+
+    /// Creates an image with all pixels set to the given value by calling
+    /// [`vxCreateUniformImage`].
+    ///
+    /// Uniform images are read-only.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use openvx::{ImageType, PixelValue, VxContext, VxImage};
+    ///
+    /// let context = VxContext::create().unwrap();
+    /// let black = PixelValue::from_rgb([0, 0, 0]);
+    /// let image = VxImage::uniform(&context, 640, 480, ImageType::RGB, black).unwrap();
+    /// ```
+    ///
+    /// [`vxCreateUniformImage`]: ../../libopenvx_sys/fn.vxCreateUniformImage.html
+    pub fn uniform(
+        context: &'a VxContext,
+        width: vx_uint32,
+        height: vx_uint32,
+        format: ImageType,
+        value: PixelValue,
+    ) -> Result<VxImage<'a>> {
+        let value = value.to_raw();
+        let image = unsafe {
+            vxCreateUniformImage(context.as_raw(), width, height, format.to_raw(), &value)
+        };
+        VxImage::from(image).into_checked()
+    }
+
+    /// Creates an image from a region of interest of `parent` by calling
+    /// [`vxCreateImageFromROI`].
+    ///
+    /// The new image shares its memory with the parent image and keeps a reference to it;
+    /// see [`parent`](#method.parent) and [`roi`](#method.roi).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use openvx::{ImageType, Rect, VxContext, VxImage};
+    ///
+    /// let context = VxContext::create().unwrap();
+    /// let image = VxImage::new(&context, 640, 480, ImageType::U8).unwrap();
+    ///
+    /// let roi = VxImage::from_roi(&image, Rect::from_size(100, 50, 320, 240)).unwrap();
+    /// assert_eq!(roi.roi(), Some(Rect::new(100, 50, 420, 290)));
+    /// assert_eq!(roi.valid_rect().unwrap(), Rect::from_size(0, 0, 320, 240));
+    /// ```
+    ///
+    /// [`vxCreateImageFromROI`]: ../../libopenvx_sys/fn.vxCreateImageFromROI.html
+    pub fn from_roi(parent: &VxImage<'a>, rect: Rect) -> Result<VxImage<'a>> {
+        let raw_rect = rect.to_raw();
+        let image = unsafe { vxCreateImageFromROI(parent.raw, &raw_rect) };
+        let mut image = VxImage::from(image).into_checked()?;
+        image.roi = Some((Box::new(parent.clone()), rect));
+        Ok(image)
+    }
+
+    /// Creates an image from a single channel of `parent` by calling
+    /// [`vxCreateImageFromChannel`].
+    ///
+    /// The new image shares its memory with the parent image. Only the channels of
+    /// planar formats whose channel occupies a full plane, such as the luma channel of
+    /// `IYUV`, `NV12`, `NV21` and `YUV4`, can be referenced.
+    ///
+    /// [`vxCreateImageFromChannel`]: ../../libopenvx_sys/fn.vxCreateImageFromChannel.html
+    pub fn from_channel(parent: &VxImage<'a>, channel: Channel) -> Result<VxImage<'a>> {
+        let image = unsafe { vxCreateImageFromChannel(parent.raw, channel.to_raw() as vx_enum) };
+        VxImage::from(image).into_checked()
+    }
+
+    /// Creates an image backed by host memory by calling [`vxCreateImageFromHandle`].
+    ///
+    /// The implementation reads and writes the planes directly. Their number has to match the
+    /// number of planes of `format`, and the addressing of each plane has to describe the plane
+    /// of an image with the dimensions of the first plane; [`ImageType::plane_addressing`]
+    /// describes tightly packed planes. Padding between pixels and rows is allowed.
+    ///
+    /// Use [`VxHostImage`](struct.VxHostImage.html) to attach owned buffers safely.
+    ///
+    /// # Safety
+    ///
+    /// The implementation keeps accessing the planes for as long as the image object exists,
+    /// which includes references held by graphs and nodes after this wrapper was dropped. The
+    /// caller has to ensure that the planes outlive all references to the image, or reclaim
+    /// them from the implementation by calling `vxSwapImageHandle` first.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VxError::InvalidFormat`] if the layout of `format` is unknown,
+    /// [`VxError::InvalidParameters`] if the number of planes does not match or a plane's
+    /// addressing does not match the format, and [`VxError::InvalidDimension`] if a stride is
+    /// too small or a plane is smaller than its addressing requires.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use openvx::{ImagePlane, ImageType, VxContext, VxImage};
+    ///
+    /// let context = VxContext::create().unwrap();
+    /// let format = ImageType::U8;
+    /// let mut pixels = vec![0u8; format.buffer_size(640, 480).unwrap()];
+    /// let addressing = format.plane_addressing(0, 640, 480).unwrap();
+    /// let plane = ImagePlane::new(addressing, &mut pixels);
+    /// // The image is released before the pixels are dropped and not used by any graph.
+    /// let image = unsafe { VxImage::from_handle(&context, format, vec![plane]) }.unwrap();
+    /// ```
+    ///
+    /// [`vxCreateImageFromHandle`]: ../../libopenvx_sys/fn.vxCreateImageFromHandle.html
+    /// [`ImageType::plane_addressing`]: ../enum.ImageType.html#method.plane_addressing
+    /// [`VxError::InvalidFormat`]: ../enum.VxError.html#variant.InvalidFormat
+    /// [`VxError::InvalidParameters`]: ../enum.VxError.html#variant.InvalidParameters
+    /// [`VxError::InvalidDimension`]: ../enum.VxError.html#variant.InvalidDimension
+    pub unsafe fn from_handle(
+        context: &'a VxContext,
+        format: ImageType,
+        planes: Vec<ImagePlane<'a>>,
+    ) -> Result<VxImage<'a>> {
+        validate_planes(format, &planes)?;
+
+        let addressing: Vec<vx_imagepatch_addressing_t> = planes
+            .iter()
+            .map(|plane| plane.addressing.to_raw())
+            .collect();
+        let pointers: Vec<*mut c_void> = planes
+            .into_iter()
+            .map(|plane| plane.data.as_mut_ptr() as *mut c_void)
+            .collect();
+
+        let image = vxCreateImageFromHandle(
+            context.as_raw(),
+            format.to_raw(),
+            addressing.as_ptr(),
+            pointers.as_ptr(),
+            MemoryType::Host.to_raw() as vx_enum,
+        );
+        VxImage::from(image).into_checked()
+    }
+
+    /// Maps a patch of plane `plane` into host memory by calling [`vxMapImagePatch`].
+    ///
+    /// The element type `T` has to match the format of the plane, e.g. `[u8; 3]` for `RGB`
+    /// images; see [`PixelType`]. The returned guard unmaps the patch when dropped.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VxError::NotCompatible`] if `T` does not match the format of the plane and
+    /// [`VxError::NotSupported`] if the implementation returned a layout that cannot be
+    /// represented as rows of `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use openvx::{Access, ImageType, Rect, VxContext, VxImage};
+    ///
+    /// let context = VxContext::create().unwrap();
+    /// let image = VxImage::new(&context, 640, 480, ImageType::U8).unwrap();
+    ///
+    /// let mut patch = image
+    ///     .map_patch::<u8>(Rect::new(0, 0, 640, 480), 0, Access::Write)
+    ///     .unwrap();
+    /// for (y, row) in patch.rows_mut().enumerate() {
+    ///     row.fill(y as u8);
+    /// }
+    /// ```
+    ///
+    /// [`vxMapImagePatch`]: ../../libopenvx_sys/fn.vxMapImagePatch.html
+    /// [`PixelType`]: ../trait.PixelType.html
+    /// [`VxError::NotCompatible`]: ../enum.VxError.html#variant.NotCompatible
+    /// [`VxError::NotSupported`]: ../enum.VxError.html#variant.NotSupported
+    pub fn map_patch<T: PixelType>(
+        &self,
+        rect: Rect,
+        plane: u32,
+        access: Access,
+    ) -> Result<ImagePatch<'_, T>> {
+        ImagePatch::map(self, rect, plane, access)
+    }
+
+    /// Maps a patch of a bit-packed `U1` image into host memory by calling [`vxMapImagePatch`].
+    ///
+    /// The returned guard reads and writes single pixels as `bool` and unmaps the patch
+    /// when dropped.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VxError::NotCompatible`] if the image is not a `U1` image.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use openvx::{Access, ImageType, Rect, VxContext, VxImage};
+    ///
+    /// let context = VxContext::create().unwrap();
+    /// let mask = VxImage::new(&context, 640, 480, ImageType::U1).unwrap();
+    /// // ... process a graph thresholding into `mask` ...
+    ///
+    /// let patch = mask.map_bits(Rect::new(0, 0, 640, 480), Access::Read).unwrap();
+    /// let set = patch.iter().filter(|&bit| bit).count();
+    /// ```
+    ///
+    /// [`vxMapImagePatch`]: ../../libopenvx_sys/fn.vxMapImagePatch.html
+    /// [`VxError::NotCompatible`]: ../enum.VxError.html#variant.NotCompatible
+    pub fn map_bits(&self, rect: Rect, access: Access) -> Result<BitPatch<'_>> {
+        BitPatch::map(self, rect, access)
+    }
+
+    /// Writes the row-major pixels `bits` into the region `rect` of a bit-packed `U1` image
+    /// by calling [`vxCopyImagePatch`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VxError::NotCompatible`] if the image is not a `U1` image and
+    /// [`VxError::InvalidDimension`] if `bits` holds fewer pixels than `rect`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use openvx::{ImageType, Rect, VxContext, VxImage};
+    ///
+    /// let context = VxContext::create().unwrap();
+    /// let mask = VxImage::new(&context, 4, 2, ImageType::U1).unwrap();
+    ///
+    /// let luma = [0u8, 200, 30, 255, 90, 0, 180, 10];
+    /// let bits: Vec<bool> = luma.iter().map(|&value| value > 127).collect();
+    /// mask.copy_from_bits(Rect::new(0, 0, 4, 2), &bits).unwrap();
+    /// ```
+    ///
+    /// [`vxCopyImagePatch`]: ../../libopenvx_sys/fn.vxCopyImagePatch.html
+    /// [`VxError::NotCompatible`]: ../enum.VxError.html#variant.NotCompatible
+    /// [`VxError::InvalidDimension`]: ../enum.VxError.html#variant.InvalidDimension
+    pub fn copy_from_bits(&self, rect: Rect, bits: &[bool]) -> Result<()> {
+        if self.query::<attr::Format>()? != ImageType::U1 {
+            return Err(VxError::NotCompatible.into());
+        }
+        if bits.len() < rect.width() as usize * rect.height() as usize {
+            return Err(VxError::InvalidDimension.into());
+        }
+
+        let addressing = bit_addressing(rect);
+        let mut data = pack_bits(bits, &addressing, rect.start_x % 8);
+        self.copy_patch(
+            0,
+            rect,
+            &addressing,
+            data.as_mut_ptr() as *mut c_void,
+            Access::Write,
+            None,
+        )
+    }
+
+    /// Reads the region `rect` of a bit-packed `U1` image into row-major pixels
+    /// by calling [`vxCopyImagePatch`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VxError::NotCompatible`] if the image is not a `U1` image.
+    ///
+    /// [`vxCopyImagePatch`]: ../../libopenvx_sys/fn.vxCopyImagePatch.html
+    /// [`VxError::NotCompatible`]: ../enum.VxError.html#variant.NotCompatible
+    pub fn copy_to_bits(&self, rect: Rect) -> Result<Vec<bool>> {
+        if self.query::<attr::Format>()? != ImageType::U1 {
+            return Err(VxError::NotCompatible.into());
+        }
+
+        let addressing = bit_addressing(rect);
+        let mut data = vec![0u8; addressing.buffer_size()];
+        self.copy_patch(
+            0,
+            rect,
+            &addressing,
+            data.as_mut_ptr() as *mut c_void,
+            Access::Read,
+            None,
+        )?;
+        Ok(unpack_bits(&data, &addressing, rect.start_x % 8))
+    }
+
+    /// Writes `data` into the region `rect` of plane `plane` by calling [`vxCopyImagePatch`].
+    ///
+    /// `data` holds the rows of the plane with `stride` elements between the starts of
+    /// consecutive rows. Chroma planes of sub-sampled formats such as `NV12` or `IYUV`
+    /// have fewer rows and columns than `rect`; the addressing is derived from the format.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VxError::NotCompatible`] if `T` does not match the format of the plane and
+    /// [`VxError::InvalidDimension`] if `stride` or `data` are too small for the region.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use openvx::{ImageType, Rect, VxContext, VxImage};
+    ///
+    /// let context = VxContext::create().unwrap();
+    /// let image = VxImage::new(&context, 64, 48, ImageType::IYUV).unwrap();
+    /// let rect = Rect::new(0, 0, 64, 48);
+    ///
+    /// image.copy_from_slice(0, rect, &vec![16u8; 64 * 48], 64).unwrap();
+    /// image.copy_from_slice(1, rect, &vec![128u8; 32 * 24], 32).unwrap();
+    /// image.copy_from_slice(2, rect, &vec![128u8; 32 * 24], 32).unwrap();
+    /// ```
+    ///
+    /// [`vxCopyImagePatch`]: ../../libopenvx_sys/fn.vxCopyImagePatch.html
+    /// [`VxError::NotCompatible`]: ../enum.VxError.html#variant.NotCompatible
+    /// [`VxError::InvalidDimension`]: ../enum.VxError.html#variant.InvalidDimension
+    pub fn copy_from_slice<T: PixelType>(
+        &self,
+        plane: u32,
+        rect: Rect,
+        data: &[T],
+        stride: usize,
+    ) -> Result<()> {
+        let format = self.query::<attr::Format>()?;
+        let subsampling = check_plane::<T>(format, plane)?;
+        self.write_patch(plane, rect, subsampling, data, stride, None)
+    }
+
+    /// Reads the region `rect` of plane `plane` into a new vector by calling
+    /// [`vxCopyImagePatch`].
+    ///
+    /// The rows of the plane are stored without gaps; chroma planes of sub-sampled formats
+    /// yield fewer rows and columns than `rect`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VxError::NotCompatible`] if `T` does not match the format of the plane.
+    ///
+    /// [`vxCopyImagePatch`]: ../../libopenvx_sys/fn.vxCopyImagePatch.html
+    /// [`VxError::NotCompatible`]: ../enum.VxError.html#variant.NotCompatible
+    pub fn copy_to_vec<T: PixelType>(&self, plane: u32, rect: Rect) -> Result<Vec<T>> {
+        let format = self.query::<attr::Format>()?;
+        let subsampling = check_plane::<T>(format, plane)?;
+        self.read_patch(plane, rect, subsampling, None)
+    }
+
+    /// Writes `data`, holding rows of plane `plane` with `stride` elements between their
+    /// starts, into the region `rect`. The plane is sub-sampled by `subsampling` relative
+    /// to `rect`; `flags` are passed to `vxCopyImagePatchWithFlags` if given.
+    pub(crate) fn write_patch<T: PixelType>(
+        &self,
+        plane: u32,
+        rect: Rect,
+        subsampling: (u32, u32),
+        data: &[T],
+        stride: usize,
+        flags: Option<vx_uint32>,
+    ) -> Result<()> {
+        let addressing = copy_addressing::<T>(rect, subsampling, stride)?;
+        if data.len() < required_len(&addressing, stride) {
+            return Err(VxError::InvalidDimension.into());
+        }
+
+        self.copy_patch(
+            plane,
+            rect,
+            &addressing,
+            data.as_ptr() as *mut c_void,
+            Access::Write,
+            flags,
+        )
+    }
+
+    /// Reads the region `rect` of plane `plane` into a tightly packed vector; see
+    /// [`write_patch`](#method.write_patch) for the arguments.
+    pub(crate) fn read_patch<T: PixelType>(
+        &self,
+        plane: u32,
+        rect: Rect,
+        subsampling: (u32, u32),
+        flags: Option<vx_uint32>,
+    ) -> Result<Vec<T>> {
+        let (subsampling_x, subsampling_y) = subsampling;
+        let width = (rect.width() / subsampling_x) as usize;
+        let height = (rect.height() / subsampling_y) as usize;
+        let addressing = copy_addressing::<T>(rect, subsampling, width)?;
+
+        // Every bit pattern is a valid value of a `PixelType`.
+        let mut data: Vec<T> = vec![unsafe { std::mem::zeroed() }; width * height];
+        self.copy_patch(
+            plane,
+            rect,
+            &addressing,
+            data.as_mut_ptr() as *mut c_void,
+            Access::Read,
+            flags,
+        )?;
+        Ok(data)
+    }
+
+    /// Copies between host memory and the image by calling [`vxCopyImagePatch`] or, if
+    /// `flags` are given, [`vxCopyImagePatchWithFlags`].
+    ///
+    /// [`vxCopyImagePatch`]: ../../libopenvx_sys/fn.vxCopyImagePatch.html
+    /// [`vxCopyImagePatchWithFlags`]: ../../libopenvx_sys/fn.vxCopyImagePatchWithFlags.html
+    fn copy_patch(
+        &self,
+        plane: u32,
+        rect: Rect,
+        addressing: &ImageAddressing,
+        ptr: *mut c_void,
+        access: Access,
+        flags: Option<vx_uint32>,
+    ) -> Result<()> {
+        let rect = rect.to_raw();
+        let addressing = addressing.to_raw();
+        let (usage, memory_type) = (
+            access.to_raw() as vx_enum,
+            MemoryType::Host.to_raw() as vx_enum,
+        );
+        match flags {
+            None => check_call("vxCopyImagePatch", self, || unsafe {
+                vxCopyImagePatch(self.raw, &rect, plane, &addressing, ptr, usage, memory_type)
+            }),
+            Some(flags) => check_call("vxCopyImagePatchWithFlags", self, || unsafe {
+                vxCopyImagePatchWithFlags(
+                    self.raw,
+                    &rect,
+                    plane,
+                    &addressing,
+                    ptr,
+                    usage,
+                    memory_type,
+                    flags,
+                )
+            }),
+        }
+    }
+
+    /// Returns the image this image was created from by [`from_roi`](#method.from_roi).
+    pub fn parent(&self) -> Option<&VxImage<'a>> {
+        self.roi.as_ref().map(|(parent, _)| parent.as_ref())
+    }
+
+    /// Returns the region of the parent this image was created from by
+    /// [`from_roi`](#method.from_roi), in coordinates of the parent.
+    pub fn roi(&self) -> Option<Rect> {
+        self.roi.as_ref().map(|(_, rect)| *rect)
+    }
+
+    /// Returns the region of the image with defined pixels by calling [`vxGetValidRegionImage`].
+    ///
+    /// Nodes shrink the valid region of their outputs when pixels near the border are undefined,
+    /// e.g. for filters with [`Border::Undefined`].
+    ///
+    /// [`vxGetValidRegionImage`]: ../../libopenvx_sys/fn.vxGetValidRegionImage.html
+    /// [`Border::Undefined`]: ../enum.Border.html#variant.Undefined
+    pub fn valid_rect(&self) -> Result<Rect> {
+        let mut rect = Rect::default().to_raw();
+        let status = unsafe { vxGetValidRegionImage(self.raw, &mut rect) };
+        VxStatus::new_result(status, Rect::from(rect))
+    }
+
+    /// Sets the region of the image with defined pixels by calling [`vxSetImageValidRectangle`].
+    ///
+    /// [`vxSetImageValidRectangle`]: ../../libopenvx_sys/fn.vxSetImageValidRectangle.html
+    pub fn set_valid_rect(&self, rect: Rect) -> Result<()> {
+        let rect = rect.to_raw();
+        let status = unsafe { vxSetImageValidRectangle(self.raw, &rect) };
+        VxStatus::new_result(status, ())
+    }
+
+    /// Resets the valid region to the full image by calling [`vxSetImageValidRectangle`].
+    ///
+    /// [`vxSetImageValidRectangle`]: ../../libopenvx_sys/fn.vxSetImageValidRectangle.html
+    pub fn reset_valid_rect(&self) -> Result<()> {
+        let status = unsafe { vxSetImageValidRectangle(self.raw, std::ptr::null()) };
+        VxStatus::new_result(status, ())
+    }
+
+    /// Clips `rect` to the valid region of the image.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use openvx::{ImageType, Rect, VxContext, VxImage};
+    ///
+    /// let context = VxContext::create().unwrap();
+    /// let edges = VxImage::new(&context, 640, 480, ImageType::U8).unwrap();
+    /// // ... process a graph writing `edges` with an undefined border ...
+    ///
+    /// let defined = edges.clip_to_valid(Rect::from_size(0, 0, 640, 480)).unwrap();
+    /// let pixels = edges.copy_to_vec::<u8>(0, defined).unwrap();
+    /// ```
+    pub fn clip_to_valid(&self, rect: Rect) -> Result<Rect> {
+        Ok(rect.intersection(&self.valid_rect()?))
+    }
+
+    /// Returns the valid region of the image in coordinates of its parent, or of the image
+    /// itself if it was not created by [`from_roi`](#method.from_roi).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VxError::InvalidValue`] if the region exceeds the coordinate range.
+    ///
+    /// [`VxError::InvalidValue`]: ../enum.VxError.html#variant.InvalidValue
+    pub fn valid_rect_in_parent(&self) -> Result<Rect> {
+        let valid = self.valid_rect()?;
+        match self.roi() {
+            Some(roi) => valid
+                .offset(roi.start_x, roi.start_y)
+                .ok_or_else(|| VxError::InvalidValue.into()),
+            None => Ok(valid),
+        }
+    }
+
     #[allow(dead_code)]
     pub fn is_null(&self) -> bool {
         self.raw.is_null()
     }
 }
 
-impl AsVxReference for VxImage {
-    fn as_reference(&self) -> VxReference {
+impl PartialEq for VxImage<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.raw == other.raw
+    }
+}
+
+impl Eq for VxImage<'_> {}
+
+impl Hash for VxImage<'_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.raw.hash(state);
+    }
+}
+
+impl<'a> AsRaw for VxImage<'a> {
+    type Result = vx_image;
+
+    fn as_raw(&self) -> Self::Result {
+        self.raw
+    }
+}
+
+impl<'a> AsVxReference for VxImage<'a> {
+    fn as_reference(&self) -> VxReference<'_> {
         VxReference::from(self.raw)
     }
 }
 
-impl From<vx_image> for VxImage {
+impl QueryAttribute for VxImage<'_> {
+    unsafe fn query_raw(&self, attribute: vx_enum, ptr: *mut c_void, size: vx_size) -> vx_status {
+        vxQueryImage(self.raw, attribute, ptr, size)
+    }
+}
+
+impl SetAttribute for VxImage<'_> {
+    unsafe fn set_raw(&self, attribute: vx_enum, ptr: *const c_void, size: vx_size) -> vx_status {
+        vxSetImageAttribute(self.raw, attribute, ptr, size)
+    }
+}
+
+impl<'a> Release for VxImage<'a> {
+    fn release(&mut self) -> Result<()> {
+        if self.raw.is_null() {
+            return Ok(());
+        }
+
+        let status = unsafe { vxReleaseImage(&mut self.raw) };
+        self.raw = std::ptr::null_mut();
+        self.roi = None;
+
+        VxStatus::new_result(status, ())
+    }
+}
+
+impl<'a> Drop for VxImage<'a> {
+    fn drop(&mut self) {
+        self.release().unwrap();
+    }
+}
+
+impl<'a> Clone for VxImage<'a> {
+    /// Creates a new handle to the same object, incrementing its reference count
+    /// by calling [`vxRetainReference`](../../libopenvx_sys/fn.vxRetainReference.html).
+    fn clone(&self) -> Self {
+        retain_reference(self.raw as vx_reference);
+        VxImage {
+            raw: self.raw,
+            roi: self.roi.clone(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'a> From<vx_image> for VxImage<'a> {
+    /// Takes ownership of the reference; it will be released when the wrapper is dropped.
     fn from(value: vx_image) -> Self {
-        VxImage { raw: value }
+        VxImage {
+            raw: value,
+            roi: None,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'a> Into<vx_image> for VxImage<'a> {
+    /// Gives up ownership of the reference; the caller becomes responsible for releasing it.
+    fn into(mut self) -> vx_image {
+        self.roi = None;
+        let raw = self.raw;
+        std::mem::forget(self);
+        raw
     }
 }
 
-impl Into<vx_image> for VxImage {
-    fn into(self) -> vx_image {
-        return self.raw;
+/// Checks that `T` represents the elements of plane `plane` of `format` and returns the
+/// sub-sampling of the plane.
+fn check_plane<T: PixelType>(format: ImageType, plane: u32) -> Result<(u32, u32)> {
+    if !T::is_compatible(format, plane) {
+        return Err(VxError::NotCompatible.into());
+    }
+    Ok(format.subsampling(plane).unwrap_or((1, 1)))
+}
+
+/// Computes the addressing of host memory holding the region `rect` of a plane sub-sampled
+/// by `subsampling`, with rows of `stride` elements of type `T`.
+fn copy_addressing<T: PixelType>(
+    rect: Rect,
+    subsampling: (u32, u32),
+    stride: usize,
+) -> Result<ImageAddressing> {
+    let (subsampling_x, subsampling_y) = subsampling;
+    if stride < (rect.width() / subsampling_x) as usize {
+        return Err(VxError::InvalidDimension.into());
+    }
+
+    let element = std::mem::size_of::<T>();
+    let mut addressing = ImageAddressing::new(
+        rect.width(),
+        rect.height(),
+        element as i32,
+        (stride * element) as i32,
+    );
+    addressing.scale_x = VX_SCALE_UNITY / subsampling_x;
+    addressing.scale_y = VX_SCALE_UNITY / subsampling_y;
+    Ok(addressing)
+}
+
+/// Returns the number of elements needed to hold the rows described by `addressing`.
+fn required_len(addressing: &ImageAddressing, stride: usize) -> usize {
+    let width = (addressing.dim_x * addressing.scale_x / VX_SCALE_UNITY) as usize;
+    let height = (addressing.dim_y * addressing.scale_y / VX_SCALE_UNITY) as usize;
+    match height {
+        0 => 0,
+        height => stride * (height - 1) + width,
     }
 }
 
+/// Checks that the planes of `format` were given, that their addressing matches the layout
+/// of the format with the dimensions of the first plane and that each covers the memory its
+/// addressing describes.
+fn validate_planes(format: ImageType, planes: &[ImagePlane<'_>]) -> Result<()> {
+    let expected = format.planes().ok_or(VxError::InvalidFormat)?;
+    if planes.len() != expected as usize {
+        return Err(VxError::InvalidParameters.into());
+    }
+
+    let (width, height) = (planes[0].addressing.dim_x, planes[0].addressing.dim_y);
+    for (index, plane) in planes.iter().enumerate() {
+        let actual = &plane.addressing;
+        let expected = format
+            .plane_addressing(index as u32, width, height)
+            .ok_or(VxError::InvalidFormat)?;
+
+        let bit_packed = expected.stride_x == 0;
+        if (actual.dim_x, actual.dim_y) != (expected.dim_x, expected.dim_y)
+            || (actual.scale_x, actual.scale_y) != (expected.scale_x, expected.scale_y)
+            || (actual.step_x, actual.step_y) != (expected.step_x, expected.step_y)
+            || (bit_packed
+                && (actual.stride_x, actual.stride_x_bits)
+                    != (expected.stride_x, expected.stride_x_bits))
+        {
+            return Err(VxError::InvalidParameters.into());
+        }
+
+        // Columns of the plane, e.g. half the width for the chroma planes of `IYUV`.
+        let columns = width as i64 * expected.scale_x as i64 / VX_SCALE_UNITY as i64;
+        let row_size = (actual.stride_x as i64 * columns).max(expected.stride_y as i64);
+        if actual.stride_x < expected.stride_x || (actual.stride_y as i64) < row_size {
+            return Err(VxError::InvalidDimension.into());
+        }
+        if plane.data.len() < actual.buffer_size() {
+            return Err(VxError::InvalidDimension.into());
+        }
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -40,4 +786,115 @@ mod tests {
     fn is_null() {
         assert!(VxImage::from(std::ptr::null_mut()).is_null());
     }
+
+    #[test]
+    fn equality_ignores_roi() {
+        let raw = std::ptr::NonNull::<u8>::dangling().as_ptr() as vx_image;
+        let mut roi = std::mem::ManuallyDrop::new(VxImage::from(raw));
+        roi.roi = Some((
+            Box::new(VxImage::from(std::ptr::null_mut())),
+            Rect::new(0, 0, 1, 1),
+        ));
+        let plain = std::mem::ManuallyDrop::new(VxImage::from(raw));
+
+        let hash = |image: &VxImage<'_>| {
+            let mut hasher = std::collections::hash_map::DefaultHasher::new();
+            image.hash(&mut hasher);
+            hasher.finish()
+        };
+        assert_eq!(*roi, *plain);
+        assert_eq!(hash(&roi), hash(&plain));
+    }
+
+    #[test]
+    fn copy_addressing_of_subsampled_planes() {
+        let rect = Rect::new(0, 0, 64, 48);
+        let format = ImageType::NV12;
+
+        let subsampling = check_plane::<u8>(format, 0).unwrap();
+        let luma = copy_addressing::<u8>(rect, subsampling, 64).unwrap();
+        assert_eq!(luma, ImageAddressing::new(64, 48, 1, 64));
+        assert_eq!(required_len(&luma, 64), 64 * 48);
+
+        let subsampling = check_plane::<[u8; 2]>(format, 1).unwrap();
+        let chroma = copy_addressing::<[u8; 2]>(rect, subsampling, 40).unwrap();
+        assert_eq!((chroma.stride_x, chroma.stride_y), (2, 80));
+        assert_eq!(chroma.scale_x, VX_SCALE_UNITY / 2);
+        assert_eq!(required_len(&chroma, 40), 40 * 23 + 32);
+
+        assert_eq!(
+            copy_addressing::<u8>(rect, (2, 2), 31).unwrap_err(),
+            VxError::InvalidDimension
+        );
+        assert_eq!(
+            check_plane::<u8>(ImageType::RGB, 0).unwrap_err(),
+            VxError::NotCompatible
+        );
+    }
+
+    #[test]
+    fn validate_planes() {
+        assert_eq!(
+            super::validate_planes(ImageType::U8, &[]).unwrap_err(),
+            VxError::InvalidParameters
+        );
+
+        let mut data = [0u8; 16];
+        let (short, full) = data.split_at_mut(1);
+        let addressing = ImageAddressing::new(3, 5, 1, 3);
+        assert_eq!(
+            super::validate_planes(ImageType::U8, &[ImagePlane::new(addressing, short)])
+                .unwrap_err(),
+            VxError::InvalidDimension
+        );
+        let plane = ImagePlane::new(addressing, full);
+        assert_eq!(
+            super::validate_planes(ImageType::NV12, std::slice::from_ref(&plane)).unwrap_err(),
+            VxError::InvalidParameters
+        );
+        assert_eq!(
+            super::validate_planes(ImageType::Virtual, std::slice::from_ref(&plane)).unwrap_err(),
+            VxError::InvalidFormat
+        );
+        assert!(super::validate_planes(ImageType::U8, &[plane]).is_ok());
+    }
+
+    #[test]
+    fn validate_plane_strides() {
+        let mut data = [0u8; 64];
+        let check = |addressing: ImageAddressing, data: &mut [u8]| {
+            super::validate_planes(ImageType::RGB, &[ImagePlane::new(addressing, data)])
+        };
+
+        // Padding between pixels and rows is fine.
+        assert!(check(ImageAddressing::new(2, 2, 4, 10), &mut data).is_ok());
+        for (stride_x, stride_y) in [(3, -6), (3, 0), (3, 5), (2, 6), (4, 6)] {
+            assert_eq!(
+                check(ImageAddressing::new(2, 2, stride_x, stride_y), &mut data).unwrap_err(),
+                VxError::InvalidDimension
+            );
+        }
+    }
+
+    #[test]
+    fn validate_subsampled_planes() {
+        let format = ImageType::NV12;
+        let mut luma = [0u8; 16];
+        let mut chroma = [0u8; 8];
+        let expected = format.plane_addressing(1, 4, 4).unwrap();
+        let valid = [
+            ImagePlane::new(format.plane_addressing(0, 4, 4).unwrap(), &mut luma),
+            ImagePlane::new(expected, &mut chroma),
+        ];
+        assert!(super::validate_planes(format, &valid).is_ok());
+
+        let [luma, chroma] = valid;
+        let mut unscaled = expected;
+        unscaled.scale_y = VX_SCALE_UNITY;
+        let invalid = [luma, ImagePlane::new(unscaled, chroma.data)];
+        assert_eq!(
+            super::validate_planes(format, &invalid).unwrap_err(),
+            VxError::InvalidParameters
+        );
+    }
 }
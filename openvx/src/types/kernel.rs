@@ -1,34 +1,163 @@
-use crate::{AsVxReference, VxReference};
-use libopenvx_sys::vx_kernel;
+use crate::types::reference::retain_reference;
+use crate::{
+    attr, AsRaw, AsVxReference, CheckStatus, ParameterInfo, QueryAttribute, Release, Result,
+    SetAttribute, VxContext, VxError, VxParameter, VxReference, VxStatus,
+};
+use libopenvx_sys::{
+    vxGetKernelByEnum, vxGetKernelByName, vxQueryKernel, vxReleaseKernel, vxSetKernelAttribute,
+    vx_enum, vx_kernel, vx_reference, vx_size, vx_status, vx_uint32,
+};
+use std::ffi::c_void;
+use std::marker::PhantomData;
 
 /// An opaque reference to the descriptor of a kernel.
-#[derive(Debug, Hash, PartialEq, Eq, Copy, Clone)]
-pub struct VxKernel {
+///
+/// The kernel is bound to the lifetime `'ctx` of the [`VxContext`](struct.VxContext.html) it was obtained from.
+#[derive(Debug, Hash, PartialEq, Eq)]
+pub struct VxKernel<'ctx> {
     raw: vx_kernel,
+    _marker: PhantomData<&'ctx ()>,
 }
 
-impl VxKernel {
+impl<'ctx> VxKernel<'ctx> {
+    /// Obtains a reference to a kernel using a string to specify the name
+    /// by calling [`vxGetKernelByName`].
+    ///
+    /// # Arguments
+    ///
+    /// * `context` - The context to look the kernel up in.
+    /// * `name` - The name of the kernel, e.g. `org.khronos.openvx.color_convert`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VxError::InvalidParameters`] if the name contains a nul byte.
+    ///
+    /// [`vxGetKernelByName`]: ../../libopenvx_sys/fn.vxGetKernelByName.html
+    /// [`VxError::InvalidParameters`]: ../enum.VxError.html#variant.InvalidParameters
+    pub fn get_by_name(context: &'ctx VxContext, name: &str) -> Result<VxKernel<'ctx>> {
+        let name = std::ffi::CString::new(name).map_err(|_| VxError::InvalidParameters)?;
+        let kernel = unsafe { vxGetKernelByName(context.as_raw(), name.as_ptr()) };
+        VxKernel::from(kernel).into_checked()
+    }
+
+    /// Obtains a reference to the kernel using the kernel enumeration
+    /// by calling [`vxGetKernelByEnum`].
+    ///
+    /// [`vxGetKernelByEnum`]: ../../libopenvx_sys/fn.vxGetKernelByEnum.html
+    pub fn get_by_enum(context: &'ctx VxContext, kernel: vx_enum) -> Result<VxKernel<'ctx>> {
+        let kernel = unsafe { vxGetKernelByEnum(context.as_raw(), kernel) };
+        VxKernel::from(kernel).into_checked()
+    }
+
+    /// Returns the name of the kernel (`VX_KERNEL_NAME`).
+    pub fn name(&self) -> Result<String> {
+        self.query::<attr::KernelName>()
+    }
+
+    /// Returns the enumeration of the kernel (`VX_KERNEL_ENUM`).
+    pub fn enumeration(&self) -> Result<vx_enum> {
+        self.query::<attr::KernelEnum>()
+    }
+
+    /// Returns the number of parameters of the kernel (`VX_KERNEL_PARAMETERS`).
+    pub fn num_parameters(&self) -> Result<vx_uint32> {
+        self.query::<attr::NumParameters>()
+    }
+
+    /// Retrieves the parameter at the given index by calling [`vxGetKernelParameterByIndex`].
+    ///
+    /// [`vxGetKernelParameterByIndex`]: ../../libopenvx_sys/fn.vxGetKernelParameterByIndex.html
+    pub fn parameter(&self, index: vx_uint32) -> Result<VxParameter<'_>> {
+        VxParameter::from_kernel(self, index)
+    }
+
+    /// Returns the signature of the kernel, i.e. the direction, type and state
+    /// of each of its parameters.
+    pub fn parameters(&self) -> Result<Vec<ParameterInfo>> {
+        (0..self.num_parameters()?)
+            .map(|index| self.parameter(index)?.info())
+            .collect()
+    }
+
     #[allow(dead_code)]
     pub fn is_null(&self) -> bool {
         self.raw.is_null()
     }
 }
 
-impl AsVxReference for VxKernel {
-    fn as_reference(&self) -> VxReference {
+impl<'ctx> AsRaw for VxKernel<'ctx> {
+    type Result = vx_kernel;
+
+    fn as_raw(&self) -> Self::Result {
+        self.raw
+    }
+}
+
+impl<'ctx> AsVxReference for VxKernel<'ctx> {
+    fn as_reference(&self) -> VxReference<'_> {
         VxReference::from(self.raw)
     }
 }
 
-impl From<vx_kernel> for VxKernel {
+impl QueryAttribute for VxKernel<'_> {
+    unsafe fn query_raw(&self, attribute: vx_enum, ptr: *mut c_void, size: vx_size) -> vx_status {
+        vxQueryKernel(self.raw, attribute, ptr, size)
+    }
+}
+
+impl SetAttribute for VxKernel<'_> {
+    unsafe fn set_raw(&self, attribute: vx_enum, ptr: *const c_void, size: vx_size) -> vx_status {
+        vxSetKernelAttribute(self.raw, attribute, ptr, size)
+    }
+}
+
+impl<'ctx> Release for VxKernel<'ctx> {
+    fn release(&mut self) -> Result<()> {
+        if self.raw.is_null() {
+            return Ok(());
+        }
+
+        let status = unsafe { vxReleaseKernel(&mut self.raw) };
+        self.raw = std::ptr::null_mut();
+
+        VxStatus::new_result(status, ())
+    }
+}
+
+impl<'ctx> Drop for VxKernel<'ctx> {
+    fn drop(&mut self) {
+        self.release().unwrap();
+    }
+}
+
+impl<'ctx> Clone for VxKernel<'ctx> {
+    /// Creates a new handle to the same object, incrementing its reference count
+    /// by calling [`vxRetainReference`](../../libopenvx_sys/fn.vxRetainReference.html).
+    fn clone(&self) -> Self {
+        retain_reference(self.raw as vx_reference);
+        VxKernel {
+            raw: self.raw,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'ctx> From<vx_kernel> for VxKernel<'ctx> {
+    /// Takes ownership of the reference; it will be released when the wrapper is dropped.
     fn from(value: vx_kernel) -> Self {
-        VxKernel { raw: value }
+        VxKernel {
+            raw: value,
+            _marker: PhantomData,
+        }
     }
 }
 
-impl Into<vx_kernel> for VxKernel {
+impl<'ctx> Into<vx_kernel> for VxKernel<'ctx> {
+    /// Gives up ownership of the reference; the caller becomes responsible for releasing it.
     fn into(self) -> vx_kernel {
-        return self.raw;
+        let raw = self.raw;
+        std::mem::forget(self);
+        raw
     }
 }
 
@@ -1,34 +1,129 @@
-use crate::{AsVxReference, VxReference};
-use libopenvx_sys::vx_lut;
+use crate::types::reference::retain_reference;
+use crate::{
+    AsRaw, AsVxReference, CheckStatus, QueryAttribute, Release, Result, VxContext, VxGraph,
+    VxReference, VxStatus, VxType,
+};
+use libopenvx_sys::{
+    vxCreateLUT, vxCreateVirtualLUT, vxQueryLUT, vxReleaseLUT, vx_enum, vx_lut, vx_reference,
+    vx_size, vx_status,
+};
+use std::ffi::c_void;
+use std::marker::PhantomData;
 
 /// The Look-Up Table (LUT) Object.
-#[derive(Debug, Hash, PartialEq, Eq, Copy, Clone)]
-pub struct VxLut {
+///
+/// The object is bound to the lifetime `'a` of the [`VxContext`](struct.VxContext.html) it was
+/// created in or, for virtual objects, of the [`VxGraph`](struct.VxGraph.html) that owns it.
+#[derive(Debug, Hash, PartialEq, Eq)]
+pub struct VxLut<'a> {
     raw: vx_lut,
+    _marker: PhantomData<&'a ()>,
 }
 
-impl VxLut {
+impl<'a> VxLut<'a> {
+    /// Creates a LUT by calling [`vxCreateLUT`].
+    ///
+    /// # Arguments
+    ///
+    /// * `context` - The context in which to create the LUT.
+    /// * `data_type` - The type of data stored in the LUT, e.g. [`VxType::UInt8`] or [`VxType::Int16`].
+    /// * `count` - The number of entries desired.
+    ///
+    /// [`vxCreateLUT`]: ../../libopenvx_sys/fn.vxCreateLUT.html
+    /// [`VxType::UInt8`]: ../enum.VxType.html#variant.UInt8
+    /// [`VxType::Int16`]: ../enum.VxType.html#variant.Int16
+    pub fn new(context: &'a VxContext, data_type: VxType, count: vx_size) -> Result<VxLut<'a>> {
+        let lut = unsafe { vxCreateLUT(context.as_raw(), data_type.to_raw() as vx_enum, count) };
+        VxLut::from(lut).into_checked()
+    }
+
+    /// Creates an opaque reference to a LUT object with no direct user access
+    /// by calling [`vxCreateVirtualLUT`].
+    ///
+    /// [`vxCreateVirtualLUT`]: ../../libopenvx_sys/fn.vxCreateVirtualLUT.html
+    pub fn virtual_lut(
+        graph: &'a VxGraph<'_>,
+        data_type: VxType,
+        count: vx_size,
+    ) -> Result<VxLut<'a>> {
+        let lut =
+            unsafe { vxCreateVirtualLUT(graph.as_raw(), data_type.to_raw() as vx_enum, count) };
+        VxLut::from(lut).into_checked()
+    }
+
     #[allow(dead_code)]
     pub fn is_null(&self) -> bool {
         self.raw.is_null()
     }
 }
 
-impl AsVxReference for VxLut {
-    fn as_reference(&self) -> VxReference {
+impl<'a> AsRaw for VxLut<'a> {
+    type Result = vx_lut;
+
+    fn as_raw(&self) -> Self::Result {
+        self.raw
+    }
+}
+
+impl<'a> AsVxReference for VxLut<'a> {
+    fn as_reference(&self) -> VxReference<'_> {
         VxReference::from(self.raw)
     }
 }
 
-impl From<vx_lut> for VxLut {
+impl QueryAttribute for VxLut<'_> {
+    unsafe fn query_raw(&self, attribute: vx_enum, ptr: *mut c_void, size: vx_size) -> vx_status {
+        vxQueryLUT(self.raw, attribute, ptr, size)
+    }
+}
+
+impl<'a> Release for VxLut<'a> {
+    fn release(&mut self) -> Result<()> {
+        if self.raw.is_null() {
+            return Ok(());
+        }
+
+        let status = unsafe { vxReleaseLUT(&mut self.raw) };
+        self.raw = std::ptr::null_mut();
+
+        VxStatus::new_result(status, ())
+    }
+}
+
+impl<'a> Drop for VxLut<'a> {
+    fn drop(&mut self) {
+        self.release().unwrap();
+    }
+}
+
+impl<'a> Clone for VxLut<'a> {
+    /// Creates a new handle to the same object, incrementing its reference count
+    /// by calling [`vxRetainReference`](../../libopenvx_sys/fn.vxRetainReference.html).
+    fn clone(&self) -> Self {
+        retain_reference(self.raw as vx_reference);
+        VxLut {
+            raw: self.raw,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'a> From<vx_lut> for VxLut<'a> {
+    /// Takes ownership of the reference; it will be released when the wrapper is dropped.
     fn from(value: vx_lut) -> Self {
-        VxLut { raw: value }
+        VxLut {
+            raw: value,
+            _marker: PhantomData,
+        }
     }
 }
 
-impl Into<vx_lut> for VxLut {
+impl<'a> Into<vx_lut> for VxLut<'a> {
+    /// Gives up ownership of the reference; the caller becomes responsible for releasing it.
     fn into(self) -> vx_lut {
-        return self.raw;
+        let raw = self.raw;
+        std::mem::forget(self);
+        raw
     }
 }
 
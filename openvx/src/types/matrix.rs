@@ -1,34 +1,353 @@
-use crate::{AsVxReference, VxReference};
-use libopenvx_sys::vx_matrix;
+use crate::error::check_call;
+use crate::types::reference::retain_reference;
+use crate::{
+    attr, Access, AffineTransform, AsRaw, AsVxReference, CheckStatus, Coordinates2d, MatrixElement,
+    MemoryType, Pattern, PerspectiveTransform, QueryAttribute, Release, Result, VxContext, VxError,
+    VxGraph, VxReference, VxStatus,
+};
+use libopenvx_sys::{
+    vxCopyMatrix, vxCreateMatrix, vxCreateMatrixFromPattern, vxCreateMatrixFromPatternAndOrigin,
+    vxCreateVirtualMatrix, vxQueryMatrix, vxReleaseMatrix, vx_enum, vx_matrix, vx_reference,
+    vx_size, vx_status,
+};
+use std::ffi::c_void;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
 
 /// The Matrix Object. An MxN matrix of some unit type.
-#[derive(Debug, Hash, PartialEq, Eq, Copy, Clone)]
-pub struct VxMatrix {
+///
+/// The elements are represented by `T` in host memory; see
+/// [`MatrixElement`](trait.MatrixElement.html) for the supported types. They are stored
+/// row by row, with `columns` elements per row.
+///
+/// The object is bound to the lifetime `'a` of the [`VxContext`](struct.VxContext.html) it was
+/// created in or, for virtual objects, of the [`VxGraph`](struct.VxGraph.html) that owns it.
+///
+/// # Examples
+///
+/// ```no_run
+/// use openvx::{VxContext, VxMatrix};
+///
+/// let context = VxContext::create().unwrap();
+/// let matrix = VxMatrix::from_rows(&context, &[[1i32, 2, 3], [4, 5, 6]]).unwrap();
+/// assert_eq!((matrix.columns().unwrap(), matrix.rows().unwrap()), (3, 2));
+/// assert_eq!(matrix.to_vec().unwrap(), [1, 2, 3, 4, 5, 6]);
+/// ```
+pub struct VxMatrix<'a, T> {
     raw: vx_matrix,
+    _marker: PhantomData<(&'a (), T)>,
 }
 
-impl VxMatrix {
+impl<'a, T: MatrixElement> VxMatrix<'a, T> {
+    /// Creates a matrix with undefined elements by calling [`vxCreateMatrix`].
+    ///
+    /// # Arguments
+    ///
+    /// * `context` - The context in which to create the matrix.
+    /// * `columns` - The first dimensionality.
+    /// * `rows` - The second dimensionality.
+    ///
+    /// [`vxCreateMatrix`]: ../../libopenvx_sys/fn.vxCreateMatrix.html
+    pub fn new(context: &'a VxContext, columns: vx_size, rows: vx_size) -> Result<Self> {
+        let matrix = unsafe {
+            vxCreateMatrix(
+                context.as_raw(),
+                T::DATA_TYPE.to_raw() as vx_enum,
+                columns,
+                rows,
+            )
+        };
+        VxMatrix::from(matrix).into_checked()
+    }
+
+    /// Creates a matrix with `C` columns and `R` rows holding `rows`.
+    pub fn from_rows<const C: usize, const R: usize>(
+        context: &'a VxContext,
+        rows: &[[T; C]; R],
+    ) -> Result<Self> {
+        Self::from_slice(context, C, R, rows.as_flattened())
+    }
+
+    /// Creates a matrix holding `data`, stored row by row.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VxError::InvalidDimension`] if `data` does not hold `columns * rows` elements.
+    ///
+    /// [`VxError::InvalidDimension`]: ../enum.VxError.html#variant.InvalidDimension
+    pub fn from_slice(
+        context: &'a VxContext,
+        columns: vx_size,
+        rows: vx_size,
+        data: &[T],
+    ) -> Result<Self> {
+        if data.len() != columns * rows {
+            return Err(VxError::InvalidDimension.into());
+        }
+
+        let matrix = Self::new(context, columns, rows)?;
+        matrix.copy(data.as_ptr() as *mut c_void, Access::Write)?;
+        Ok(matrix)
+    }
+
+    /// Creates an opaque reference to a matrix object without direct user access
+    /// by calling [`vxCreateVirtualMatrix`].
+    ///
+    /// [`vxCreateVirtualMatrix`]: ../../libopenvx_sys/fn.vxCreateVirtualMatrix.html
+    pub fn virtual_matrix(graph: &'a VxGraph<'_>, columns: vx_size, rows: vx_size) -> Result<Self> {
+        let matrix = unsafe {
+            vxCreateVirtualMatrix(
+                graph.as_raw(),
+                T::DATA_TYPE.to_raw() as vx_enum,
+                columns,
+                rows,
+            )
+        };
+        VxMatrix::from(matrix).into_checked()
+    }
+
+    /// Returns the number of columns.
+    pub fn columns(&self) -> Result<usize> {
+        self.query::<attr::Columns>()
+    }
+
+    /// Returns the number of rows.
+    pub fn rows(&self) -> Result<usize> {
+        self.query::<attr::Rows>()
+    }
+
+    /// Returns the origin of the matrix, which is its center unless specified on creation.
+    pub fn origin(&self) -> Result<Coordinates2d> {
+        self.query::<attr::Origin>()
+    }
+
+    /// Returns the pattern the matrix was created from.
+    pub fn pattern(&self) -> Result<Pattern> {
+        self.query::<attr::Pattern>()
+    }
+
+    /// Writes all elements, stored row by row, by calling [`vxCopyMatrix`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VxError::NotCompatible`] if the data type of the matrix does not match `T`,
+    /// and [`VxError::InvalidDimension`] if `data` does not hold all elements.
+    ///
+    /// [`vxCopyMatrix`]: ../../libopenvx_sys/fn.vxCopyMatrix.html
+    /// [`VxError::NotCompatible`]: ../enum.VxError.html#variant.NotCompatible
+    /// [`VxError::InvalidDimension`]: ../enum.VxError.html#variant.InvalidDimension
+    pub fn copy_from(&self, data: &[T]) -> Result<()> {
+        self.check_len(data.len())?;
+        self.copy(data.as_ptr() as *mut c_void, Access::Write)
+    }
+
+    /// Reads all elements, stored row by row, by calling [`vxCopyMatrix`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VxError::NotCompatible`] if the data type of the matrix does not match `T`,
+    /// and [`VxError::InvalidDimension`] if `data` does not hold all elements.
+    ///
+    /// [`vxCopyMatrix`]: ../../libopenvx_sys/fn.vxCopyMatrix.html
+    /// [`VxError::NotCompatible`]: ../enum.VxError.html#variant.NotCompatible
+    /// [`VxError::InvalidDimension`]: ../enum.VxError.html#variant.InvalidDimension
+    pub fn copy_to(&self, data: &mut [T]) -> Result<()> {
+        self.check_len(data.len())?;
+        self.copy(data.as_mut_ptr() as *mut c_void, Access::Read)
+    }
+
+    /// Reads all elements, stored row by row, into a vector by calling [`vxCopyMatrix`].
+    ///
+    /// [`vxCopyMatrix`]: ../../libopenvx_sys/fn.vxCopyMatrix.html
+    pub fn to_vec(&self) -> Result<Vec<T>> {
+        let mut data = vec![T::default(); self.columns()? * self.rows()?];
+        self.copy_to(&mut data)?;
+        Ok(data)
+    }
+
+    /// Checks that the matrix holds `len` elements of type `T`.
+    fn check_len(&self, len: usize) -> Result<()> {
+        if self.query::<attr::Type>()? != T::DATA_TYPE {
+            return Err(VxError::NotCompatible.into());
+        }
+        if len != self.columns()? * self.rows()? {
+            return Err(VxError::InvalidDimension.into());
+        }
+        Ok(())
+    }
+
+    fn copy(&self, ptr: *mut c_void, access: Access) -> Result<()> {
+        check_call("vxCopyMatrix", self, || unsafe {
+            vxCopyMatrix(
+                self.raw,
+                ptr,
+                access.to_raw() as vx_enum,
+                MemoryType::Host.to_raw() as vx_enum,
+            )
+        })
+    }
+}
+
+impl<'a> VxMatrix<'a, u8> {
+    /// Creates a boolean matrix of the given pattern by calling [`vxCreateMatrixFromPattern`],
+    /// e.g. as mask of `vxNonLinearFilterNode`.
+    ///
+    /// [`vxCreateMatrixFromPattern`]: ../../libopenvx_sys/fn.vxCreateMatrixFromPattern.html
+    pub fn from_pattern(
+        context: &'a VxContext,
+        pattern: Pattern,
+        columns: vx_size,
+        rows: vx_size,
+    ) -> Result<Self> {
+        let matrix = unsafe {
+            vxCreateMatrixFromPattern(context.as_raw(), pattern.to_raw() as vx_enum, columns, rows)
+        };
+        VxMatrix::from(matrix).into_checked()
+    }
+
+    /// Creates a boolean matrix of the given pattern with an origin other than its center
+    /// by calling [`vxCreateMatrixFromPatternAndOrigin`].
+    ///
+    /// [`vxCreateMatrixFromPatternAndOrigin`]: ../../libopenvx_sys/fn.vxCreateMatrixFromPatternAndOrigin.html
+    pub fn from_pattern_with_origin(
+        context: &'a VxContext,
+        pattern: Pattern,
+        columns: vx_size,
+        rows: vx_size,
+        origin: Coordinates2d,
+    ) -> Result<Self> {
+        let matrix = unsafe {
+            vxCreateMatrixFromPatternAndOrigin(
+                context.as_raw(),
+                pattern.to_raw() as vx_enum,
+                columns,
+                rows,
+                origin.x as vx_size,
+                origin.y as vx_size,
+            )
+        };
+        VxMatrix::from(matrix).into_checked()
+    }
+}
+
+impl<'a> VxMatrix<'a, f32> {
+    /// Creates the 2x3 matrix of `vxWarpAffineNode` from an affine transform.
+    ///
+    /// The node maps output pixels to input locations; see
+    /// [`AffineTransform`](../struct.AffineTransform.html).
+    pub fn from_affine(context: &'a VxContext, transform: &AffineTransform) -> Result<Self> {
+        Self::from_rows(context, &transform.to_vx_layout())
+    }
+
+    /// Creates the 3x3 matrix of `vxWarpPerspectiveNode` from a perspective transform.
+    ///
+    /// The node maps output pixels to input locations; see
+    /// [`PerspectiveTransform`](../struct.PerspectiveTransform.html).
+    pub fn from_perspective(
+        context: &'a VxContext,
+        transform: &PerspectiveTransform,
+    ) -> Result<Self> {
+        Self::from_rows(context, &transform.to_vx_layout())
+    }
+}
+
+impl<'a, T> VxMatrix<'a, T> {
     #[allow(dead_code)]
     pub fn is_null(&self) -> bool {
         self.raw.is_null()
     }
 }
 
-impl AsVxReference for VxMatrix {
-    fn as_reference(&self) -> VxReference {
+impl<T> fmt::Debug for VxMatrix<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("VxMatrix").field("raw", &self.raw).finish()
+    }
+}
+
+impl<T> PartialEq for VxMatrix<'_, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.raw == other.raw
+    }
+}
+
+impl<T> Eq for VxMatrix<'_, T> {}
+
+impl<T> Hash for VxMatrix<'_, T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.raw.hash(state);
+    }
+}
+
+impl<'a, T> AsRaw for VxMatrix<'a, T> {
+    type Result = vx_matrix;
+
+    fn as_raw(&self) -> Self::Result {
+        self.raw
+    }
+}
+
+impl<'a, T> AsVxReference for VxMatrix<'a, T> {
+    fn as_reference(&self) -> VxReference<'_> {
         VxReference::from(self.raw)
     }
 }
 
-impl From<vx_matrix> for VxMatrix {
+impl<T> QueryAttribute for VxMatrix<'_, T> {
+    unsafe fn query_raw(&self, attribute: vx_enum, ptr: *mut c_void, size: vx_size) -> vx_status {
+        vxQueryMatrix(self.raw, attribute, ptr, size)
+    }
+}
+
+impl<'a, T> Release for VxMatrix<'a, T> {
+    fn release(&mut self) -> Result<()> {
+        if self.raw.is_null() {
+            return Ok(());
+        }
+
+        let status = unsafe { vxReleaseMatrix(&mut self.raw) };
+        self.raw = std::ptr::null_mut();
+
+        VxStatus::new_result(status, ())
+    }
+}
+
+impl<'a, T> Drop for VxMatrix<'a, T> {
+    fn drop(&mut self) {
+        self.release().unwrap();
+    }
+}
+
+impl<'a, T> Clone for VxMatrix<'a, T> {
+    /// Creates a new handle to the same object, incrementing its reference count
+    /// by calling [`vxRetainReference`](../../libopenvx_sys/fn.vxRetainReference.html).
+    fn clone(&self) -> Self {
+        retain_reference(self.raw as vx_reference);
+        VxMatrix {
+            raw: self.raw,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, T> From<vx_matrix> for VxMatrix<'a, T> {
+    /// Takes ownership of the reference; it will be released when the wrapper is dropped.
+    ///
+    /// The data type of the matrix is not checked against `T`.
     fn from(value: vx_matrix) -> Self {
-        VxMatrix { raw: value }
+        VxMatrix {
+            raw: value,
+            _marker: PhantomData,
+        }
     }
 }
 
-impl Into<vx_matrix> for VxMatrix {
+impl<'a, T> Into<vx_matrix> for VxMatrix<'a, T> {
+    /// Gives up ownership of the reference; the caller becomes responsible for releasing it.
     fn into(self) -> vx_matrix {
-        return self.raw;
+        let raw = self.raw;
+        std::mem::forget(self);
+        raw
     }
 }
 
@@ -38,6 +357,6 @@ mod tests {
 
     #[test]
     fn is_null() {
-        assert!(VxMatrix::from(std::ptr::null_mut()).is_null());
+        assert!(VxMatrix::<f32>::from(std::ptr::null_mut()).is_null());
     }
 }
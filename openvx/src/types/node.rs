@@ -1,35 +1,186 @@
-use crate::{AsVxReference, VxReference};
-use libopenvx_sys::vx_node;
+use crate::types::reference::retain_reference;
+use crate::{
+    AsRaw, AsVxReference, CheckStatus, QueryAttribute, Release, Result, SetAttribute, VxGraph,
+    VxKernel, VxReference, VxStatus,
+};
+#[cfg(feature = "tracing")]
+use libopenvx_sys::{
+    vxAssignNodeCallback, vxRetrieveNodeCallback, vx_action, vx_action_e_VX_ACTION_CONTINUE,
+};
+use libopenvx_sys::{
+    vxCreateGenericNode, vxQueryNode, vxReleaseNode, vxSetNodeAttribute, vx_enum, vx_node,
+    vx_reference, vx_size, vx_status,
+};
+use std::ffi::c_void;
+use std::marker::PhantomData;
 
 /// An opaque reference to a kernel node.
-#[derive(Debug, Hash, PartialEq, Eq, Copy, Clone)]
-pub struct VxNode {
+///
+/// The node is bound to the lifetime `'graph` of the [`VxGraph`](struct.VxGraph.html) it was created in.
+#[derive(Debug, Hash, PartialEq, Eq)]
+pub struct VxNode<'graph> {
     raw: vx_node,
+    _marker: PhantomData<&'graph ()>,
 }
 
-impl VxNode {
+impl<'graph> VxNode<'graph> {
+    /// Creates a reference to a node object for a given kernel by calling [`vxCreateGenericNode`].
+    ///
+    /// The parameters of the node have to be set using `vxSetParameterByIndex` before
+    /// the graph is verified.
+    ///
+    /// [`vxCreateGenericNode`]: ../../libopenvx_sys/fn.vxCreateGenericNode.html
+    pub fn create_generic(
+        graph: &'graph VxGraph<'_>,
+        kernel: &VxKernel<'_>,
+    ) -> Result<VxNode<'graph>> {
+        let node = unsafe { vxCreateGenericNode(graph.as_raw(), kernel.as_raw()) };
+        VxNode::from(node).into_checked()
+    }
+
+    /// Emits a `tracing` span for every completed execution of this node by assigning a
+    /// completion callback through [`vxAssignNodeCallback`].
+    ///
+    /// OpenVX only notifies the host after a node has completed, so the span is a completion
+    /// event: it is created and closed within the callback and its own duration is meaningless.
+    /// The time the node took is recorded in the `perf.tmp_ns` field instead. The span is named
+    /// `node` and carries the node's name, its status and its performance measurements as
+    /// `perf.*` fields.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VxError::NotSupported`] if a different callback is already assigned to the node.
+    ///
+    /// [`vxAssignNodeCallback`]: ../../libopenvx_sys/fn.vxAssignNodeCallback.html
+    /// [`VxError::NotSupported`]: ../enum.VxError.html#variant.NotSupported
+    #[cfg(feature = "tracing")]
+    pub fn trace_execution(&self) -> Result<&Self> {
+        match unsafe { vxRetrieveNodeCallback(self.raw) } {
+            None => {}
+            Some(callback) if callback as *const () == trace_completion as *const () => {
+                return Ok(self)
+            }
+            Some(_) => return Err(crate::VxError::NotSupported.into()),
+        }
+
+        let status = unsafe { vxAssignNodeCallback(self.raw, Some(trace_completion)) };
+        VxStatus::new_result(status, self)
+    }
+
     #[allow(dead_code)]
     pub fn is_null(&self) -> bool {
         self.raw.is_null()
     }
 }
 
-impl AsVxReference for VxNode {
-    fn as_reference(&self) -> VxReference {
+impl<'graph> AsRaw for VxNode<'graph> {
+    type Result = vx_node;
+
+    fn as_raw(&self) -> Self::Result {
+        self.raw
+    }
+}
+
+impl<'graph> AsVxReference for VxNode<'graph> {
+    fn as_reference(&self) -> VxReference<'_> {
         VxReference::from(self.raw)
     }
 }
 
-impl From<vx_node> for VxNode {
+impl QueryAttribute for VxNode<'_> {
+    unsafe fn query_raw(&self, attribute: vx_enum, ptr: *mut c_void, size: vx_size) -> vx_status {
+        vxQueryNode(self.raw, attribute, ptr, size)
+    }
+}
+
+impl SetAttribute for VxNode<'_> {
+    unsafe fn set_raw(&self, attribute: vx_enum, ptr: *const c_void, size: vx_size) -> vx_status {
+        vxSetNodeAttribute(self.raw, attribute, ptr, size)
+    }
+}
+
+impl<'graph> Release for VxNode<'graph> {
+    fn release(&mut self) -> Result<()> {
+        if self.raw.is_null() {
+            return Ok(());
+        }
+
+        let status = unsafe { vxReleaseNode(&mut self.raw) };
+        self.raw = std::ptr::null_mut();
+
+        VxStatus::new_result(status, ())
+    }
+}
+
+impl<'graph> Drop for VxNode<'graph> {
+    fn drop(&mut self) {
+        self.release().unwrap();
+    }
+}
+
+impl<'graph> Clone for VxNode<'graph> {
+    /// Creates a new handle to the same object, incrementing its reference count
+    /// by calling [`vxRetainReference`](../../libopenvx_sys/fn.vxRetainReference.html).
+    fn clone(&self) -> Self {
+        retain_reference(self.raw as vx_reference);
+        VxNode {
+            raw: self.raw,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'graph> From<vx_node> for VxNode<'graph> {
+    /// Takes ownership of the reference; it will be released when the wrapper is dropped.
     fn from(value: vx_node) -> Self {
-        VxNode { raw: value }
+        VxNode {
+            raw: value,
+            _marker: PhantomData,
+        }
     }
 }
 
-impl Into<vx_node> for VxNode {
+impl<'graph> Into<vx_node> for VxNode<'graph> {
+    /// Gives up ownership of the reference; the caller becomes responsible for releasing it.
     fn into(self) -> vx_node {
-        return self.raw;
+        let raw = self.raw;
+        std::mem::forget(self);
+        raw
+    }
+}
+
+/// The completion callback assigned by [`VxNode::trace_execution`].
+#[cfg(feature = "tracing")]
+unsafe extern "C" fn trace_completion(node: vx_node) -> vx_action {
+    // Unwinding into the implementation is undefined; swallow panics of subscribers instead.
+    let _ = std::panic::catch_unwind(|| trace_node(node));
+    vx_action_e_VX_ACTION_CONTINUE as vx_action
+}
+
+#[cfg(feature = "tracing")]
+fn trace_node(node: vx_node) {
+    use crate::{attr, GetName};
+
+    // The node is borrowed from the implementation and must not be released.
+    let node = std::mem::ManuallyDrop::new(VxNode::from(node));
+    let span = tracing::info_span!(
+        "node",
+        node = %node.get_name().ok().flatten().unwrap_or_default(),
+        status = tracing::field::Empty,
+        perf.tmp_ns = tracing::field::Empty,
+        perf.avg_ns = tracing::field::Empty,
+        perf.min_ns = tracing::field::Empty,
+        perf.max_ns = tracing::field::Empty,
+        perf.num = tracing::field::Empty,
+    );
+
+    if let Ok(status) = node.query::<attr::Status>() {
+        span.record("status", tracing::field::display(status));
+    }
+    if let Ok(performance) = node.query::<attr::Performance>() {
+        performance.record(&span);
     }
+    span.in_scope(|| tracing::trace!("node completed"));
 }
 
 #[cfg(test)]
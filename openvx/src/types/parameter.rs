@@ -1,34 +1,126 @@
-use crate::{AsVxReference, VxReference};
-use libopenvx_sys::vx_parameter;
+use crate::types::reference::retain_reference;
+use crate::{
+    attr, AsRaw, AsVxReference, CheckStatus, ParameterInfo, QueryAttribute, Release, Result,
+    VxKernel, VxNode, VxReference, VxStatus,
+};
+use libopenvx_sys::{
+    vxGetKernelParameterByIndex, vxGetParameterByIndex, vxQueryParameter, vxReleaseParameter,
+    vx_enum, vx_parameter, vx_reference, vx_size, vx_status, vx_uint32,
+};
+use std::ffi::c_void;
+use std::marker::PhantomData;
 
 /// An opaque reference to a single parameter.
-#[derive(Debug, Hash, PartialEq, Eq, Copy, Clone)]
-pub struct VxParameter {
+///
+/// The parameter is bound to the lifetime `'a` of the [`VxNode`](struct.VxNode.html) or
+/// [`VxKernel`](struct.VxKernel.html) it was obtained from.
+#[derive(Debug, Hash, PartialEq, Eq)]
+pub struct VxParameter<'a> {
     raw: vx_parameter,
+    _marker: PhantomData<&'a ()>,
 }
 
-impl VxParameter {
+impl<'a> VxParameter<'a> {
+    /// Retrieves a node parameter by index by calling [`vxGetParameterByIndex`].
+    ///
+    /// [`vxGetParameterByIndex`]: ../../libopenvx_sys/fn.vxGetParameterByIndex.html
+    pub fn from_node(node: &'a VxNode<'_>, index: vx_uint32) -> Result<VxParameter<'a>> {
+        let parameter = unsafe { vxGetParameterByIndex(node.as_raw(), index) };
+        VxParameter::from(parameter).into_checked()
+    }
+
+    /// Retrieves a kernel parameter by index by calling [`vxGetKernelParameterByIndex`].
+    ///
+    /// [`vxGetKernelParameterByIndex`]: ../../libopenvx_sys/fn.vxGetKernelParameterByIndex.html
+    pub fn from_kernel(kernel: &'a VxKernel<'_>, index: vx_uint32) -> Result<VxParameter<'a>> {
+        let parameter = unsafe { vxGetKernelParameterByIndex(kernel.as_raw(), index) };
+        VxParameter::from(parameter).into_checked()
+    }
+
+    /// Returns the index (`VX_PARAMETER_INDEX`), direction (`VX_PARAMETER_DIRECTION`),
+    /// type (`VX_PARAMETER_TYPE`) and state (`VX_PARAMETER_STATE`) of the parameter.
+    pub fn info(&self) -> Result<ParameterInfo> {
+        Ok(ParameterInfo {
+            index: self.query::<attr::Index>()?,
+            direction: self.query::<attr::Direction>()?,
+            r#type: self.query::<attr::Type>()?,
+            state: self.query::<attr::State>()?,
+        })
+    }
+
     #[allow(dead_code)]
     pub fn is_null(&self) -> bool {
         self.raw.is_null()
     }
 }
 
-impl AsVxReference for VxParameter {
-    fn as_reference(&self) -> VxReference {
+impl<'a> AsRaw for VxParameter<'a> {
+    type Result = vx_parameter;
+
+    fn as_raw(&self) -> Self::Result {
+        self.raw
+    }
+}
+
+impl<'a> AsVxReference for VxParameter<'a> {
+    fn as_reference(&self) -> VxReference<'_> {
         VxReference::from(self.raw)
     }
 }
 
-impl From<vx_parameter> for VxParameter {
+impl QueryAttribute for VxParameter<'_> {
+    unsafe fn query_raw(&self, attribute: vx_enum, ptr: *mut c_void, size: vx_size) -> vx_status {
+        vxQueryParameter(self.raw, attribute, ptr, size)
+    }
+}
+
+impl<'a> Release for VxParameter<'a> {
+    fn release(&mut self) -> Result<()> {
+        if self.raw.is_null() {
+            return Ok(());
+        }
+
+        let status = unsafe { vxReleaseParameter(&mut self.raw) };
+        self.raw = std::ptr::null_mut();
+
+        VxStatus::new_result(status, ())
+    }
+}
+
+impl<'a> Drop for VxParameter<'a> {
+    fn drop(&mut self) {
+        self.release().unwrap();
+    }
+}
+
+impl<'a> Clone for VxParameter<'a> {
+    /// Creates a new handle to the same object, incrementing its reference count
+    /// by calling [`vxRetainReference`](../../libopenvx_sys/fn.vxRetainReference.html).
+    fn clone(&self) -> Self {
+        retain_reference(self.raw as vx_reference);
+        VxParameter {
+            raw: self.raw,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'a> From<vx_parameter> for VxParameter<'a> {
+    /// Takes ownership of the reference; it will be released when the wrapper is dropped.
     fn from(value: vx_parameter) -> Self {
-        VxParameter { raw: value }
+        VxParameter {
+            raw: value,
+            _marker: PhantomData,
+        }
     }
 }
 
-impl Into<vx_parameter> for VxParameter {
+impl<'a> Into<vx_parameter> for VxParameter<'a> {
+    /// Gives up ownership of the reference; the caller becomes responsible for releasing it.
     fn into(self) -> vx_parameter {
-        return self.raw;
+        let raw = self.raw;
+        std::mem::forget(self);
+        raw
     }
 }
 
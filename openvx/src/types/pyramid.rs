@@ -1,33 +1,162 @@
-use crate::{AsVxReference, VxReference};
-use libopenvx_sys::vx_pyramid;
+use crate::types::reference::retain_reference;
+use crate::{
+    AsRaw, AsVxReference, CheckStatus, ImageType, QueryAttribute, Release, Result, VxContext,
+    VxGraph, VxReference, VxStatus,
+};
+use libopenvx_sys::{
+    vxCreatePyramid, vxCreateVirtualPyramid, vxQueryPyramid, vxReleasePyramid, vx_enum, vx_float32,
+    vx_pyramid, vx_reference, vx_size, vx_status, vx_uint32,
+};
+use std::ffi::c_void;
+use std::marker::PhantomData;
 
-#[derive(Debug, Hash, PartialEq, Eq, Copy, Clone)]
-pub struct VxPyramid {
+/// The Image Pyramid object. A set of scaled images.
+///
+/// The object is bound to the lifetime `'a` of the [`VxContext`](struct.VxContext.html) it was
+/// created in or, for virtual objects, of the [`VxGraph`](struct.VxGraph.html) that owns it.
+#[derive(Debug, Hash, PartialEq, Eq)]
+pub struct VxPyramid<'a> {
     raw: vx_pyramid,
+    _marker: PhantomData<&'a ()>,
 }
 
-impl VxPyramid {
+impl<'a> VxPyramid<'a> {
+    /// Creates a reference to a pyramid object of the supplied number of levels
+    /// by calling [`vxCreatePyramid`].
+    ///
+    /// # Arguments
+    ///
+    /// * `context` - The context in which to create the pyramid.
+    /// * `levels` - The number of levels desired. This is required to be a non-zero value.
+    /// * `scale` - Used to indicate the scale between pyramid levels, e.g. `0.5` for halving
+    ///   the size on each level.
+    /// * `width` - The width of the 0th level image in pixels.
+    /// * `height` - The height of the 0th level image in pixels.
+    /// * `format` - The format of all images in the pyramid.
+    ///
+    /// [`vxCreatePyramid`]: ../../libopenvx_sys/fn.vxCreatePyramid.html
+    pub fn new(
+        context: &'a VxContext,
+        levels: vx_size,
+        scale: vx_float32,
+        width: vx_uint32,
+        height: vx_uint32,
+        format: ImageType,
+    ) -> Result<VxPyramid<'a>> {
+        let pyramid = unsafe {
+            vxCreatePyramid(
+                context.as_raw(),
+                levels,
+                scale,
+                width,
+                height,
+                format.to_raw(),
+            )
+        };
+        VxPyramid::from(pyramid).into_checked()
+    }
+
+    /// Creates a reference to a virtual pyramid object of the supplied number of levels
+    /// by calling [`vxCreateVirtualPyramid`].
+    ///
+    /// Width, height and format may be left unspecified (`0` or [`ImageType::Virtual`]).
+    ///
+    /// [`vxCreateVirtualPyramid`]: ../../libopenvx_sys/fn.vxCreateVirtualPyramid.html
+    /// [`ImageType::Virtual`]: ../enum.ImageType.html#variant.Virtual
+    pub fn virtual_pyramid(
+        graph: &'a VxGraph<'_>,
+        levels: vx_size,
+        scale: vx_float32,
+        width: vx_uint32,
+        height: vx_uint32,
+        format: ImageType,
+    ) -> Result<VxPyramid<'a>> {
+        let pyramid = unsafe {
+            vxCreateVirtualPyramid(
+                graph.as_raw(),
+                levels,
+                scale,
+                width,
+                height,
+                format.to_raw(),
+            )
+        };
+        VxPyramid::from(pyramid).into_checked()
+    }
+
     #[allow(dead_code)]
     pub fn is_null(&self) -> bool {
         self.raw.is_null()
     }
 }
 
-impl AsVxReference for VxPyramid {
-    fn as_reference(&self) -> VxReference {
+impl<'a> AsRaw for VxPyramid<'a> {
+    type Result = vx_pyramid;
+
+    fn as_raw(&self) -> Self::Result {
+        self.raw
+    }
+}
+
+impl<'a> AsVxReference for VxPyramid<'a> {
+    fn as_reference(&self) -> VxReference<'_> {
         VxReference::from(self.raw)
     }
 }
 
-impl From<vx_pyramid> for VxPyramid {
+impl QueryAttribute for VxPyramid<'_> {
+    unsafe fn query_raw(&self, attribute: vx_enum, ptr: *mut c_void, size: vx_size) -> vx_status {
+        vxQueryPyramid(self.raw, attribute, ptr, size)
+    }
+}
+
+impl<'a> Release for VxPyramid<'a> {
+    fn release(&mut self) -> Result<()> {
+        if self.raw.is_null() {
+            return Ok(());
+        }
+
+        let status = unsafe { vxReleasePyramid(&mut self.raw) };
+        self.raw = std::ptr::null_mut();
+
+        VxStatus::new_result(status, ())
+    }
+}
+
+impl<'a> Drop for VxPyramid<'a> {
+    fn drop(&mut self) {
+        self.release().unwrap();
+    }
+}
+
+impl<'a> Clone for VxPyramid<'a> {
+    /// Creates a new handle to the same object, incrementing its reference count
+    /// by calling [`vxRetainReference`](../../libopenvx_sys/fn.vxRetainReference.html).
+    fn clone(&self) -> Self {
+        retain_reference(self.raw as vx_reference);
+        VxPyramid {
+            raw: self.raw,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'a> From<vx_pyramid> for VxPyramid<'a> {
+    /// Takes ownership of the reference; it will be released when the wrapper is dropped.
     fn from(value: vx_pyramid) -> Self {
-        VxPyramid { raw: value }
+        VxPyramid {
+            raw: value,
+            _marker: PhantomData,
+        }
     }
 }
 
-impl Into<vx_pyramid> for VxPyramid {
+impl<'a> Into<vx_pyramid> for VxPyramid<'a> {
+    /// Gives up ownership of the reference; the caller becomes responsible for releasing it.
     fn into(self) -> vx_pyramid {
-        return self.raw;
+        let raw = self.raw;
+        std::mem::forget(self);
+        raw
     }
 }
 
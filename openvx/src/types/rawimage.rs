@@ -0,0 +1,327 @@
+use crate::{
+    Access, AsRaw, AsVxReference, CfaPattern, CheckStatus, ExposureInterleaving, ImagePatch,
+    PixelType, RawBuffer, RawFormat, Rect, Release, Result, VxContext, VxError, VxGraph, VxImage,
+    VxReference,
+};
+use libopenvx_sys::{
+    vxCreateRawImage, vxCreateVirtualRawImage, vx_image, vx_image_raw_create_params_t,
+    vx_image_raw_format_t, vx_uint32,
+};
+use std::ops::Deref;
+
+/// The maximum number of exposures of a raw image (`VX_IMAGE_RAW_MAX_EXPOSURES`).
+const MAX_EXPOSURES: usize = 3;
+
+/// The parameters of a raw image (`vx_image_raw_create_params_t`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RawImageParams {
+    /// The width in pixels.
+    pub width: vx_uint32,
+    /// The height in lines, not including meta data lines.
+    pub height: vx_uint32,
+    /// The number of exposures of a sensor readout, at most three.
+    pub exposures: vx_uint32,
+    /// The memory layout of the exposures.
+    pub interleaving: ExposureInterleaving,
+    /// The format of each exposure, or a single format shared by interleaved exposures.
+    pub formats: Vec<RawFormat>,
+    /// The number of meta data lines before the pixels.
+    pub meta_height_before: vx_uint32,
+    /// The number of meta data lines after the pixels.
+    pub meta_height_after: vx_uint32,
+    /// The color filter array of the sensor; only kept on the host.
+    pub cfa: CfaPattern,
+}
+
+impl RawImageParams {
+    /// Creates the parameters of a single exposure without meta data.
+    pub fn new(width: vx_uint32, height: vx_uint32, format: RawFormat, cfa: CfaPattern) -> Self {
+        Self {
+            width,
+            height,
+            exposures: 1,
+            interleaving: ExposureInterleaving::Planar,
+            formats: vec![format],
+            meta_height_before: 0,
+            meta_height_after: 0,
+            cfa,
+        }
+    }
+
+    /// Returns the format of exposure `exposure`.
+    pub fn format(&self, exposure: u32) -> Option<RawFormat> {
+        match self.interleaving {
+            ExposureInterleaving::Planar => self.formats.get(exposure as usize).copied(),
+            _ if exposure < self.exposures => self.formats.first().copied(),
+            _ => None,
+        }
+    }
+
+    /// Converts this instance into a [`vx_image_raw_create_params_t`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VxError::InvalidParameters`] if there are no or more than three exposures
+    /// or formats, or if planar exposures do not have one format each.
+    ///
+    /// [`vx_image_raw_create_params_t`]: ../../libopenvx_sys/type.vx_image_raw_create_params_t.html
+    /// [`VxError::InvalidParameters`]: ../enum.VxError.html#variant.InvalidParameters
+    pub fn to_raw(&self) -> Result<vx_image_raw_create_params_t> {
+        let exposures = self.exposures as usize;
+        if !(1..=MAX_EXPOSURES).contains(&exposures)
+            || !(1..=MAX_EXPOSURES).contains(&self.formats.len())
+            || (self.interleaving == ExposureInterleaving::Planar
+                && self.formats.len() != exposures)
+        {
+            return Err(VxError::InvalidParameters.into());
+        }
+
+        let mut format = [vx_image_raw_format_t {
+            pixel_container: 0,
+            msb: 0,
+        }; MAX_EXPOSURES];
+        for (raw, format) in format.iter_mut().zip(&self.formats) {
+            *raw = format.to_raw();
+        }
+
+        Ok(vx_image_raw_create_params_t {
+            width: self.width,
+            height: self.height,
+            num_exposures: self.exposures,
+            exposure_interleaving: self.interleaving.to_raw(),
+            format,
+            meta_height_before: self.meta_height_before,
+            meta_height_after: self.meta_height_after,
+        })
+    }
+}
+
+/// A raw sensor image created through the `vx_khr_raw_image` extension.
+///
+/// The wrapper keeps the parameters the image was created with, including the
+/// [`CfaPattern`](enum.CfaPattern.html), and dereferences to the
+/// [`VxImage`](struct.VxImage.html) to pass it to nodes accepting raw images.
+///
+/// # Examples
+///
+/// ```no_run
+/// use openvx::{
+///     Access, CfaPattern, PixelContainer, RawBuffer, RawFormat, RawImageParams, Rect, VxContext,
+///     VxRawImage,
+/// };
+///
+/// let context = VxContext::create().unwrap();
+/// let format = RawFormat::with_bit_depth(PixelContainer::Bit16, 12);
+/// let params = RawImageParams::new(1920, 1080, format, CfaPattern::Rggb);
+/// let image = VxRawImage::new(&context, params).unwrap();
+///
+/// let patch = image
+///     .map_patch::<u16>(Rect::new(0, 0, 1920, 1080), 0, RawBuffer::Pixel, Access::Read)
+///     .unwrap();
+/// let red = patch.pixel(0, 0);
+/// ```
+#[derive(Debug, Clone)]
+pub struct VxRawImage<'a> {
+    image: VxImage<'a>,
+    params: RawImageParams,
+}
+
+impl<'a> VxRawImage<'a> {
+    /// Creates a raw image by calling [`vxCreateRawImage`].
+    ///
+    /// [`vxCreateRawImage`]: ../../libopenvx_sys/fn.vxCreateRawImage.html
+    pub fn new(context: &'a VxContext, params: RawImageParams) -> Result<Self> {
+        let raw = params.to_raw()?;
+        let image = unsafe {
+            vxCreateRawImage(
+                context.as_raw(),
+                &raw,
+                std::mem::size_of::<vx_image_raw_create_params_t>(),
+            )
+        };
+        Ok(Self {
+            image: VxImage::from(image).into_checked()?,
+            params,
+        })
+    }
+
+    /// Creates a virtual raw image by calling [`vxCreateVirtualRawImage`].
+    ///
+    /// [`vxCreateVirtualRawImage`]: ../../libopenvx_sys/fn.vxCreateVirtualRawImage.html
+    pub fn virtual_image(graph: &'a VxGraph<'_>, params: RawImageParams) -> Result<Self> {
+        let raw = params.to_raw()?;
+        let image = unsafe {
+            vxCreateVirtualRawImage(
+                graph.as_raw(),
+                &raw,
+                std::mem::size_of::<vx_image_raw_create_params_t>(),
+            )
+        };
+        Ok(Self {
+            image: VxImage::from(image).into_checked()?,
+            params,
+        })
+    }
+
+    /// Returns the parameters the image was created with.
+    pub fn params(&self) -> &RawImageParams {
+        &self.params
+    }
+
+    /// Returns the color filter array of the sensor.
+    pub fn cfa(&self) -> CfaPattern {
+        self.params.cfa
+    }
+
+    /// Returns the image.
+    pub fn image(&self) -> &VxImage<'a> {
+        &self.image
+    }
+
+    /// Maps a patch of buffer `buffer` of exposure `exposure` into host memory by calling
+    /// [`vxMapImagePatch`].
+    ///
+    /// The element type `T` has to match the container of the exposure, i.e. `u8` for
+    /// [`PixelContainer::Bit8`] and `u16` for [`PixelContainer::Bit16`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VxError::NotCompatible`] if `T` does not match the container and
+    /// [`VxError::NotSupported`] for packed containers.
+    ///
+    /// [`vxMapImagePatch`]: ../../libopenvx_sys/fn.vxMapImagePatch.html
+    /// [`PixelContainer::Bit8`]: ../enum.PixelContainer.html#variant.Bit8
+    /// [`PixelContainer::Bit16`]: ../enum.PixelContainer.html#variant.Bit16
+    /// [`VxError::NotCompatible`]: ../enum.VxError.html#variant.NotCompatible
+    /// [`VxError::NotSupported`]: ../enum.VxError.html#variant.NotSupported
+    pub fn map_patch<T: PixelType>(
+        &self,
+        rect: Rect,
+        exposure: u32,
+        buffer: RawBuffer,
+        access: Access,
+    ) -> Result<ImagePatch<'_, T>> {
+        self.check_element::<T>(exposure)?;
+        ImagePatch::map_with_flags(&self.image, rect, exposure, access, buffer.to_raw())
+    }
+
+    /// Writes `data` into the region `rect` of buffer `buffer` of exposure `exposure`
+    /// by calling [`vxCopyImagePatchWithFlags`].
+    ///
+    /// `data` holds rows with `stride` elements between the starts of consecutive rows.
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of [`map_patch`](#method.map_patch), and [`VxError::InvalidDimension`]
+    /// if `stride` or `data` are too small for the region.
+    ///
+    /// [`vxCopyImagePatchWithFlags`]: ../../libopenvx_sys/fn.vxCopyImagePatchWithFlags.html
+    /// [`VxError::InvalidDimension`]: ../enum.VxError.html#variant.InvalidDimension
+    pub fn copy_from_slice<T: PixelType>(
+        &self,
+        exposure: u32,
+        rect: Rect,
+        buffer: RawBuffer,
+        data: &[T],
+        stride: usize,
+    ) -> Result<()> {
+        self.check_element::<T>(exposure)?;
+        let flags = Some(buffer.to_raw());
+        self.image
+            .write_patch(exposure, rect, (1, 1), data, stride, flags)
+    }
+
+    /// Reads the region `rect` of buffer `buffer` of exposure `exposure` into a tightly packed
+    /// vector by calling [`vxCopyImagePatchWithFlags`].
+    ///
+    /// [`vxCopyImagePatchWithFlags`]: ../../libopenvx_sys/fn.vxCopyImagePatchWithFlags.html
+    pub fn copy_to_vec<T: PixelType>(
+        &self,
+        exposure: u32,
+        rect: Rect,
+        buffer: RawBuffer,
+    ) -> Result<Vec<T>> {
+        self.check_element::<T>(exposure)?;
+        self.image
+            .read_patch(exposure, rect, (1, 1), Some(buffer.to_raw()))
+    }
+
+    /// Checks that `T` represents the pixels of exposure `exposure`.
+    fn check_element<T: PixelType>(&self, exposure: u32) -> Result<()> {
+        let format = self
+            .params
+            .format(exposure)
+            .ok_or(VxError::InvalidParameters)?;
+        match format.container.bytes_per_pixel() {
+            None => Err(VxError::NotSupported.into()),
+            Some(bytes) if bytes != std::mem::size_of::<T>() => Err(VxError::NotCompatible.into()),
+            Some(_) => Ok(()),
+        }
+    }
+}
+
+impl<'a> Deref for VxRawImage<'a> {
+    type Target = VxImage<'a>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.image
+    }
+}
+
+impl AsRaw for VxRawImage<'_> {
+    type Result = vx_image;
+
+    fn as_raw(&self) -> Self::Result {
+        self.image.as_raw()
+    }
+}
+
+impl AsVxReference for VxRawImage<'_> {
+    fn as_reference(&self) -> VxReference<'_> {
+        self.image.as_reference()
+    }
+}
+
+impl Release for VxRawImage<'_> {
+    fn release(&mut self) -> Result<()> {
+        self.image.release()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PixelContainer;
+
+    #[test]
+    fn params_to_raw() {
+        let format = RawFormat::with_bit_depth(PixelContainer::Bit16, 10);
+        let mut params = RawImageParams::new(64, 48, format, CfaPattern::Bggr);
+        params.meta_height_before = 2;
+
+        let raw = params.to_raw().unwrap();
+        assert_eq!((raw.width, raw.height, raw.num_exposures), (64, 48, 1));
+        assert_eq!(RawFormat::from(raw.format[0]), format);
+        assert_eq!(raw.meta_height_before, 2);
+
+        params.exposures = 2;
+        assert_eq!(params.to_raw().unwrap_err(), VxError::InvalidParameters);
+        params.interleaving = ExposureInterleaving::LineInterleaved;
+        assert!(params.to_raw().is_ok());
+
+        params.formats.clear();
+        assert_eq!(params.to_raw().unwrap_err(), VxError::InvalidParameters);
+    }
+
+    #[test]
+    fn formats_of_interleaved_exposures() {
+        let format = RawFormat::with_bit_depth(PixelContainer::Bit8, 8);
+        let mut params = RawImageParams::new(64, 48, format, CfaPattern::Rggb);
+        params.exposures = 2;
+        assert_eq!(params.format(1), None);
+
+        params.interleaving = ExposureInterleaving::LineInterleaved;
+        assert_eq!(params.format(1), Some(format));
+        assert_eq!(params.format(2), None);
+    }
+}
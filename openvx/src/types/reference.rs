@@ -1,23 +1,29 @@
-use crate::name::set_name;
-use crate::{CheckStatus, Result, SetName, VxStatus};
+use crate::attr;
+use crate::{CheckStatus, QueryAttribute, Result, VxStatus};
 use libopenvx_sys::{
-    vxGetStatus, vxQueryReference, vx_context, vx_convolution, vx_delay, vx_distribution, vx_enum,
-    vx_graph, vx_image, vx_kernel, vx_lut, vx_matrix, vx_node, vx_parameter, vx_pyramid,
-    vx_reference, vx_reference_attribute_e_VX_REFERENCE_COUNT, vx_scalar, vx_size, vx_threshold,
-    vx_uint32,
+    vxGetStatus, vxQueryReference, vxRetainReference, vx_array, vx_context, vx_convolution,
+    vx_delay, vx_distribution, vx_enum, vx_graph, vx_image, vx_kernel, vx_lut, vx_matrix, vx_node,
+    vx_parameter, vx_pyramid, vx_reference, vx_remap, vx_scalar, vx_size, vx_status, vx_tensor,
+    vx_threshold,
 };
-use std::borrow::Borrow;
+use std::ffi::c_void;
+use std::marker::PhantomData;
 
 pub trait AsVxReference {
-    fn as_reference(&self) -> VxReference;
+    fn as_reference(&self) -> VxReference<'_>;
 }
 
+/// A non-owning, borrowed reference to any OpenVX object.
+///
+/// The reference is bound to the lifetime `'a` of the object it was obtained from
+/// and does not affect the object's reference count.
 #[derive(Debug, Hash, PartialEq, Eq)]
-pub struct VxReference {
+pub struct VxReference<'a> {
     raw: vx_reference,
+    _marker: PhantomData<&'a ()>,
 }
 
-impl VxReference {
+impl VxReference<'_> {
     pub fn is_null(&self) -> bool {
         self.raw.is_null()
     }
@@ -25,7 +31,7 @@ impl VxReference {
 
 pub trait ReferenceCount {
     /// Returns the reference count of the object.
-    fn get_reference_count(&self) -> usize;
+    fn get_reference_count(&self) -> Result<usize>;
 }
 
 impl<P> ReferenceCount for P
@@ -33,20 +39,24 @@ where
     P: AsVxReference,
 {
     /// Returns the reference count of the object.
-    fn get_reference_count(&self) -> usize {
-        let mut ref_count: vx_uint32 = 0;
-
-        unsafe {
-            vxQueryReference(
-                self.as_reference().raw,
-                vx_reference_attribute_e_VX_REFERENCE_COUNT as vx_enum,
-                &mut ref_count as *mut _ as *mut std::ffi::c_void,
-                std::mem::size_of_val(&ref_count) as vx_size,
-            );
-        }
+    fn get_reference_count(&self) -> Result<usize> {
+        let count = self.as_reference().query::<attr::ReferenceCount>()?;
+        Ok(count as usize)
+    }
+}
 
-        ref_count as usize
+/// Increments the reference count of the object by calling [`vxRetainReference`].
+///
+/// Null references are ignored.
+///
+/// [`vxRetainReference`]: ../../libopenvx_sys/fn.vxRetainReference.html
+pub(crate) fn retain_reference(reference: vx_reference) {
+    if reference.is_null() {
+        return;
     }
+
+    let status = unsafe { vxRetainReference(reference) };
+    VxStatus::new_result(status, ()).expect("Retaining reference failed");
 }
 
 impl<P> CheckStatus for P
@@ -56,143 +66,189 @@ where
     fn check_status(&self) -> Result<&Self> {
         let status = unsafe { vxGetStatus(self.as_reference().raw) };
         return if let VxStatus::Error(error) = VxStatus::from(status) {
-            Err(error)
+            Err(error.into())
         } else {
             Ok(self)
         };
     }
 }
 
-impl SetName for VxReference {
-    fn set_name<S>(&self, name: S) -> &Self
-    where
-        S: Borrow<str>,
-    {
-        set_name(self.raw, name);
-        self
+impl QueryAttribute for VxReference<'_> {
+    unsafe fn query_raw(&self, attribute: vx_enum, ptr: *mut c_void, size: vx_size) -> vx_status {
+        vxQueryReference(self.raw, attribute, ptr, size)
+    }
+}
+
+impl AsVxReference for VxReference<'_> {
+    fn as_reference(&self) -> VxReference<'_> {
+        VxReference::from(self.raw)
     }
 }
 
-impl From<vx_reference> for VxReference {
+impl From<vx_reference> for VxReference<'_> {
     fn from(value: vx_reference) -> Self {
-        VxReference { raw: value }
+        VxReference {
+            raw: value,
+            _marker: PhantomData,
+        }
     }
 }
 
-impl Into<vx_reference> for VxReference {
+impl Into<vx_reference> for VxReference<'_> {
     fn into(self) -> vx_reference {
         return self.raw;
     }
 }
 
-impl From<vx_graph> for VxReference {
+impl From<vx_graph> for VxReference<'_> {
     fn from(value: vx_graph) -> Self {
         VxReference {
             raw: value as vx_reference,
+            _marker: PhantomData,
         }
     }
 }
 
-impl From<vx_node> for VxReference {
+impl From<vx_node> for VxReference<'_> {
     fn from(value: vx_node) -> Self {
         VxReference {
             raw: value as vx_reference,
+            _marker: PhantomData,
         }
     }
 }
 
-impl From<vx_parameter> for VxReference {
+impl From<vx_parameter> for VxReference<'_> {
     fn from(value: vx_parameter) -> Self {
         VxReference {
             raw: value as vx_reference,
+            _marker: PhantomData,
         }
     }
 }
 
-impl From<vx_image> for VxReference {
+impl From<vx_image> for VxReference<'_> {
     fn from(value: vx_image) -> Self {
         VxReference {
             raw: value as vx_reference,
+            _marker: PhantomData,
         }
     }
 }
 
-impl From<vx_kernel> for VxReference {
+impl From<vx_kernel> for VxReference<'_> {
     fn from(value: vx_kernel) -> Self {
         VxReference {
             raw: value as vx_reference,
+            _marker: PhantomData,
         }
     }
 }
 
-impl From<vx_scalar> for VxReference {
+impl From<vx_scalar> for VxReference<'_> {
     fn from(value: vx_scalar) -> Self {
         VxReference {
             raw: value as vx_reference,
+            _marker: PhantomData,
         }
     }
 }
 
-impl From<vx_context> for VxReference {
+impl From<vx_context> for VxReference<'_> {
     fn from(value: vx_context) -> Self {
         VxReference {
             raw: value as vx_reference,
+            _marker: PhantomData,
         }
     }
 }
 
-impl From<vx_lut> for VxReference {
+impl From<vx_lut> for VxReference<'_> {
     fn from(value: vx_lut) -> Self {
         VxReference {
             raw: value as vx_reference,
+            _marker: PhantomData,
         }
     }
 }
 
-impl From<vx_distribution> for VxReference {
+impl From<vx_distribution> for VxReference<'_> {
     fn from(value: vx_distribution) -> Self {
         VxReference {
             raw: value as vx_reference,
+            _marker: PhantomData,
         }
     }
 }
 
-impl From<vx_matrix> for VxReference {
+impl From<vx_matrix> for VxReference<'_> {
     fn from(value: vx_matrix) -> Self {
         VxReference {
             raw: value as vx_reference,
+            _marker: PhantomData,
         }
     }
 }
 
-impl From<vx_threshold> for VxReference {
+impl From<vx_threshold> for VxReference<'_> {
     fn from(value: vx_threshold) -> Self {
         VxReference {
             raw: value as vx_reference,
+            _marker: PhantomData,
         }
     }
 }
 
-impl From<vx_convolution> for VxReference {
+impl From<vx_convolution> for VxReference<'_> {
     fn from(value: vx_convolution) -> Self {
         VxReference {
             raw: value as vx_reference,
+            _marker: PhantomData,
         }
     }
 }
 
-impl From<vx_pyramid> for VxReference {
+impl From<vx_pyramid> for VxReference<'_> {
     fn from(value: vx_pyramid) -> Self {
         VxReference {
             raw: value as vx_reference,
+            _marker: PhantomData,
         }
     }
 }
 
-impl From<vx_delay> for VxReference {
+impl From<vx_delay> for VxReference<'_> {
     fn from(value: vx_delay) -> Self {
         VxReference {
             raw: value as vx_reference,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl From<vx_remap> for VxReference<'_> {
+    fn from(value: vx_remap) -> Self {
+        VxReference {
+            raw: value as vx_reference,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl From<vx_array> for VxReference<'_> {
+    fn from(value: vx_array) -> Self {
+        VxReference {
+            raw: value as vx_reference,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl From<vx_tensor> for VxReference<'_> {
+    fn from(value: vx_tensor) -> Self {
+        VxReference {
+            raw: value as vx_reference,
+            _marker: PhantomData,
         }
     }
 }
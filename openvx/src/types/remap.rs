@@ -0,0 +1,161 @@
+use crate::types::reference::retain_reference;
+use crate::{
+    AsRaw, AsVxReference, CheckStatus, QueryAttribute, Release, Result, VxContext, VxGraph,
+    VxReference, VxStatus,
+};
+use libopenvx_sys::{
+    vxCreateRemap, vxCreateVirtualRemap, vxQueryRemap, vxReleaseRemap, vx_enum, vx_reference,
+    vx_remap, vx_size, vx_status, vx_uint32,
+};
+use std::ffi::c_void;
+use std::marker::PhantomData;
+
+/// The remap table Object. A remap table contains per-pixel mapping of output pixels to input pixels.
+///
+/// The object is bound to the lifetime `'a` of the [`VxContext`](struct.VxContext.html) it was
+/// created in or, for virtual objects, of the [`VxGraph`](struct.VxGraph.html) that owns it.
+#[derive(Debug, Hash, PartialEq, Eq)]
+pub struct VxRemap<'a> {
+    raw: vx_remap,
+    _marker: PhantomData<&'a ()>,
+}
+
+impl<'a> VxRemap<'a> {
+    /// Creates a remap table object by calling [`vxCreateRemap`].
+    ///
+    /// # Arguments
+    ///
+    /// * `context` - The context in which to create the remap table.
+    /// * `source_width` - Width of the source image in pixels.
+    /// * `source_height` - Height of the source image in pixels.
+    /// * `destination_width` - Width of the destination image in pixels.
+    /// * `destination_height` - Height of the destination image in pixels.
+    ///
+    /// [`vxCreateRemap`]: ../../libopenvx_sys/fn.vxCreateRemap.html
+    pub fn new(
+        context: &'a VxContext,
+        source_width: vx_uint32,
+        source_height: vx_uint32,
+        destination_width: vx_uint32,
+        destination_height: vx_uint32,
+    ) -> Result<VxRemap<'a>> {
+        let remap = unsafe {
+            vxCreateRemap(
+                context.as_raw(),
+                source_width,
+                source_height,
+                destination_width,
+                destination_height,
+            )
+        };
+        VxRemap::from(remap).into_checked()
+    }
+
+    /// Creates an opaque reference to a remap table object without direct user access
+    /// by calling [`vxCreateVirtualRemap`].
+    ///
+    /// [`vxCreateVirtualRemap`]: ../../libopenvx_sys/fn.vxCreateVirtualRemap.html
+    pub fn virtual_remap(
+        graph: &'a VxGraph<'_>,
+        source_width: vx_uint32,
+        source_height: vx_uint32,
+        destination_width: vx_uint32,
+        destination_height: vx_uint32,
+    ) -> Result<VxRemap<'a>> {
+        let remap = unsafe {
+            vxCreateVirtualRemap(
+                graph.as_raw(),
+                source_width,
+                source_height,
+                destination_width,
+                destination_height,
+            )
+        };
+        VxRemap::from(remap).into_checked()
+    }
+
+    #[allow(dead_code)]
+    pub fn is_null(&self) -> bool {
+        self.raw.is_null()
+    }
+}
+
+impl<'a> AsRaw for VxRemap<'a> {
+    type Result = vx_remap;
+
+    fn as_raw(&self) -> Self::Result {
+        self.raw
+    }
+}
+
+impl<'a> AsVxReference for VxRemap<'a> {
+    fn as_reference(&self) -> VxReference<'_> {
+        VxReference::from(self.raw)
+    }
+}
+
+impl QueryAttribute for VxRemap<'_> {
+    unsafe fn query_raw(&self, attribute: vx_enum, ptr: *mut c_void, size: vx_size) -> vx_status {
+        vxQueryRemap(self.raw, attribute, ptr, size)
+    }
+}
+
+impl<'a> Release for VxRemap<'a> {
+    fn release(&mut self) -> Result<()> {
+        if self.raw.is_null() {
+            return Ok(());
+        }
+
+        let status = unsafe { vxReleaseRemap(&mut self.raw) };
+        self.raw = std::ptr::null_mut();
+
+        VxStatus::new_result(status, ())
+    }
+}
+
+impl<'a> Drop for VxRemap<'a> {
+    fn drop(&mut self) {
+        self.release().unwrap();
+    }
+}
+
+impl<'a> Clone for VxRemap<'a> {
+    /// Creates a new handle to the same object, incrementing its reference count
+    /// by calling [`vxRetainReference`](../../libopenvx_sys/fn.vxRetainReference.html).
+    fn clone(&self) -> Self {
+        retain_reference(self.raw as vx_reference);
+        VxRemap {
+            raw: self.raw,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'a> From<vx_remap> for VxRemap<'a> {
+    /// Takes ownership of the reference; it will be released when the wrapper is dropped.
+    fn from(value: vx_remap) -> Self {
+        VxRemap {
+            raw: value,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'a> From<VxRemap<'a>> for vx_remap {
+    /// Gives up ownership of the reference; the caller becomes responsible for releasing it.
+    fn from(value: VxRemap<'a>) -> Self {
+        let raw = value.raw;
+        std::mem::forget(value);
+        raw
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn is_null() {
+        assert!(VxRemap::from(std::ptr::null_mut()).is_null());
+    }
+}
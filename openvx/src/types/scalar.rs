@@ -1,36 +1,216 @@
-use crate::{AsVxReference, VxReference};
-use libopenvx_sys::vx_scalar;
+use crate::error::check_call;
+use crate::types::reference::retain_reference;
+use crate::{
+    Access, AsRaw, AsVxReference, CheckStatus, MemoryType, QueryAttribute, Release, Result,
+    ScalarType, VxContext, VxGraph, VxReference, VxStatus, VxType,
+};
+use libopenvx_sys::{
+    vxCopyScalarWithSize, vxCreateScalar, vxCreateScalarWithSize, vxCreateVirtualScalar,
+    vxQueryScalar, vxReleaseScalar, vx_enum, vx_reference, vx_scalar, vx_size, vx_status,
+};
+use std::ffi::c_void;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
 
-/// An opaque reference to a scalar.
+/// A reference to a scalar holding a value of type `T`.
 ///
-/// A scalar can be up to 64 bits wide.
-#[derive(Debug, Hash, PartialEq, Eq, Copy, Clone)]
-pub struct VxScalar {
+/// A scalar can be up to 64 bits wide, or hold one of the structs listed at
+/// [`ScalarType`](trait.ScalarType.html). Scalars are read and written by calling
+/// [`vxCopyScalarWithSize`], so the implementation rejects values of the wrong size.
+///
+/// The object is bound to the lifetime `'a` of the [`VxContext`](struct.VxContext.html) it was
+/// created in or, for virtual objects, of the [`VxGraph`](struct.VxGraph.html) that owns it.
+///
+/// # Examples
+///
+/// ```no_run
+/// use openvx::{VxContext, VxScalar};
+///
+/// let context = VxContext::create().unwrap();
+/// let shift = VxScalar::new(&context, 2i32).unwrap();
+/// shift.set(3).unwrap();
+/// assert_eq!(shift.get().unwrap(), 3);
+/// ```
+///
+/// [`vxCopyScalarWithSize`]: ../../libopenvx_sys/fn.vxCopyScalarWithSize.html
+pub struct VxScalar<'a, T> {
     raw: vx_scalar,
+    _marker: PhantomData<(&'a (), T)>,
 }
 
-impl VxScalar {
+impl<'a, T: ScalarType> VxScalar<'a, T> {
+    /// Creates a scalar holding `value` by calling [`vxCreateScalar`].
+    ///
+    /// [`vxCreateScalar`]: ../../libopenvx_sys/fn.vxCreateScalar.html
+    pub fn new(context: &'a VxContext, value: T) -> Result<Self> {
+        let raw = value.to_raw();
+        let scalar = unsafe {
+            vxCreateScalar(
+                context.as_raw(),
+                T::TYPE.to_raw() as vx_enum,
+                &raw as *const T::Raw as *const c_void,
+            )
+        };
+        VxScalar::from(scalar).into_checked()
+    }
+
+    /// Creates a scalar of a user struct registered at runtime by calling
+    /// [`vxCreateScalarWithSize`].
+    ///
+    /// `data_type` is the type returned by [`vxRegisterUserStruct`]; the implementation checks
+    /// that its size matches the size of `T::Raw`.
+    ///
+    /// [`vxCreateScalarWithSize`]: ../../libopenvx_sys/fn.vxCreateScalarWithSize.html
+    /// [`vxRegisterUserStruct`]: ../../libopenvx_sys/fn.vxRegisterUserStruct.html
+    pub fn with_type(context: &'a VxContext, data_type: VxType, value: T) -> Result<Self> {
+        let raw = value.to_raw();
+        let scalar = unsafe {
+            vxCreateScalarWithSize(
+                context.as_raw(),
+                data_type.to_raw() as vx_enum,
+                &raw as *const T::Raw as *const c_void,
+                std::mem::size_of::<T::Raw>(),
+            )
+        };
+        VxScalar::from(scalar).into_checked()
+    }
+
+    /// Creates an opaque reference to a scalar with no direct user access
+    /// by calling [`vxCreateVirtualScalar`].
+    ///
+    /// [`vxCreateVirtualScalar`]: ../../libopenvx_sys/fn.vxCreateVirtualScalar.html
+    pub fn virtual_scalar(graph: &'a VxGraph<'_>) -> Result<Self> {
+        let scalar = unsafe { vxCreateVirtualScalar(graph.as_raw(), T::TYPE.to_raw() as vx_enum) };
+        VxScalar::from(scalar).into_checked()
+    }
+
+    /// Reads the value by calling [`vxCopyScalarWithSize`].
+    ///
+    /// [`vxCopyScalarWithSize`]: ../../libopenvx_sys/fn.vxCopyScalarWithSize.html
+    pub fn get(&self) -> Result<T> {
+        let mut raw = std::mem::MaybeUninit::<T::Raw>::uninit();
+        self.copy(raw.as_mut_ptr() as *mut c_void, Access::Read)?;
+        Ok(T::from_raw(unsafe { raw.assume_init() }))
+    }
+
+    /// Writes the value by calling [`vxCopyScalarWithSize`].
+    ///
+    /// [`vxCopyScalarWithSize`]: ../../libopenvx_sys/fn.vxCopyScalarWithSize.html
+    pub fn set(&self, value: T) -> Result<()> {
+        let mut raw = value.to_raw();
+        self.copy(&mut raw as *mut T::Raw as *mut c_void, Access::Write)
+    }
+
+    fn copy(&self, ptr: *mut c_void, access: Access) -> Result<()> {
+        check_call("vxCopyScalarWithSize", self, || unsafe {
+            vxCopyScalarWithSize(
+                self.raw,
+                std::mem::size_of::<T::Raw>(),
+                ptr,
+                access.to_raw() as vx_enum,
+                MemoryType::Host.to_raw() as vx_enum,
+            )
+        })
+    }
+}
+
+impl<'a, T> VxScalar<'a, T> {
     #[allow(dead_code)]
     pub fn is_null(&self) -> bool {
         self.raw.is_null()
     }
 }
 
-impl AsVxReference for VxScalar {
-    fn as_reference(&self) -> VxReference {
+impl<T> fmt::Debug for VxScalar<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("VxScalar").field("raw", &self.raw).finish()
+    }
+}
+
+impl<T> PartialEq for VxScalar<'_, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.raw == other.raw
+    }
+}
+
+impl<T> Eq for VxScalar<'_, T> {}
+
+impl<T> Hash for VxScalar<'_, T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.raw.hash(state);
+    }
+}
+
+impl<'a, T> AsRaw for VxScalar<'a, T> {
+    type Result = vx_scalar;
+
+    fn as_raw(&self) -> Self::Result {
+        self.raw
+    }
+}
+
+impl<'a, T> AsVxReference for VxScalar<'a, T> {
+    fn as_reference(&self) -> VxReference<'_> {
         VxReference::from(self.raw)
     }
 }
 
-impl From<vx_scalar> for VxScalar {
+impl<T> QueryAttribute for VxScalar<'_, T> {
+    unsafe fn query_raw(&self, attribute: vx_enum, ptr: *mut c_void, size: vx_size) -> vx_status {
+        vxQueryScalar(self.raw, attribute, ptr, size)
+    }
+}
+
+impl<'a, T> Release for VxScalar<'a, T> {
+    fn release(&mut self) -> Result<()> {
+        if self.raw.is_null() {
+            return Ok(());
+        }
+
+        let status = unsafe { vxReleaseScalar(&mut self.raw) };
+        self.raw = std::ptr::null_mut();
+
+        VxStatus::new_result(status, ())
+    }
+}
+
+impl<'a, T> Drop for VxScalar<'a, T> {
+    fn drop(&mut self) {
+        self.release().unwrap();
+    }
+}
+
+impl<'a, T> Clone for VxScalar<'a, T> {
+    /// Creates a new handle to the same object, incrementing its reference count
+    /// by calling [`vxRetainReference`](../../libopenvx_sys/fn.vxRetainReference.html).
+    fn clone(&self) -> Self {
+        retain_reference(self.raw as vx_reference);
+        VxScalar {
+            raw: self.raw,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, T> From<vx_scalar> for VxScalar<'a, T> {
+    /// Takes ownership of the reference; it will be released when the wrapper is dropped.
+    ///
+    /// The data type of the scalar is not checked against `T`.
     fn from(value: vx_scalar) -> Self {
-        VxScalar { raw: value }
+        VxScalar {
+            raw: value,
+            _marker: PhantomData,
+        }
     }
 }
 
-impl Into<vx_scalar> for VxScalar {
+impl<'a, T> Into<vx_scalar> for VxScalar<'a, T> {
+    /// Gives up ownership of the reference; the caller becomes responsible for releasing it.
     fn into(self) -> vx_scalar {
-        return self.raw;
+        let raw = self.raw;
+        std::mem::forget(self);
+        raw
     }
 }
 
@@ -40,6 +220,6 @@ mod tests {
 
     #[test]
     fn is_null() {
-        assert!(VxScalar::from(std::ptr::null_mut()).is_null());
+        assert!(VxScalar::<u8>::from(std::ptr::null_mut()).is_null());
     }
 }
@@ -0,0 +1,211 @@
+use crate::types::reference::retain_reference;
+use crate::{
+    attr, Access, AsRaw, AsVxReference, CheckStatus, QueryAttribute, Release, Result,
+    TensorElement, TensorPatch, VxContext, VxGraph, VxReference, VxStatus, VxType,
+};
+use libopenvx_sys::{
+    vxCreateTensor, vxCreateVirtualTensor, vxQueryTensor, vxReleaseTensor, vx_enum, vx_int8,
+    vx_reference, vx_size, vx_status, vx_tensor, vx_tensor_attribute_e_VX_TENSOR_DIMS,
+};
+use std::ffi::c_void;
+use std::marker::PhantomData;
+
+/// The multidimensional data object (Tensor).
+///
+/// The object is bound to the lifetime `'a` of the [`VxContext`](struct.VxContext.html) it was
+/// created in or, for virtual objects, of the [`VxGraph`](struct.VxGraph.html) that owns it.
+#[derive(Debug, Hash, PartialEq, Eq)]
+pub struct VxTensor<'a> {
+    raw: vx_tensor,
+    _marker: PhantomData<&'a ()>,
+}
+
+impl<'a> VxTensor<'a> {
+    /// Creates an opaque reference to a tensor data buffer by calling [`vxCreateTensor`].
+    ///
+    /// # Arguments
+    ///
+    /// * `context` - The context in which to create the tensor.
+    /// * `dims` - The size of each dimension. The number of dimensions must not exceed
+    ///   `VX_CONTEXT_MAX_TENSOR_DIMS`.
+    /// * `data_type` - The type of the tensor elements, e.g. [`VxType::Int16`].
+    /// * `fixed_point_position` - The fixed point precision of the elements, or `0` for integers.
+    ///
+    /// [`vxCreateTensor`]: ../../libopenvx_sys/fn.vxCreateTensor.html
+    /// [`VxType::Int16`]: ../enum.VxType.html#variant.Int16
+    pub fn new(
+        context: &'a VxContext,
+        dims: &[vx_size],
+        data_type: VxType,
+        fixed_point_position: vx_int8,
+    ) -> Result<VxTensor<'a>> {
+        let tensor = unsafe {
+            vxCreateTensor(
+                context.as_raw(),
+                dims.len(),
+                dims.as_ptr(),
+                data_type.to_raw() as vx_enum,
+                fixed_point_position,
+            )
+        };
+        VxTensor::from(tensor).into_checked()
+    }
+
+    /// Creates an opaque reference to a tensor data buffer with no direct user access
+    /// by calling [`vxCreateVirtualTensor`].
+    ///
+    /// [`vxCreateVirtualTensor`]: ../../libopenvx_sys/fn.vxCreateVirtualTensor.html
+    pub fn virtual_tensor(
+        graph: &'a VxGraph<'_>,
+        dims: &[vx_size],
+        data_type: VxType,
+        fixed_point_position: vx_int8,
+    ) -> Result<VxTensor<'a>> {
+        let tensor = unsafe {
+            vxCreateVirtualTensor(
+                graph.as_raw(),
+                dims.len(),
+                dims.as_ptr(),
+                data_type.to_raw() as vx_enum,
+                fixed_point_position,
+            )
+        };
+        VxTensor::from(tensor).into_checked()
+    }
+
+    /// Returns the size of each dimension (`VX_TENSOR_DIMS`).
+    pub fn dims(&self) -> Result<Vec<usize>> {
+        let mut dims: Vec<vx_size> = vec![0; self.query::<attr::NumberOfDims>()?];
+        let status = unsafe {
+            self.query_raw(
+                vx_tensor_attribute_e_VX_TENSOR_DIMS as vx_enum,
+                dims.as_mut_ptr() as *mut c_void,
+                std::mem::size_of_val(dims.as_slice()),
+            )
+        };
+        VxStatus::new_result(status, dims)
+    }
+
+    /// Maps the elements from `start` (inclusive) to `end` (exclusive) into host memory
+    /// by calling [`vxMapTensorPatch`].
+    ///
+    /// Both bounds need one coordinate per dimension of the tensor. The patch is unmapped when
+    /// the returned guard is dropped.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`VxError::NotCompatible`] if `T` does not match the data type of the tensor, and
+    /// [`VxError::InvalidParameters`] if the bounds do not match its dimensions.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use openvx::{Access, VxContext, VxTensor, VxType};
+    ///
+    /// let context = VxContext::create().unwrap();
+    /// let tensor = VxTensor::new(&context, &[4, 3], VxType::Int16, 0).unwrap();
+    ///
+    /// let dims = tensor.dims().unwrap();
+    /// let patch = tensor
+    ///     .map_patch::<i16>(&[0, 0], &dims, Access::Read)
+    ///     .unwrap();
+    /// assert_eq!(*patch.get(&[3, 2]), 0);
+    /// ```
+    ///
+    /// [`vxMapTensorPatch`]: ../../libopenvx_sys/fn.vxMapTensorPatch.html
+    /// [`VxError::NotCompatible`]: ../enum.VxError.html#variant.NotCompatible
+    /// [`VxError::InvalidParameters`]: ../enum.VxError.html#variant.InvalidParameters
+    pub fn map_patch<T: TensorElement>(
+        &self,
+        start: &[usize],
+        end: &[usize],
+        access: Access,
+    ) -> Result<TensorPatch<'_, T>> {
+        TensorPatch::map(self, start, end, access)
+    }
+
+    #[allow(dead_code)]
+    pub fn is_null(&self) -> bool {
+        self.raw.is_null()
+    }
+}
+
+impl<'a> AsRaw for VxTensor<'a> {
+    type Result = vx_tensor;
+
+    fn as_raw(&self) -> Self::Result {
+        self.raw
+    }
+}
+
+impl<'a> AsVxReference for VxTensor<'a> {
+    fn as_reference(&self) -> VxReference<'_> {
+        VxReference::from(self.raw)
+    }
+}
+
+impl QueryAttribute for VxTensor<'_> {
+    unsafe fn query_raw(&self, attribute: vx_enum, ptr: *mut c_void, size: vx_size) -> vx_status {
+        vxQueryTensor(self.raw, attribute, ptr, size)
+    }
+}
+
+impl<'a> Release for VxTensor<'a> {
+    fn release(&mut self) -> Result<()> {
+        if self.raw.is_null() {
+            return Ok(());
+        }
+
+        let status = unsafe { vxReleaseTensor(&mut self.raw) };
+        self.raw = std::ptr::null_mut();
+
+        VxStatus::new_result(status, ())
+    }
+}
+
+impl<'a> Drop for VxTensor<'a> {
+    fn drop(&mut self) {
+        self.release().unwrap();
+    }
+}
+
+impl<'a> Clone for VxTensor<'a> {
+    /// Creates a new handle to the same object, incrementing its reference count
+    /// by calling [`vxRetainReference`](../../libopenvx_sys/fn.vxRetainReference.html).
+    fn clone(&self) -> Self {
+        retain_reference(self.raw as vx_reference);
+        VxTensor {
+            raw: self.raw,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'a> From<vx_tensor> for VxTensor<'a> {
+    /// Takes ownership of the reference; it will be released when the wrapper is dropped.
+    fn from(value: vx_tensor) -> Self {
+        VxTensor {
+            raw: value,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'a> From<VxTensor<'a>> for vx_tensor {
+    /// Gives up ownership of the reference; the caller becomes responsible for releasing it.
+    fn from(value: VxTensor<'a>) -> Self {
+        let raw = value.raw;
+        std::mem::forget(value);
+        raw
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn is_null() {
+        assert!(VxTensor::from(std::ptr::null_mut()).is_null());
+    }
+}
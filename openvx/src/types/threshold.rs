@@ -1,35 +1,258 @@
-use crate::{AsVxReference, VxReference};
-use libopenvx_sys::vx_threshold;
+use crate::error::check_call;
+use crate::types::reference::retain_reference;
+use crate::{
+    Access, AsRaw, AsVxReference, CheckStatus, ImageType, MemoryType, PixelValue, QueryAttribute,
+    Release, Result, SetAttribute, VxContext, VxGraph, VxReference, VxStatus,
+};
+use libopenvx_sys::{
+    vxCopyThresholdRange, vxCreateThresholdForImage, vxCreateVirtualThresholdForImage,
+    vxQueryThreshold, vxReleaseThreshold, vxSetThresholdAttribute, vx_enum, vx_pixel_value_t,
+    vx_reference, vx_size, vx_status, vx_threshold, vx_threshold_type_e,
+    vx_threshold_type_e_VX_THRESHOLD_TYPE_BINARY, vx_threshold_type_e_VX_THRESHOLD_TYPE_RANGE,
+};
+use std::ffi::c_void;
+use std::fmt::{Display, Formatter};
+use std::marker::PhantomData;
+
+/// The type of thresholding a [`VxThreshold`](struct.VxThreshold.html) performs.
+#[derive(Debug, Eq, PartialEq, Hash, Copy, Clone)]
+pub enum ThresholdType {
+    /// A threshold with only 1 value.
+    Binary,
+    /// A threshold with 2 values (upper/lower). Use with Canny Edge Detection.
+    Range,
+    /// An unknown threshold type.
+    Other { r#type: vx_threshold_type_e },
+}
+
+impl ThresholdType {
+    pub const fn new(r#type: vx_threshold_type_e) -> Self {
+        #[allow(non_upper_case_globals)]
+        match r#type {
+            vx_threshold_type_e_VX_THRESHOLD_TYPE_BINARY => ThresholdType::Binary,
+            vx_threshold_type_e_VX_THRESHOLD_TYPE_RANGE => ThresholdType::Range,
+            r#type => ThresholdType::Other { r#type },
+        }
+    }
+
+    /// Converts this instance into a [`vx_threshold_type_e`].
+    ///
+    /// [`vx_threshold_type_e`]: ../libopenvx_sys/type.vx_threshold_type_e.html
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use openvx::ThresholdType;
+    /// use libopenvx_sys::vx_threshold_type_e_VX_THRESHOLD_TYPE_RANGE;
+    ///
+    /// let x = ThresholdType::Range;
+    /// assert_eq!(x.to_raw(), vx_threshold_type_e_VX_THRESHOLD_TYPE_RANGE);
+    /// ```
+    pub const fn to_raw(&self) -> vx_threshold_type_e {
+        match self {
+            ThresholdType::Binary => vx_threshold_type_e_VX_THRESHOLD_TYPE_BINARY,
+            ThresholdType::Range => vx_threshold_type_e_VX_THRESHOLD_TYPE_RANGE,
+            ThresholdType::Other { r#type } => *r#type,
+        }
+    }
+}
+
+impl From<vx_threshold_type_e> for ThresholdType {
+    fn from(value: vx_threshold_type_e) -> Self {
+        ThresholdType::new(value)
+    }
+}
+
+impl Display for ThresholdType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ThresholdType::Binary => write!(f, "VX_THRESHOLD_TYPE_BINARY"),
+            ThresholdType::Range => write!(f, "VX_THRESHOLD_TYPE_RANGE"),
+            ThresholdType::Other { r#type } => write!(f, "VX_THRESHOLD_TYPE_OTHER ({})", r#type),
+        }
+    }
+}
 
 /// The Threshold Object. A thresholding object contains the types and
 /// limit values of the thresholding required.
-#[derive(Debug, Hash, PartialEq, Eq, Copy, Clone)]
-pub struct VxThreshold {
+///
+/// The object is bound to the lifetime `'a` of the [`VxContext`](struct.VxContext.html) it was
+/// created in or, for virtual objects, of the [`VxGraph`](struct.VxGraph.html) that owns it.
+#[derive(Debug, Hash, PartialEq, Eq)]
+pub struct VxThreshold<'a> {
     raw: vx_threshold,
+    _marker: PhantomData<&'a ()>,
 }
 
-impl VxThreshold {
+impl<'a> VxThreshold<'a> {
+    /// Creates a threshold object and returns a reference to it by calling
+    /// [`vxCreateThresholdForImage`].
+    ///
+    /// # Arguments
+    ///
+    /// * `context` - The context in which to create the threshold.
+    /// * `threshold_type` - The type of thresholding operation.
+    /// * `input_format` - The format of images that will be used as input of the thresholding operation.
+    /// * `output_format` - The format of images that will be generated by the thresholding operation.
+    ///
+    /// [`vxCreateThresholdForImage`]: ../../libopenvx_sys/fn.vxCreateThresholdForImage.html
+    pub fn new(
+        context: &'a VxContext,
+        threshold_type: ThresholdType,
+        input_format: ImageType,
+        output_format: ImageType,
+    ) -> Result<VxThreshold<'a>> {
+        let threshold = unsafe {
+            vxCreateThresholdForImage(
+                context.as_raw(),
+                threshold_type.to_raw() as vx_enum,
+                input_format.to_raw(),
+                output_format.to_raw(),
+            )
+        };
+        VxThreshold::from(threshold).into_checked()
+    }
+
+    /// Creates an opaque reference to a threshold object without direct user access
+    /// by calling [`vxCreateVirtualThresholdForImage`].
+    ///
+    /// [`vxCreateVirtualThresholdForImage`]: ../../libopenvx_sys/fn.vxCreateVirtualThresholdForImage.html
+    pub fn virtual_threshold(
+        graph: &'a VxGraph<'_>,
+        threshold_type: ThresholdType,
+        input_format: ImageType,
+        output_format: ImageType,
+    ) -> Result<VxThreshold<'a>> {
+        let threshold = unsafe {
+            vxCreateVirtualThresholdForImage(
+                graph.as_raw(),
+                threshold_type.to_raw() as vx_enum,
+                input_format.to_raw(),
+                output_format.to_raw(),
+            )
+        };
+        VxThreshold::from(threshold).into_checked()
+    }
+
+    /// Returns the lower and upper values of a range threshold by calling
+    /// [`vxCopyThresholdRange`].
+    ///
+    /// [`vxCopyThresholdRange`]: ../../libopenvx_sys/fn.vxCopyThresholdRange.html
+    pub fn range(&self) -> Result<(PixelValue, PixelValue)> {
+        let mut lower = PixelValue::zero().to_raw();
+        let mut upper = PixelValue::zero().to_raw();
+        self.copy_range(&mut lower, &mut upper, Access::Read)?;
+        Ok((lower.into(), upper.into()))
+    }
+
+    /// Sets the lower and upper values of a range threshold by calling
+    /// [`vxCopyThresholdRange`].
+    ///
+    /// [`vxCopyThresholdRange`]: ../../libopenvx_sys/fn.vxCopyThresholdRange.html
+    pub fn set_range(&self, lower: PixelValue, upper: PixelValue) -> Result<()> {
+        let mut lower = lower.to_raw();
+        let mut upper = upper.to_raw();
+        self.copy_range(&mut lower, &mut upper, Access::Write)
+    }
+
+    fn copy_range(
+        &self,
+        lower: &mut vx_pixel_value_t,
+        upper: &mut vx_pixel_value_t,
+        access: Access,
+    ) -> Result<()> {
+        check_call("vxCopyThresholdRange", self, || unsafe {
+            vxCopyThresholdRange(
+                self.raw,
+                lower,
+                upper,
+                access.to_raw() as vx_enum,
+                MemoryType::Host.to_raw() as vx_enum,
+            )
+        })
+    }
+
     #[allow(dead_code)]
     pub fn is_null(&self) -> bool {
         self.raw.is_null()
     }
 }
 
-impl AsVxReference for VxThreshold {
-    fn as_reference(&self) -> VxReference {
+impl<'a> AsRaw for VxThreshold<'a> {
+    type Result = vx_threshold;
+
+    fn as_raw(&self) -> Self::Result {
+        self.raw
+    }
+}
+
+impl<'a> AsVxReference for VxThreshold<'a> {
+    fn as_reference(&self) -> VxReference<'_> {
         VxReference::from(self.raw)
     }
 }
 
-impl From<vx_threshold> for VxThreshold {
+impl QueryAttribute for VxThreshold<'_> {
+    unsafe fn query_raw(&self, attribute: vx_enum, ptr: *mut c_void, size: vx_size) -> vx_status {
+        vxQueryThreshold(self.raw, attribute, ptr, size)
+    }
+}
+
+impl SetAttribute for VxThreshold<'_> {
+    unsafe fn set_raw(&self, attribute: vx_enum, ptr: *const c_void, size: vx_size) -> vx_status {
+        vxSetThresholdAttribute(self.raw, attribute, ptr, size)
+    }
+}
+
+impl<'a> Release for VxThreshold<'a> {
+    fn release(&mut self) -> Result<()> {
+        if self.raw.is_null() {
+            return Ok(());
+        }
+
+        let status = unsafe { vxReleaseThreshold(&mut self.raw) };
+        self.raw = std::ptr::null_mut();
+
+        VxStatus::new_result(status, ())
+    }
+}
+
+impl<'a> Drop for VxThreshold<'a> {
+    fn drop(&mut self) {
+        self.release().unwrap();
+    }
+}
+
+impl<'a> Clone for VxThreshold<'a> {
+    /// Creates a new handle to the same object, incrementing its reference count
+    /// by calling [`vxRetainReference`](../../libopenvx_sys/fn.vxRetainReference.html).
+    fn clone(&self) -> Self {
+        retain_reference(self.raw as vx_reference);
+        VxThreshold {
+            raw: self.raw,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<'a> From<vx_threshold> for VxThreshold<'a> {
+    /// Takes ownership of the reference; it will be released when the wrapper is dropped.
     fn from(value: vx_threshold) -> Self {
-        VxThreshold { raw: value }
+        VxThreshold {
+            raw: value,
+            _marker: PhantomData,
+        }
     }
 }
 
-impl Into<vx_threshold> for VxThreshold {
+impl<'a> Into<vx_threshold> for VxThreshold<'a> {
+    /// Gives up ownership of the reference; the caller becomes responsible for releasing it.
     fn into(self) -> vx_threshold {
-        return self.raw;
+        let raw = self.raw;
+        std::mem::forget(self);
+        raw
     }
 }
 
@@ -37,6 +260,14 @@ impl Into<vx_threshold> for VxThreshold {
 mod tests {
     use super::*;
 
+    #[test]
+    fn threshold_type_roundtrip() {
+        for t in [ThresholdType::Binary, ThresholdType::Range] {
+            assert_eq!(ThresholdType::from(t.to_raw()), t);
+        }
+        assert_eq!(ThresholdType::new(0), ThresholdType::Other { r#type: 0 });
+    }
+
     #[test]
     fn is_null() {
         assert!(VxThreshold::from(std::ptr::null_mut()).is_null());
@@ -269,6 +269,10 @@ impl fmt::Display for VxError {
     }
 }
 
+impl std::error::Error for VxError {}
+
+impl std::error::Error for InvalidErrorStatusError {}
+
 #[cfg(test)]
 mod tests {
     #![macro_use]
@@ -24,7 +24,7 @@ impl VxStatus {
     pub fn new_result<T>(status: vx_status_e, value: T) -> Result<T> {
         match VxStatus::new(status) {
             VxStatus::Success => Ok(value),
-            VxStatus::Error(error) => Err(error),
+            VxStatus::Error(error) => Err(error.into()),
         }
     }
 
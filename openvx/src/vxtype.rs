@@ -0,0 +1,307 @@
+use core::fmt;
+use libopenvx_sys::*;
+
+/// The type enumeration lists all the known types in OpenVX.
+#[derive(Debug, Eq, PartialEq, Hash, Copy, Clone)]
+pub enum VxType {
+    /// An invalid type value. When passed an error must be returned.
+    Invalid,
+    /// A `vx_char`.
+    Char,
+    /// A `vx_int8`.
+    Int8,
+    /// A `vx_uint8`.
+    UInt8,
+    /// A `vx_int16`.
+    Int16,
+    /// A `vx_uint16`.
+    UInt16,
+    /// A `vx_int32`.
+    Int32,
+    /// A `vx_uint32`.
+    UInt32,
+    /// A `vx_int64`.
+    Int64,
+    /// A `vx_uint64`.
+    UInt64,
+    /// A `vx_float32`.
+    Float32,
+    /// A `vx_float64`.
+    Float64,
+    /// A `vx_enum`. Equivalent in size to a `vx_int32`.
+    Enum,
+    /// A `vx_size`.
+    Size,
+    /// A `vx_df_image`.
+    DfImage,
+    /// A `vx_float16`.
+    Float16,
+    /// A `vx_bool`.
+    Bool,
+    /// A `vx_rectangle_t`.
+    Rectangle,
+    /// A `vx_keypoint_t`.
+    Keypoint,
+    /// A `vx_coordinates2d_t`.
+    Coordinates2D,
+    /// A `vx_coordinates3d_t`.
+    Coordinates3D,
+    /// A `vx_coordinates2df_t`.
+    Coordinates2DF,
+    /// A `vx_hog_t`.
+    HogParams,
+    /// A `vx_hough_lines_p_t`.
+    HoughLinesParams,
+    /// A `vx_line2d_t`.
+    Line2D,
+    /// A `vx_tensor_matrix_multiply_params_t`.
+    TensorMatrixMultiplyParams,
+    /// A `vx_reference`.
+    Reference,
+    /// A `vx_context`.
+    Context,
+    /// A `vx_graph`.
+    Graph,
+    /// A `vx_node`.
+    Node,
+    /// A `vx_kernel`.
+    Kernel,
+    /// A `vx_parameter`.
+    Parameter,
+    /// A `vx_delay`.
+    Delay,
+    /// A `vx_lut`.
+    Lut,
+    /// A `vx_distribution`.
+    Distribution,
+    /// A `vx_pyramid`.
+    Pyramid,
+    /// A `vx_threshold`.
+    Threshold,
+    /// A `vx_matrix`.
+    Matrix,
+    /// A `vx_convolution`.
+    Convolution,
+    /// A `vx_scalar` when needed to be completely generic for kernel validation.
+    Scalar,
+    /// A `vx_array`.
+    Array,
+    /// A `vx_image`.
+    Image,
+    /// A `vx_remap`.
+    Remap,
+    /// An error object which has no type.
+    Error,
+    /// A `vx_meta_format`.
+    MetaFormat,
+    /// A `vx_object_array`.
+    ObjectArray,
+    /// A `vx_tensor`.
+    Tensor,
+    /// A user-defined, vendor-defined or otherwise unknown type.
+    Other { r#type: vx_type_e },
+}
+
+impl VxType {
+    pub const fn new(r#type: vx_type_e) -> Self {
+        #[allow(non_upper_case_globals)]
+        match r#type {
+            vx_type_e_VX_TYPE_INVALID => VxType::Invalid,
+            vx_type_e_VX_TYPE_CHAR => VxType::Char,
+            vx_type_e_VX_TYPE_INT8 => VxType::Int8,
+            vx_type_e_VX_TYPE_UINT8 => VxType::UInt8,
+            vx_type_e_VX_TYPE_INT16 => VxType::Int16,
+            vx_type_e_VX_TYPE_UINT16 => VxType::UInt16,
+            vx_type_e_VX_TYPE_INT32 => VxType::Int32,
+            vx_type_e_VX_TYPE_UINT32 => VxType::UInt32,
+            vx_type_e_VX_TYPE_INT64 => VxType::Int64,
+            vx_type_e_VX_TYPE_UINT64 => VxType::UInt64,
+            vx_type_e_VX_TYPE_FLOAT32 => VxType::Float32,
+            vx_type_e_VX_TYPE_FLOAT64 => VxType::Float64,
+            vx_type_e_VX_TYPE_ENUM => VxType::Enum,
+            vx_type_e_VX_TYPE_SIZE => VxType::Size,
+            vx_type_e_VX_TYPE_DF_IMAGE => VxType::DfImage,
+            vx_type_e_VX_TYPE_FLOAT16 => VxType::Float16,
+            vx_type_e_VX_TYPE_BOOL => VxType::Bool,
+            vx_type_e_VX_TYPE_RECTANGLE => VxType::Rectangle,
+            vx_type_e_VX_TYPE_KEYPOINT => VxType::Keypoint,
+            vx_type_e_VX_TYPE_COORDINATES2D => VxType::Coordinates2D,
+            vx_type_e_VX_TYPE_COORDINATES3D => VxType::Coordinates3D,
+            vx_type_e_VX_TYPE_COORDINATES2DF => VxType::Coordinates2DF,
+            vx_type_e_VX_TYPE_HOG_PARAMS => VxType::HogParams,
+            vx_type_e_VX_TYPE_HOUGH_LINES_PARAMS => VxType::HoughLinesParams,
+            vx_type_e_VX_TYPE_LINE_2D => VxType::Line2D,
+            vx_type_e_VX_TYPE_TENSOR_MATRIX_MULTIPLY_PARAMS => VxType::TensorMatrixMultiplyParams,
+            vx_type_e_VX_TYPE_REFERENCE => VxType::Reference,
+            vx_type_e_VX_TYPE_CONTEXT => VxType::Context,
+            vx_type_e_VX_TYPE_GRAPH => VxType::Graph,
+            vx_type_e_VX_TYPE_NODE => VxType::Node,
+            vx_type_e_VX_TYPE_KERNEL => VxType::Kernel,
+            vx_type_e_VX_TYPE_PARAMETER => VxType::Parameter,
+            vx_type_e_VX_TYPE_DELAY => VxType::Delay,
+            vx_type_e_VX_TYPE_LUT => VxType::Lut,
+            vx_type_e_VX_TYPE_DISTRIBUTION => VxType::Distribution,
+            vx_type_e_VX_TYPE_PYRAMID => VxType::Pyramid,
+            vx_type_e_VX_TYPE_THRESHOLD => VxType::Threshold,
+            vx_type_e_VX_TYPE_MATRIX => VxType::Matrix,
+            vx_type_e_VX_TYPE_CONVOLUTION => VxType::Convolution,
+            vx_type_e_VX_TYPE_SCALAR => VxType::Scalar,
+            vx_type_e_VX_TYPE_ARRAY => VxType::Array,
+            vx_type_e_VX_TYPE_IMAGE => VxType::Image,
+            vx_type_e_VX_TYPE_REMAP => VxType::Remap,
+            vx_type_e_VX_TYPE_ERROR => VxType::Error,
+            vx_type_e_VX_TYPE_META_FORMAT => VxType::MetaFormat,
+            vx_type_e_VX_TYPE_OBJECT_ARRAY => VxType::ObjectArray,
+            vx_type_e_VX_TYPE_TENSOR => VxType::Tensor,
+            r#type => VxType::Other { r#type },
+        }
+    }
+
+    /// Converts this instance into a [`vx_type_e`].
+    ///
+    /// [`vx_type_e`]: ../libopenvx_sys/type.vx_type_e.html
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use openvx::VxType;
+    ///
+    /// let x: VxType = VxType::UInt8;
+    /// assert_eq!(x.to_raw(), libopenvx_sys::vx_type_e_VX_TYPE_UINT8);
+    /// ```
+    pub const fn to_raw(&self) -> vx_type_e {
+        match self {
+            VxType::Invalid => vx_type_e_VX_TYPE_INVALID,
+            VxType::Char => vx_type_e_VX_TYPE_CHAR,
+            VxType::Int8 => vx_type_e_VX_TYPE_INT8,
+            VxType::UInt8 => vx_type_e_VX_TYPE_UINT8,
+            VxType::Int16 => vx_type_e_VX_TYPE_INT16,
+            VxType::UInt16 => vx_type_e_VX_TYPE_UINT16,
+            VxType::Int32 => vx_type_e_VX_TYPE_INT32,
+            VxType::UInt32 => vx_type_e_VX_TYPE_UINT32,
+            VxType::Int64 => vx_type_e_VX_TYPE_INT64,
+            VxType::UInt64 => vx_type_e_VX_TYPE_UINT64,
+            VxType::Float32 => vx_type_e_VX_TYPE_FLOAT32,
+            VxType::Float64 => vx_type_e_VX_TYPE_FLOAT64,
+            VxType::Enum => vx_type_e_VX_TYPE_ENUM,
+            VxType::Size => vx_type_e_VX_TYPE_SIZE,
+            VxType::DfImage => vx_type_e_VX_TYPE_DF_IMAGE,
+            VxType::Float16 => vx_type_e_VX_TYPE_FLOAT16,
+            VxType::Bool => vx_type_e_VX_TYPE_BOOL,
+            VxType::Rectangle => vx_type_e_VX_TYPE_RECTANGLE,
+            VxType::Keypoint => vx_type_e_VX_TYPE_KEYPOINT,
+            VxType::Coordinates2D => vx_type_e_VX_TYPE_COORDINATES2D,
+            VxType::Coordinates3D => vx_type_e_VX_TYPE_COORDINATES3D,
+            VxType::Coordinates2DF => vx_type_e_VX_TYPE_COORDINATES2DF,
+            VxType::HogParams => vx_type_e_VX_TYPE_HOG_PARAMS,
+            VxType::HoughLinesParams => vx_type_e_VX_TYPE_HOUGH_LINES_PARAMS,
+            VxType::Line2D => vx_type_e_VX_TYPE_LINE_2D,
+            VxType::TensorMatrixMultiplyParams => vx_type_e_VX_TYPE_TENSOR_MATRIX_MULTIPLY_PARAMS,
+            VxType::Reference => vx_type_e_VX_TYPE_REFERENCE,
+            VxType::Context => vx_type_e_VX_TYPE_CONTEXT,
+            VxType::Graph => vx_type_e_VX_TYPE_GRAPH,
+            VxType::Node => vx_type_e_VX_TYPE_NODE,
+            VxType::Kernel => vx_type_e_VX_TYPE_KERNEL,
+            VxType::Parameter => vx_type_e_VX_TYPE_PARAMETER,
+            VxType::Delay => vx_type_e_VX_TYPE_DELAY,
+            VxType::Lut => vx_type_e_VX_TYPE_LUT,
+            VxType::Distribution => vx_type_e_VX_TYPE_DISTRIBUTION,
+            VxType::Pyramid => vx_type_e_VX_TYPE_PYRAMID,
+            VxType::Threshold => vx_type_e_VX_TYPE_THRESHOLD,
+            VxType::Matrix => vx_type_e_VX_TYPE_MATRIX,
+            VxType::Convolution => vx_type_e_VX_TYPE_CONVOLUTION,
+            VxType::Scalar => vx_type_e_VX_TYPE_SCALAR,
+            VxType::Array => vx_type_e_VX_TYPE_ARRAY,
+            VxType::Image => vx_type_e_VX_TYPE_IMAGE,
+            VxType::Remap => vx_type_e_VX_TYPE_REMAP,
+            VxType::Error => vx_type_e_VX_TYPE_ERROR,
+            VxType::MetaFormat => vx_type_e_VX_TYPE_META_FORMAT,
+            VxType::ObjectArray => vx_type_e_VX_TYPE_OBJECT_ARRAY,
+            VxType::Tensor => vx_type_e_VX_TYPE_TENSOR,
+            VxType::Other { r#type } => *r#type,
+        }
+    }
+}
+
+impl From<vx_type_e> for VxType {
+    fn from(r#type: vx_type_e) -> Self {
+        VxType::new(r#type)
+    }
+}
+
+impl fmt::Display for VxType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            VxType::Invalid => write!(f, "VX_TYPE_INVALID"),
+            VxType::Char => write!(f, "VX_TYPE_CHAR"),
+            VxType::Int8 => write!(f, "VX_TYPE_INT8"),
+            VxType::UInt8 => write!(f, "VX_TYPE_UINT8"),
+            VxType::Int16 => write!(f, "VX_TYPE_INT16"),
+            VxType::UInt16 => write!(f, "VX_TYPE_UINT16"),
+            VxType::Int32 => write!(f, "VX_TYPE_INT32"),
+            VxType::UInt32 => write!(f, "VX_TYPE_UINT32"),
+            VxType::Int64 => write!(f, "VX_TYPE_INT64"),
+            VxType::UInt64 => write!(f, "VX_TYPE_UINT64"),
+            VxType::Float32 => write!(f, "VX_TYPE_FLOAT32"),
+            VxType::Float64 => write!(f, "VX_TYPE_FLOAT64"),
+            VxType::Enum => write!(f, "VX_TYPE_ENUM"),
+            VxType::Size => write!(f, "VX_TYPE_SIZE"),
+            VxType::DfImage => write!(f, "VX_TYPE_DF_IMAGE"),
+            VxType::Float16 => write!(f, "VX_TYPE_FLOAT16"),
+            VxType::Bool => write!(f, "VX_TYPE_BOOL"),
+            VxType::Rectangle => write!(f, "VX_TYPE_RECTANGLE"),
+            VxType::Keypoint => write!(f, "VX_TYPE_KEYPOINT"),
+            VxType::Coordinates2D => write!(f, "VX_TYPE_COORDINATES2D"),
+            VxType::Coordinates3D => write!(f, "VX_TYPE_COORDINATES3D"),
+            VxType::Coordinates2DF => write!(f, "VX_TYPE_COORDINATES2DF"),
+            VxType::HogParams => write!(f, "VX_TYPE_HOG_PARAMS"),
+            VxType::HoughLinesParams => write!(f, "VX_TYPE_HOUGH_LINES_PARAMS"),
+            VxType::Line2D => write!(f, "VX_TYPE_LINE_2D"),
+            VxType::TensorMatrixMultiplyParams => {
+                write!(f, "VX_TYPE_TENSOR_MATRIX_MULTIPLY_PARAMS")
+            }
+            VxType::Reference => write!(f, "VX_TYPE_REFERENCE"),
+            VxType::Context => write!(f, "VX_TYPE_CONTEXT"),
+            VxType::Graph => write!(f, "VX_TYPE_GRAPH"),
+            VxType::Node => write!(f, "VX_TYPE_NODE"),
+            VxType::Kernel => write!(f, "VX_TYPE_KERNEL"),
+            VxType::Parameter => write!(f, "VX_TYPE_PARAMETER"),
+            VxType::Delay => write!(f, "VX_TYPE_DELAY"),
+            VxType::Lut => write!(f, "VX_TYPE_LUT"),
+            VxType::Distribution => write!(f, "VX_TYPE_DISTRIBUTION"),
+            VxType::Pyramid => write!(f, "VX_TYPE_PYRAMID"),
+            VxType::Threshold => write!(f, "VX_TYPE_THRESHOLD"),
+            VxType::Matrix => write!(f, "VX_TYPE_MATRIX"),
+            VxType::Convolution => write!(f, "VX_TYPE_CONVOLUTION"),
+            VxType::Scalar => write!(f, "VX_TYPE_SCALAR"),
+            VxType::Array => write!(f, "VX_TYPE_ARRAY"),
+            VxType::Image => write!(f, "VX_TYPE_IMAGE"),
+            VxType::Remap => write!(f, "VX_TYPE_REMAP"),
+            VxType::Error => write!(f, "VX_TYPE_ERROR"),
+            VxType::MetaFormat => write!(f, "VX_TYPE_META_FORMAT"),
+            VxType::ObjectArray => write!(f, "VX_TYPE_OBJECT_ARRAY"),
+            VxType::Tensor => write!(f, "VX_TYPE_TENSOR"),
+            VxType::Other { r#type } => write!(f, "VX_TYPE_OTHER ({})", r#type),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn type_roundtrip() {
+        assert_eq!(VxType::from(vx_type_e_VX_TYPE_UINT8), VxType::UInt8);
+        assert_eq!(VxType::Image.to_raw(), vx_type_e_VX_TYPE_IMAGE);
+    }
+
+    #[test]
+    fn type_other() {
+        let r#type = vx_type_e_VX_TYPE_USER_STRUCT_START + 1;
+        assert_eq!(VxType::from(r#type), VxType::Other { r#type });
+        assert_eq!(VxType::Other { r#type }.to_raw(), r#type);
+    }
+}